@@ -0,0 +1,66 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+
+use redoubt_util::constant_time_eq;
+
+// Fast mode: FAST_BENCH=1 cargo bench -p benchmarks --bench util
+fn is_fast_mode() -> bool {
+    std::env::var("FAST_BENCH")
+        .map(|v| v == "1")
+        .unwrap_or(false)
+}
+
+fn configure_group(group: &mut criterion::BenchmarkGroup<criterion::measurement::WallTime>) {
+    if is_fast_mode() {
+        group.measurement_time(std::time::Duration::from_millis(500));
+        group.sample_size(10);
+    } else {
+        group.measurement_time(std::time::Duration::from_secs(3));
+        group.sample_size(50);
+    }
+}
+
+/// Byte-at-a-time reference (the previous `constant_time_eq` implementation).
+#[inline(never)]
+fn constant_time_eq_bytewise(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter()
+        .zip(b.iter())
+        .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+        == 0
+}
+
+// =============================================================================
+// constant_time_eq: bytewise vs word-at-a-time
+// =============================================================================
+
+fn bench_constant_time_eq(c: &mut Criterion) {
+    let mut group = c.benchmark_group("constant_time_eq");
+    configure_group(&mut group);
+
+    for size in [32, 256, 4096] {
+        let a = vec![0xA5u8; size];
+        let b = vec![0xA5u8; size];
+
+        group.throughput(Throughput::Bytes(size as u64));
+
+        group.bench_with_input(BenchmarkId::new("bytewise", size), &size, |bench, _| {
+            bench.iter(|| constant_time_eq_bytewise(black_box(&a), black_box(&b)));
+        });
+
+        group.bench_with_input(BenchmarkId::new("wide", size), &size, |bench, _| {
+            bench.iter(|| constant_time_eq(black_box(&a), black_box(&b)));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_constant_time_eq);
+criterion_main!(benches);
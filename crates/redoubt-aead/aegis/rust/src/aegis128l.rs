@@ -0,0 +1,178 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! AEGIS-128L (draft-irtf-cfrg-aegis-aead) over the portable AES round.
+
+use crate::aes::{Block, LANES, aes_round8};
+use crate::{KEY_SIZE, NONCE_SIZE, TAG_SIZE};
+
+/// Bytes absorbed per state update (two blocks).
+const RATE: usize = 32;
+
+const C0: Block = [
+    0x00, 0x01, 0x01, 0x02, 0x03, 0x05, 0x08, 0x0d, 0x15, 0x22, 0x37, 0x59, 0x90, 0xe9, 0x79, 0x62,
+];
+const C1: Block = [
+    0xdb, 0x3d, 0x18, 0x55, 0x6d, 0xc2, 0x2f, 0xf1, 0x20, 0x11, 0x31, 0x42, 0x73, 0xb5, 0x28, 0xdd,
+];
+
+#[inline(always)]
+fn xor(a: &Block, b: &Block) -> Block {
+    core::array::from_fn(|i| a[i] ^ b[i])
+}
+
+#[inline(always)]
+fn and(a: &Block, b: &Block) -> Block {
+    core::array::from_fn(|i| a[i] & b[i])
+}
+
+/// The eight-block AEGIS-128L state. Zeroized on drop.
+pub(crate) struct State {
+    s: [Block; LANES],
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        redoubt_util::fast_zeroize_slice(self.s.as_flattened_mut());
+    }
+}
+
+impl State {
+    pub(crate) fn new(key: &[u8; KEY_SIZE], nonce: &[u8; NONCE_SIZE]) -> Self {
+        let key_nonce = xor(key, nonce);
+
+        let mut state = Self {
+            s: [
+                key_nonce,
+                C1,
+                C0,
+                C1,
+                key_nonce,
+                xor(key, &C0),
+                xor(key, &C1),
+                xor(key, &C0),
+            ],
+        };
+
+        for _ in 0..10 {
+            state.update(nonce, key);
+        }
+
+        state
+    }
+
+    /// `S'[i] = AESRound(S[i - 1], S[i])`, with `m0` and `m1` mixed into the
+    /// round keys of blocks 0 and 4.
+    fn update(&mut self, m0: &Block, m1: &Block) {
+        let mut round_keys = self.s;
+        round_keys[0] = xor(&round_keys[0], m0);
+        round_keys[4] = xor(&round_keys[4], m1);
+
+        self.s.rotate_right(1);
+        aes_round8(&mut self.s, &round_keys);
+
+        redoubt_util::fast_zeroize_slice(round_keys.as_flattened_mut());
+    }
+
+    fn update_with_chunk(&mut self, chunk: &[u8; RATE]) {
+        let (m0, m1) = split(chunk);
+        self.update(&m0, &m1);
+    }
+
+    fn keystream(&self) -> [u8; RATE] {
+        let s = &self.s;
+        let z0 = xor(&xor(&s[6], &s[1]), &and(&s[2], &s[3]));
+        let z1 = xor(&xor(&s[2], &s[5]), &and(&s[6], &s[7]));
+
+        let mut z = [0u8; RATE];
+        z[..16].copy_from_slice(&z0);
+        z[16..].copy_from_slice(&z1);
+        z
+    }
+
+    /// Absorbs associated data, zero-padding the final partial chunk.
+    pub(crate) fn absorb(&mut self, aad: &[u8]) {
+        let mut chunks = aad.chunks_exact(RATE);
+
+        for chunk in chunks.by_ref() {
+            self.update_with_chunk(chunk.try_into().expect("chunk is RATE bytes"));
+        }
+
+        let tail = chunks.remainder();
+
+        if !tail.is_empty() {
+            let mut padded = [0u8; RATE];
+            padded[..tail.len()].copy_from_slice(tail);
+            self.update_with_chunk(&padded);
+        }
+    }
+
+    /// Encrypts `data` in place.
+    pub(crate) fn encrypt(&mut self, data: &mut [u8]) {
+        for chunk in data.chunks_mut(RATE) {
+            // The final chunk is zero-padded; the padding is absorbed as plaintext.
+            let mut padded = [0u8; RATE];
+            padded[..chunk.len()].copy_from_slice(chunk);
+
+            let mut keystream = self.keystream();
+            self.update_with_chunk(&padded);
+
+            for ((out, plain), key_byte) in chunk.iter_mut().zip(padded).zip(keystream) {
+                *out = plain ^ key_byte;
+            }
+
+            redoubt_util::fast_zeroize_slice(&mut padded);
+            redoubt_util::fast_zeroize_slice(&mut keystream);
+        }
+    }
+
+    /// Decrypts `data` in place.
+    pub(crate) fn decrypt(&mut self, data: &mut [u8]) {
+        for chunk in data.chunks_mut(RATE) {
+            let mut plain = self.keystream();
+
+            for (plain_byte, cipher_byte) in plain.iter_mut().zip(chunk.iter()) {
+                *plain_byte ^= cipher_byte;
+            }
+
+            // Only the real plaintext is absorbed: the keystream past the end
+            // of a partial chunk is cleared before the update.
+            plain[chunk.len()..].fill(0);
+            self.update_with_chunk(&plain);
+            chunk.copy_from_slice(&plain[..chunk.len()]);
+
+            redoubt_util::fast_zeroize_slice(&mut plain);
+        }
+    }
+
+    pub(crate) fn finalize(mut self, aad_len: usize, msg_len: usize) -> [u8; TAG_SIZE] {
+        let mut lengths = [0u8; 16];
+        lengths[..8].copy_from_slice(&((aad_len as u64) * 8).to_le_bytes());
+        lengths[8..].copy_from_slice(&((msg_len as u64) * 8).to_le_bytes());
+
+        let t = xor(&self.s[2], &lengths);
+
+        for _ in 0..7 {
+            self.update(&t, &t);
+        }
+
+        let s = &self.s;
+        let mut tag = s[0];
+
+        for block in &s[1..7] {
+            tag = xor(&tag, block);
+        }
+
+        tag
+    }
+}
+
+#[inline(always)]
+fn split(chunk: &[u8; RATE]) -> (Block, Block) {
+    let mut m0 = [0u8; 16];
+    let mut m1 = [0u8; 16];
+    m0.copy_from_slice(&chunk[..16]);
+    m1.copy_from_slice(&chunk[16..]);
+    (m0, m1)
+}
@@ -0,0 +1,237 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! Constant-time AES round function, eight blocks at a time.
+//!
+//! AEGIS-128L updates its eight state blocks with eight independent AES
+//! rounds, so the 128 S-box lookups of one update are evaluated together as a
+//! bitsliced Boolean circuit over `u128` bit planes (Boyar-Peralta). There are
+//! no table lookups and no secret-dependent branches or memory accesses.
+
+pub(crate) type Block = [u8; 16];
+
+/// Number of blocks processed by [`aes_round8`].
+pub(crate) const LANES: usize = 8;
+
+/// `blocks[i] = MixColumns(ShiftRows(SubBytes(blocks[i]))) ^ round_keys[i]`.
+#[inline]
+pub(crate) fn aes_round8(blocks: &mut [Block; LANES], round_keys: &[Block; LANES]) {
+    sub_bytes8(blocks);
+
+    for (block, round_key) in blocks.iter_mut().zip(round_keys.iter()) {
+        shift_rows(block);
+        mix_columns(block);
+
+        for (byte, key_byte) in block.iter_mut().zip(round_key.iter()) {
+            *byte ^= key_byte;
+        }
+    }
+}
+
+/// Applies the AES S-box to all 128 bytes of `blocks`.
+#[inline]
+pub(crate) fn sub_bytes8(blocks: &mut [Block; LANES]) {
+    let bytes = blocks.as_flattened_mut();
+
+    // Plane `b` holds bit `b` of every byte: bit `k` of `planes[b]` is bit
+    // `b` of `bytes[k]`.
+    let mut planes = [0u128; 8];
+
+    for (k, byte) in bytes.iter().enumerate() {
+        for (b, plane) in planes.iter_mut().enumerate() {
+            *plane |= (((byte >> b) & 1) as u128) << k;
+        }
+    }
+
+    sbox_bitsliced(&mut planes);
+
+    for (k, byte) in bytes.iter_mut().enumerate() {
+        let mut value = 0u8;
+
+        for (b, plane) in planes.iter().enumerate() {
+            value |= (((plane >> k) & 1) as u8) << b;
+        }
+
+        *byte = value;
+    }
+
+    redoubt_util::fast_zeroize_slice(&mut planes);
+}
+
+/// AES S-box as a 113-gate circuit (Boyar-Peralta, "A depth-16 circuit for
+/// the AES S-box"). `q[0]` is the least significant bit plane.
+#[inline(always)]
+fn sbox_bitsliced(q: &mut [u128; 8]) {
+    let x0 = q[7];
+    let x1 = q[6];
+    let x2 = q[5];
+    let x3 = q[4];
+    let x4 = q[3];
+    let x5 = q[2];
+    let x6 = q[1];
+    let x7 = q[0];
+
+    // Top linear transformation
+    let y14 = x3 ^ x5;
+    let y13 = x0 ^ x6;
+    let y9 = x0 ^ x3;
+    let y8 = x0 ^ x5;
+    let t0 = x1 ^ x2;
+    let y1 = t0 ^ x7;
+    let y4 = y1 ^ x3;
+    let y12 = y13 ^ y14;
+    let y2 = y1 ^ x0;
+    let y5 = y1 ^ x6;
+    let y3 = y5 ^ y8;
+    let t1 = x4 ^ y12;
+    let y15 = t1 ^ x5;
+    let y20 = t1 ^ x1;
+    let y6 = y15 ^ x7;
+    let y10 = y15 ^ t0;
+    let y11 = y20 ^ y9;
+    let y7 = x7 ^ y11;
+    let y17 = y10 ^ y11;
+    let y19 = y10 ^ y8;
+    let y16 = t0 ^ y11;
+    let y21 = y13 ^ y16;
+    let y18 = x0 ^ y16;
+
+    // Non-linear section
+    let t2 = y12 & y15;
+    let t3 = y3 & y6;
+    let t4 = t3 ^ t2;
+    let t5 = y4 & x7;
+    let t6 = t5 ^ t2;
+    let t7 = y13 & y16;
+    let t8 = y5 & y1;
+    let t9 = t8 ^ t7;
+    let t10 = y2 & y7;
+    let t11 = t10 ^ t7;
+    let t12 = y9 & y11;
+    let t13 = y14 & y17;
+    let t14 = t13 ^ t12;
+    let t15 = y8 & y10;
+    let t16 = t15 ^ t12;
+    let t17 = t4 ^ t14;
+    let t18 = t6 ^ t16;
+    let t19 = t9 ^ t14;
+    let t20 = t11 ^ t16;
+    let t21 = t17 ^ y20;
+    let t22 = t18 ^ y19;
+    let t23 = t19 ^ y21;
+    let t24 = t20 ^ y18;
+
+    let t25 = t21 ^ t22;
+    let t26 = t21 & t23;
+    let t27 = t24 ^ t26;
+    let t28 = t25 & t27;
+    let t29 = t28 ^ t22;
+    let t30 = t23 ^ t24;
+    let t31 = t22 ^ t26;
+    let t32 = t31 & t30;
+    let t33 = t32 ^ t24;
+    let t34 = t23 ^ t33;
+    let t35 = t27 ^ t33;
+    let t36 = t24 & t35;
+    let t37 = t36 ^ t34;
+    let t38 = t27 ^ t36;
+    let t39 = t29 & t38;
+    let t40 = t25 ^ t39;
+
+    let t41 = t40 ^ t37;
+    let t42 = t29 ^ t33;
+    let t43 = t29 ^ t40;
+    let t44 = t33 ^ t37;
+    let t45 = t42 ^ t41;
+    let z0 = t44 & y15;
+    let z1 = t37 & y6;
+    let z2 = t33 & x7;
+    let z3 = t43 & y16;
+    let z4 = t40 & y1;
+    let z5 = t29 & y7;
+    let z6 = t42 & y11;
+    let z7 = t45 & y17;
+    let z8 = t41 & y10;
+    let z9 = t44 & y12;
+    let z10 = t37 & y3;
+    let z11 = t33 & y4;
+    let z12 = t43 & y13;
+    let z13 = t40 & y5;
+    let z14 = t29 & y2;
+    let z15 = t42 & y9;
+    let z16 = t45 & y14;
+    let z17 = t41 & y8;
+
+    // Bottom linear transformation
+    let t46 = z15 ^ z16;
+    let t47 = z10 ^ z11;
+    let t48 = z5 ^ z13;
+    let t49 = z9 ^ z10;
+    let t50 = z2 ^ z12;
+    let t51 = z2 ^ z5;
+    let t52 = z7 ^ z8;
+    let t53 = z0 ^ z3;
+    let t54 = z6 ^ z7;
+    let t55 = z16 ^ z17;
+    let t56 = z12 ^ t48;
+    let t57 = t50 ^ t53;
+    let t58 = z4 ^ t46;
+    let t59 = z3 ^ t54;
+    let t60 = t46 ^ t57;
+    let t61 = z14 ^ t57;
+    let t62 = t52 ^ t58;
+    let t63 = t49 ^ t58;
+    let t64 = z4 ^ t59;
+    let t65 = t61 ^ t62;
+    let t66 = z1 ^ t63;
+    let s0 = t59 ^ t63;
+    let s6 = t56 ^ !t62;
+    let s7 = t48 ^ !t60;
+    let t67 = t64 ^ t65;
+    let s3 = t53 ^ t66;
+    let s4 = t51 ^ t66;
+    let s5 = t47 ^ t65;
+    let s1 = t64 ^ !s3;
+    let s2 = t55 ^ !t67;
+
+    q[7] = s0;
+    q[6] = s1;
+    q[5] = s2;
+    q[4] = s3;
+    q[3] = s4;
+    q[2] = s5;
+    q[1] = s6;
+    q[0] = s7;
+}
+
+/// Row `r` rotates left by `r` positions (column-major state).
+#[inline(always)]
+fn shift_rows(block: &mut Block) {
+    let input = *block;
+
+    for column in 0..4 {
+        for row in 0..4 {
+            block[row + 4 * column] = input[row + 4 * ((column + row) % 4)];
+        }
+    }
+}
+
+/// Multiplication by `x` in GF(2^8), without branching on the input.
+#[inline(always)]
+fn xtime(x: u8) -> u8 {
+    (x << 1) ^ ((x >> 7) * 0x1b)
+}
+
+#[inline(always)]
+fn mix_columns(block: &mut Block) {
+    for column in block.chunks_exact_mut(4) {
+        let (a0, a1, a2, a3) = (column[0], column[1], column[2], column[3]);
+        let all = a0 ^ a1 ^ a2 ^ a3;
+
+        column[0] = a0 ^ all ^ xtime(a0 ^ a1);
+        column[1] = a1 ^ all ^ xtime(a1 ^ a2);
+        column[2] = a2 ^ all ^ xtime(a2 ^ a3);
+        column[3] = a3 ^ all ^ xtime(a3 ^ a0);
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! Portable pure Rust AEGIS-128L AEAD implementation.
+//!
+//! Produces the same ciphertexts and tags as the x86_64 and aarch64 assembly
+//! backends, for targets without hardware AES (other architectures, WASM).
+//! The AES round is a bitsliced Boolean circuit, so timing does not depend
+//! on keys or data. It is considerably slower than the hardware backends.
+//!
+//! ## License
+//!
+//! GPL-3.0-only
+
+#![cfg_attr(not(test), no_std)]
+#![warn(missing_docs)]
+
+extern crate alloc;
+
+#[cfg(test)]
+mod tests;
+
+mod aegis128l;
+mod aes;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use redoubt_aead_core::{AeadApi, AeadError, EntropyError};
+
+use aegis128l::State;
+
+/// Key size: 128 bits (16 bytes).
+pub const KEY_SIZE: usize = 16;
+/// Nonce size: 128 bits (16 bytes).
+pub const NONCE_SIZE: usize = 16;
+/// Tag size: 128 bits (16 bytes).
+pub const TAG_SIZE: usize = 16;
+
+/// Portable pure Rust AEGIS-128L backend.
+pub struct Aegis128LRustBackend;
+
+impl AeadApi for Aegis128LRustBackend {
+    fn api_encrypt(
+        &mut self,
+        key: &[u8],
+        nonce: &[u8],
+        aad: &[u8],
+        data: &mut [u8],
+        tag: &mut [u8],
+    ) -> Result<(), AeadError> {
+        let key: &[u8; KEY_SIZE] = key.try_into().map_err(|_| AeadError::InvalidKeySize)?;
+        let nonce: &[u8; NONCE_SIZE] = nonce.try_into().map_err(|_| AeadError::InvalidNonceSize)?;
+        let tag: &mut [u8; TAG_SIZE] = tag.try_into().map_err(|_| AeadError::InvalidTagSize)?;
+
+        let mut state = State::new(key, nonce);
+        state.absorb(aad);
+        state.encrypt(data);
+        *tag = state.finalize(aad.len(), data.len());
+
+        Ok(())
+    }
+
+    fn api_decrypt(
+        &mut self,
+        key: &[u8],
+        nonce: &[u8],
+        aad: &[u8],
+        data: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), AeadError> {
+        let key: &[u8; KEY_SIZE] = key.try_into().map_err(|_| AeadError::InvalidKeySize)?;
+        let nonce: &[u8; NONCE_SIZE] = nonce.try_into().map_err(|_| AeadError::InvalidNonceSize)?;
+        let tag: &[u8; TAG_SIZE] = tag.try_into().map_err(|_| AeadError::InvalidTagSize)?;
+
+        let mut state = State::new(key, nonce);
+        state.absorb(aad);
+        state.decrypt(data);
+        let mut computed_tag = state.finalize(aad.len(), data.len());
+
+        let authentic = redoubt_util::constant_time_eq(&computed_tag, tag);
+        redoubt_util::fast_zeroize_slice(&mut computed_tag);
+
+        if authentic {
+            Ok(())
+        } else {
+            // Never hand back unauthenticated plaintext
+            redoubt_util::fast_zeroize_slice(data);
+            Err(AeadError::AuthenticationFailed)
+        }
+    }
+
+    fn api_generate_nonce(&mut self) -> Result<Vec<u8>, EntropyError> {
+        let mut nonce = vec![0u8; NONCE_SIZE];
+        redoubt_rand::fill_with_random_bytes(&mut nonce)?;
+
+        Ok(nonce)
+    }
+
+    fn api_key_size(&self) -> usize {
+        KEY_SIZE
+    }
+
+    fn api_nonce_size(&self) -> usize {
+        NONCE_SIZE
+    }
+
+    fn api_tag_size(&self) -> usize {
+        TAG_SIZE
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use crate::aes::{Block, LANES, aes_round8, sub_bytes8};
+
+/// Multiplication in GF(2^8) modulo x^8 + x^4 + x^3 + x + 1.
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0u8;
+
+    while b != 0 {
+        if b & 1 == 1 {
+            product ^= a;
+        }
+
+        let carry = a & 0x80 != 0;
+        a <<= 1;
+        if carry {
+            a ^= 0x1b;
+        }
+        b >>= 1;
+    }
+
+    product
+}
+
+/// Reference S-box: multiplicative inverse followed by the affine transform.
+fn reference_sbox(x: u8) -> u8 {
+    // x^254 = x^-1 (and maps 0 to 0)
+    let mut inverse = 1u8;
+    for _ in 0..254 {
+        inverse = gf_mul(inverse, x);
+    }
+
+    inverse
+        ^ inverse.rotate_left(1)
+        ^ inverse.rotate_left(2)
+        ^ inverse.rotate_left(3)
+        ^ inverse.rotate_left(4)
+        ^ 0x63
+}
+
+// =============================================================================
+// sub_bytes8()
+// =============================================================================
+
+#[test]
+fn test_sub_bytes8_matches_reference_sbox_exhaustively() {
+    // Two passes cover all 256 inputs across all 128 lanes
+    for offset in [0u8, 128] {
+        let mut blocks: [Block; LANES] =
+            core::array::from_fn(|i| core::array::from_fn(|j| offset + (i * 16 + j) as u8));
+        let expected: [Block; LANES] = core::array::from_fn(|i| blocks[i].map(reference_sbox));
+
+        sub_bytes8(&mut blocks);
+
+        assert_eq!(blocks, expected);
+    }
+}
+
+#[test]
+fn test_reference_sbox_known_values() {
+    assert_eq!(reference_sbox(0x00), 0x63);
+    assert_eq!(reference_sbox(0x01), 0x7c);
+    assert_eq!(reference_sbox(0x53), 0xed);
+    assert_eq!(reference_sbox(0xff), 0x16);
+}
+
+// =============================================================================
+// aes_round8()
+// =============================================================================
+
+#[test]
+fn test_aes_round8_matches_fips197_round_one() {
+    // FIPS-197 Appendix B: state at the start of round 1 and the round 1 key
+    let start: Block = [
+        0x19, 0x3d, 0xe3, 0xbe, 0xa0, 0xf4, 0xe2, 0x2b, 0x9a, 0xc6, 0x8d, 0x2a, 0xe9, 0xf8, 0x48,
+        0x08,
+    ];
+    let round_key: Block = [
+        0xa0, 0xfa, 0xfe, 0x17, 0x88, 0x54, 0x2c, 0xb1, 0x23, 0xa3, 0x39, 0x39, 0x2a, 0x6c, 0x76,
+        0x05,
+    ];
+    let expected: Block = [
+        0xa4, 0x9c, 0x7f, 0xf2, 0x68, 0x9f, 0x35, 0x2b, 0x6b, 0x5b, 0xea, 0x43, 0x02, 0x6a, 0x50,
+        0x49,
+    ];
+
+    let mut blocks = [start; LANES];
+    aes_round8(&mut blocks, &[round_key; LANES]);
+
+    assert_eq!(blocks, [expected; LANES]);
+}
@@ -0,0 +1,60 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use redoubt_aead_core::{AeadApi, AeadError};
+
+use crate::Aegis128LRustBackend;
+
+#[test]
+fn test_aegis128l_wycheproof() {
+    redoubt_aead_aegis_wycheproof::run_aegis128l_wycheproof_tests(&mut Aegis128LRustBackend);
+}
+
+#[test]
+fn test_aegis128l_roundtrip() {
+    redoubt_aead_aegis_wycheproof::run_aegis128l_roundtrip_tests(&mut Aegis128LRustBackend);
+}
+
+#[test]
+fn test_aegis128l_flipped_tag() {
+    redoubt_aead_aegis_wycheproof::run_aegis128l_flipped_tag_tests(&mut Aegis128LRustBackend);
+}
+
+#[test]
+fn test_aegis128l_invalid_size_encrypt() {
+    redoubt_aead_aegis_wycheproof::run_aegis128l_invalid_size_encrypt_tests(
+        &mut Aegis128LRustBackend,
+    );
+}
+
+#[test]
+fn test_aegis128l_invalid_size_decrypt() {
+    redoubt_aead_aegis_wycheproof::run_aegis128l_invalid_size_decrypt_tests(
+        &mut Aegis128LRustBackend,
+    );
+}
+
+#[test]
+fn test_aegis128l_generate_nonce() {
+    redoubt_aead_aegis_wycheproof::run_aegis128l_generate_nonce_test(&mut Aegis128LRustBackend);
+}
+
+#[test]
+fn test_decrypt_zeroizes_data_on_authentication_failure() {
+    let mut backend = Aegis128LRustBackend;
+    let key = [0x42u8; 16];
+    let nonce = [0x24u8; 16];
+    let mut data = *b"unauthenticated plaintext must not leak";
+    let mut tag = [0u8; 16];
+
+    backend
+        .api_encrypt(&key, &nonce, b"aad", &mut data, &mut tag)
+        .expect("Failed to api_encrypt(..)");
+    tag[0] ^= 1;
+
+    let result = backend.api_decrypt(&key, &nonce, b"aad", &mut data, &tag);
+
+    assert_eq!(result, Err(AeadError::AuthenticationFailed));
+    assert!(data.iter().all(|b| *b == 0));
+}
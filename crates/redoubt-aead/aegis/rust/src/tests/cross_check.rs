@@ -0,0 +1,66 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! Cross-checks the portable backend against the hardware one on this host.
+
+use redoubt_aead_core::AeadApi;
+
+use crate::Aegis128LRustBackend;
+
+#[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+fn hardware_backend() -> Option<redoubt_aead_aegis_x86::Aegis128LX86Backend> {
+    std::arch::is_x86_feature_detected!("aes")
+        .then_some(redoubt_aead_aegis_x86::Aegis128LX86Backend)
+}
+
+#[cfg(target_arch = "aarch64")]
+fn hardware_backend() -> Option<redoubt_aead_aegis_arm::Aegis128LArmBackend> {
+    std::arch::is_aarch64_feature_detected!("aes")
+        .then_some(redoubt_aead_aegis_arm::Aegis128LArmBackend)
+}
+
+fn pattern(len: usize, seed: u8) -> Vec<u8> {
+    (0..len)
+        .map(|i| (i as u8).wrapping_mul(31).wrapping_add(seed))
+        .collect()
+}
+
+#[test]
+fn test_matches_hardware_backend() {
+    let Some(mut hardware) = hardware_backend() else {
+        return;
+    };
+    let mut portable = Aegis128LRustBackend;
+
+    // Covers empty, partial, exact and multi-chunk (32-byte) inputs
+    for aad_len in [0, 1, 16, 31, 32, 33, 64, 100] {
+        for msg_len in [0, 1, 15, 16, 17, 31, 32, 33, 63, 64, 65, 200, 1000] {
+            let key = pattern(16, aad_len as u8);
+            let nonce = pattern(16, msg_len as u8);
+            let aad = pattern(aad_len, 0xA5);
+            let plaintext = pattern(msg_len, 0x5A);
+
+            let mut hardware_data = plaintext.clone();
+            let mut hardware_tag = [0u8; 16];
+            hardware
+                .api_encrypt(&key, &nonce, &aad, &mut hardware_data, &mut hardware_tag)
+                .expect("Failed to api_encrypt(..)");
+
+            let mut portable_data = plaintext.clone();
+            let mut portable_tag = [0u8; 16];
+            portable
+                .api_encrypt(&key, &nonce, &aad, &mut portable_data, &mut portable_tag)
+                .expect("Failed to api_encrypt(..)");
+
+            assert_eq!(portable_data, hardware_data, "aad {aad_len}, msg {msg_len}");
+            assert_eq!(portable_tag, hardware_tag, "aad {aad_len}, msg {msg_len}");
+
+            portable
+                .api_decrypt(&key, &nonce, &aad, &mut hardware_data, &hardware_tag)
+                .expect("Failed to api_decrypt(..)");
+
+            assert_eq!(hardware_data, plaintext);
+        }
+    }
+}
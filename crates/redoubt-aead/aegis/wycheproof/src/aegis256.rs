@@ -0,0 +1,165 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! Test runners for AEGIS-256 backends.
+//!
+//! Uses the test vectors from draft-irtf-cfrg-aegis-aead-17, Appendix A.3.
+
+use redoubt_aead_core::AeadApi;
+
+use super::wycheproof::{
+    Flag, TestCase, TestResult, run_aegis128l_generate_nonce_test,
+    run_aegis128l_invalid_size_decrypt_tests, run_aegis128l_invalid_size_encrypt_tests,
+    run_flipped_tag_tests, run_roundtrip_tests, run_vector_tests,
+};
+
+const KEY: &str = "1001000000000000000000000000000000000000000000000000000000000000";
+const NONCE: &str = "1000020000000000000000000000000000000000000000000000000000000000";
+
+/// Returns the AEGIS-256 test vectors from draft-irtf-cfrg-aegis-aead-17, A.3.
+pub fn aegis256_rfc_vectors() -> Vec<TestCase> {
+    vec![
+        TestCase {
+            tc_id: 1,
+            comment: "A.3.2 Test Vector 1".into(),
+            flags: vec![Flag::Ktv],
+            key: KEY.into(),
+            iv: NONCE.into(),
+            aad: "".into(),
+            msg: "00000000000000000000000000000000".into(),
+            ct: "754fc3d8c973246dcc6d741412a4b236".into(),
+            tag: "3fe91994768b332ed7f570a19ec5896e".into(),
+            result: TestResult::Valid,
+        },
+        TestCase {
+            tc_id: 2,
+            comment: "A.3.3 Test Vector 2".into(),
+            flags: vec![Flag::Ktv],
+            key: KEY.into(),
+            iv: NONCE.into(),
+            aad: "".into(),
+            msg: "".into(),
+            ct: "".into(),
+            tag: "e3def978a0f054afd1e761d7553afba3".into(),
+            result: TestResult::Valid,
+        },
+        TestCase {
+            tc_id: 3,
+            comment: "A.3.4 Test Vector 3".into(),
+            flags: vec![Flag::Ktv],
+            key: KEY.into(),
+            iv: NONCE.into(),
+            aad: "0001020304050607".into(),
+            msg: "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f".into(),
+            ct: "f373079ed84b2709faee373584585d60accd191db310ef5d8b11833df9dec711".into(),
+            tag: "8d86f91ee606e9ff26a01b64ccbdd91d".into(),
+            result: TestResult::Valid,
+        },
+        TestCase {
+            tc_id: 4,
+            comment: "A.3.5 Test Vector 4".into(),
+            flags: vec![Flag::Ktv],
+            key: KEY.into(),
+            iv: NONCE.into(),
+            aad: "0001020304050607".into(),
+            msg: "000102030405060708090a0b0c0d".into(),
+            ct: "f373079ed84b2709faee37358458".into(),
+            tag: "c60b9c2d33ceb058f96e6dd03c215652".into(),
+            result: TestResult::Valid,
+        },
+        TestCase {
+            tc_id: 5,
+            comment: "A.3.6 Test Vector 5".into(),
+            flags: vec![Flag::Ktv],
+            key: KEY.into(),
+            iv: NONCE.into(),
+            aad: "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20212223242526272829"
+                .into(),
+            msg: "101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f3031323334353637"
+                .into(),
+            ct: "57754a7d09963e7c787583a2e7b859bb24fa1e04d49fd550b2511a358e3bca252a9b1b8b30cc4a67"
+                .into(),
+            tag: "ab8a7d53fd0e98d727accca94925e128".into(),
+            result: TestResult::Valid,
+        },
+        TestCase {
+            tc_id: 6,
+            comment: "A.3.7 Test Vector 6 (wrong key)".into(),
+            flags: vec![Flag::Ktv],
+            key: "1000020000000000000000000000000000000000000000000000000000000000".into(),
+            iv: "1001000000000000000000000000000000000000000000000000000000000000".into(),
+            aad: "0001020304050607".into(),
+            msg: "".into(),
+            ct: "f373079ed84b2709faee37358458".into(),
+            tag: "c60b9c2d33ceb058f96e6dd03c215652".into(),
+            result: TestResult::Invalid,
+        },
+        TestCase {
+            tc_id: 7,
+            comment: "A.3.8 Test Vector 7 (modified ciphertext)".into(),
+            flags: vec![Flag::Ktv],
+            key: KEY.into(),
+            iv: NONCE.into(),
+            aad: "0001020304050607".into(),
+            msg: "".into(),
+            ct: "f373079ed84b2709faee37358459".into(),
+            tag: "c60b9c2d33ceb058f96e6dd03c215652".into(),
+            result: TestResult::Invalid,
+        },
+        TestCase {
+            tc_id: 8,
+            comment: "A.3.9 Test Vector 8 (modified AAD)".into(),
+            flags: vec![Flag::Ktv],
+            key: KEY.into(),
+            iv: NONCE.into(),
+            aad: "0001020304050608".into(),
+            msg: "".into(),
+            ct: "f373079ed84b2709faee37358458".into(),
+            tag: "c60b9c2d33ceb058f96e6dd03c215652".into(),
+            result: TestResult::Invalid,
+        },
+        TestCase {
+            tc_id: 9,
+            comment: "A.3.10 Test Vector 9 (modified tag)".into(),
+            flags: vec![Flag::Ktv, Flag::ModifiedTag],
+            key: KEY.into(),
+            iv: NONCE.into(),
+            aad: "0001020304050607".into(),
+            msg: "".into(),
+            ct: "f373079ed84b2709faee37358458".into(),
+            tag: "c60b9c2d33ceb058f96e6dd03c215653".into(),
+            result: TestResult::Invalid,
+        },
+    ]
+}
+
+/// Run all AEGIS-256 RFC test vectors against a backend.
+pub fn run_aegis256_rfc_vector_tests(backend: &mut impl AeadApi) {
+    run_vector_tests(backend, &aegis256_rfc_vectors(), "AEGIS-256 RFC");
+}
+
+/// Run roundtrip test (decrypt then re-encrypt) on valid vectors.
+pub fn run_aegis256_roundtrip_tests(backend: &mut impl AeadApi) {
+    run_roundtrip_tests(backend, &aegis256_rfc_vectors(), "AEGIS-256");
+}
+
+/// Run flipped-tag rejection test on valid vectors.
+pub fn run_aegis256_flipped_tag_tests(backend: &mut impl AeadApi) {
+    run_flipped_tag_tests(backend, &aegis256_rfc_vectors(), "AEGIS-256");
+}
+
+/// Run invalid size tests for encrypt error paths.
+pub fn run_aegis256_invalid_size_encrypt_tests(backend: &mut impl AeadApi) {
+    run_aegis128l_invalid_size_encrypt_tests(backend);
+}
+
+/// Run invalid size tests for decrypt error paths.
+pub fn run_aegis256_invalid_size_decrypt_tests(backend: &mut impl AeadApi) {
+    run_aegis128l_invalid_size_decrypt_tests(backend);
+}
+
+/// Run nonce generation test.
+pub fn run_aegis256_generate_nonce_test(backend: &mut impl AeadApi) {
+    run_aegis128l_generate_nonce_test(backend);
+}
@@ -2,9 +2,10 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // See LICENSE in the repository root for full license text.
 
-//! Shared test suite for AEGIS-128L backends.
+//! Shared test suite for AEGIS-128L and AEGIS-256 backends.
 //!
-//! Includes Wycheproof conformance vectors and implementation-specific tests
+//! Includes Wycheproof conformance vectors (AEGIS-128L), RFC test vectors
+//! (AEGIS-256) and implementation-specific tests
 //! for error paths common to both x86 and ARM assembly implementations.
 //!
 //! ## License
@@ -13,9 +14,15 @@
 
 #![warn(missing_docs)]
 
+mod aegis256;
 mod wycheproof;
 mod wycheproof_vectors;
 
+pub use aegis256::{
+    aegis256_rfc_vectors, run_aegis256_flipped_tag_tests, run_aegis256_generate_nonce_test,
+    run_aegis256_invalid_size_decrypt_tests, run_aegis256_invalid_size_encrypt_tests,
+    run_aegis256_rfc_vector_tests, run_aegis256_roundtrip_tests,
+};
 pub use wycheproof::{
     run_aegis128l_flipped_tag_tests, run_aegis128l_generate_nonce_test,
     run_aegis128l_invalid_size_decrypt_tests, run_aegis128l_invalid_size_encrypt_tests,
@@ -127,7 +127,24 @@ fn run_test_case(backend: &mut impl AeadApi, tc: &TestCase) -> Result<(), String
 pub fn run_aegis128l_wycheproof_tests(backend: &mut impl AeadApi) {
     use super::wycheproof_vectors::test_vectors;
 
-    let vectors = test_vectors();
+    run_vector_tests(backend, &test_vectors(), "AEGIS-128L Wycheproof");
+}
+
+/// Run roundtrip test (decrypt then re-encrypt) on valid vectors.
+pub fn run_aegis128l_roundtrip_tests(backend: &mut impl AeadApi) {
+    use super::wycheproof_vectors::test_vectors;
+
+    run_roundtrip_tests(backend, &test_vectors(), "AEGIS-128L");
+}
+
+/// Run flipped-tag rejection test on valid vectors.
+pub fn run_aegis128l_flipped_tag_tests(backend: &mut impl AeadApi) {
+    use super::wycheproof_vectors::test_vectors;
+
+    run_flipped_tag_tests(backend, &test_vectors(), "AEGIS-128L");
+}
+
+pub(crate) fn run_vector_tests(backend: &mut impl AeadApi, vectors: &[TestCase], label: &str) {
     let mut failures = Vec::new();
 
     for tc in vectors.iter() {
@@ -138,7 +155,8 @@ pub fn run_aegis128l_wycheproof_tests(backend: &mut impl AeadApi) {
 
     if !failures.is_empty() {
         panic!(
-            "AEGIS-128L Wycheproof test failures ({}/{}):\n{}",
+            "{} test failures ({}/{}):\n{}",
+            label,
             failures.len(),
             vectors.len(),
             failures.join("\n")
@@ -146,11 +164,7 @@ pub fn run_aegis128l_wycheproof_tests(backend: &mut impl AeadApi) {
     }
 }
 
-/// Run roundtrip test (decrypt then re-encrypt) on valid vectors.
-pub fn run_aegis128l_roundtrip_tests(backend: &mut impl AeadApi) {
-    use super::wycheproof_vectors::test_vectors;
-
-    let vectors = test_vectors();
+pub(crate) fn run_roundtrip_tests(backend: &mut impl AeadApi, vectors: &[TestCase], label: &str) {
     let mut failures = Vec::new();
 
     for tc in vectors.iter() {
@@ -188,18 +202,15 @@ pub fn run_aegis128l_roundtrip_tests(backend: &mut impl AeadApi) {
 
     if !failures.is_empty() {
         panic!(
-            "AEGIS-128L roundtrip test failures ({}):\n{}",
+            "{} roundtrip test failures ({}):\n{}",
+            label,
             failures.len(),
             failures.join("\n")
         );
     }
 }
 
-/// Run flipped-tag rejection test on valid vectors.
-pub fn run_aegis128l_flipped_tag_tests(backend: &mut impl AeadApi) {
-    use super::wycheproof_vectors::test_vectors;
-
-    let vectors = test_vectors();
+pub(crate) fn run_flipped_tag_tests(backend: &mut impl AeadApi, vectors: &[TestCase], label: &str) {
     let mut failures = Vec::new();
 
     for tc in vectors.iter() {
@@ -231,7 +242,8 @@ pub fn run_aegis128l_flipped_tag_tests(backend: &mut impl AeadApi) {
 
     if !failures.is_empty() {
         panic!(
-            "AEGIS-128L flipped tag test failures ({}):\n{}",
+            "{} flipped tag test failures ({}):\n{}",
+            label,
             failures.len(),
             failures.join("\n")
         );
@@ -12,8 +12,10 @@ fn main() {
 
     cc::Build::new()
         .file("src/asm/aegis_128l_x86_64.S")
+        .file("src/asm/aegis_256_x86_64.S")
         .flag("-maes")
         .compile("aegis_asm");
 
     println!("cargo:rerun-if-changed=src/asm/aegis_128l_x86_64.S");
+    println!("cargo:rerun-if-changed=src/asm/aegis_256_x86_64.S");
 }
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // See LICENSE in the repository root for full license text.
 
-//! x86_64 assembly AEGIS-128L AEAD implementation.
+//! x86_64 assembly AEGIS-128L and AEGIS-256 AEAD implementations.
 //!
 //! ## License
 //!
@@ -29,6 +29,13 @@ pub const NONCE_SIZE: usize = 16;
 /// Tag size: 128 bits (16 bytes).
 pub const TAG_SIZE: usize = 16;
 
+/// AEGIS-256 key size: 256 bits (32 bytes).
+pub const AEGIS256_KEY_SIZE: usize = 32;
+/// AEGIS-256 nonce size: 256 bits (32 bytes).
+pub const AEGIS256_NONCE_SIZE: usize = 32;
+/// AEGIS-256 tag size: 128 bits (16 bytes).
+pub const AEGIS256_TAG_SIZE: usize = 16;
+
 #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
 unsafe extern "C" {
     fn aegis128l_encrypt(
@@ -51,6 +58,27 @@ unsafe extern "C" {
         expected_tag: *const [u8; 16],
         computed_tag: *mut [u8; 16],
     );
+
+    fn aegis256_encrypt(
+        key: *const [u8; 32],
+        nonce: *const [u8; 32],
+        aad: *const u8,
+        aad_len: usize,
+        plaintext: *mut u8,
+        plaintext_len: usize,
+        tag: *mut [u8; 16],
+    );
+
+    fn aegis256_decrypt(
+        key: *const [u8; 32],
+        nonce: *const [u8; 32],
+        aad: *const u8,
+        aad_len: usize,
+        ciphertext: *mut u8,
+        ciphertext_len: usize,
+        expected_tag: *const [u8; 16],
+        computed_tag: *mut [u8; 16],
+    );
 }
 
 /// x86_64 assembly AEGIS-128L backend.
@@ -139,14 +167,114 @@ impl AeadApi for Aegis128LX86Backend {
     }
 }
 
+/// x86_64 assembly AEGIS-256 backend.
+///
+/// Same construction as AEGIS-128L with a 256-bit key and a 256-bit nonce,
+/// for callers that want a larger security margin and random nonces that
+/// never realistically collide.
+pub struct Aegis256X86Backend;
+
+#[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+impl AeadApi for Aegis256X86Backend {
+    fn api_encrypt(
+        &mut self,
+        key: &[u8],
+        nonce: &[u8],
+        aad: &[u8],
+        data: &mut [u8],
+        tag: &mut [u8],
+    ) -> Result<(), AeadError> {
+        let key: &[u8; AEGIS256_KEY_SIZE] =
+            key.try_into().map_err(|_| AeadError::InvalidKeySize)?;
+        let nonce: &[u8; AEGIS256_NONCE_SIZE] =
+            nonce.try_into().map_err(|_| AeadError::InvalidNonceSize)?;
+        let tag: &mut [u8; AEGIS256_TAG_SIZE] =
+            tag.try_into().map_err(|_| AeadError::InvalidTagSize)?;
+
+        unsafe {
+            aegis256_encrypt(
+                key,
+                nonce,
+                aad.as_ptr(),
+                aad.len(),
+                data.as_mut_ptr(),
+                data.len(),
+                tag,
+            );
+        }
+
+        Ok(())
+    }
+
+    fn api_decrypt(
+        &mut self,
+        key: &[u8],
+        nonce: &[u8],
+        aad: &[u8],
+        data: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), AeadError> {
+        let key: &[u8; AEGIS256_KEY_SIZE] =
+            key.try_into().map_err(|_| AeadError::InvalidKeySize)?;
+        let nonce: &[u8; AEGIS256_NONCE_SIZE] =
+            nonce.try_into().map_err(|_| AeadError::InvalidNonceSize)?;
+        let tag: &[u8; AEGIS256_TAG_SIZE] =
+            tag.try_into().map_err(|_| AeadError::InvalidTagSize)?;
+
+        let mut computed_tag = [0u8; AEGIS256_TAG_SIZE];
+
+        unsafe {
+            aegis256_decrypt(
+                key,
+                nonce,
+                aad.as_ptr(),
+                aad.len(),
+                data.as_mut_ptr(),
+                data.len(),
+                tag,
+                &mut computed_tag,
+            );
+        }
+
+        if redoubt_util::constant_time_eq(&computed_tag, tag) {
+            Ok(())
+        } else {
+            Err(AeadError::AuthenticationFailed)
+        }
+    }
+
+    fn api_generate_nonce(&mut self) -> Result<Vec<u8>, EntropyError> {
+        let mut nonce = vec![0u8; AEGIS256_NONCE_SIZE];
+        redoubt_rand::fill_with_random_bytes(&mut nonce)?;
+
+        Ok(nonce)
+    }
+
+    fn api_key_size(&self) -> usize {
+        AEGIS256_KEY_SIZE
+    }
+
+    fn api_nonce_size(&self) -> usize {
+        AEGIS256_NONCE_SIZE
+    }
+
+    fn api_tag_size(&self) -> usize {
+        AEGIS256_TAG_SIZE
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
     use super::Aegis128LX86Backend;
 
+    #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+    use super::Aegis256X86Backend;
+
     #[test]
     fn instrumentation() {
         let _ = super::Aegis128LX86Backend;
+        let _ = super::Aegis256X86Backend;
     }
 
     #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
@@ -188,4 +316,44 @@ mod tests {
     fn test_aegis128l_generate_nonce() {
         redoubt_aead_aegis_wycheproof::run_aegis128l_generate_nonce_test(&mut Aegis128LX86Backend);
     }
+
+    #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+    #[test]
+    fn test_aegis256_rfc_vectors() {
+        redoubt_aead_aegis_wycheproof::run_aegis256_rfc_vector_tests(&mut Aegis256X86Backend);
+    }
+
+    #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+    #[test]
+    fn test_aegis256_roundtrip() {
+        redoubt_aead_aegis_wycheproof::run_aegis256_roundtrip_tests(&mut Aegis256X86Backend);
+    }
+
+    #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+    #[test]
+    fn test_aegis256_flipped_tag() {
+        redoubt_aead_aegis_wycheproof::run_aegis256_flipped_tag_tests(&mut Aegis256X86Backend);
+    }
+
+    #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+    #[test]
+    fn test_aegis256_invalid_size_encrypt() {
+        redoubt_aead_aegis_wycheproof::run_aegis256_invalid_size_encrypt_tests(
+            &mut Aegis256X86Backend,
+        );
+    }
+
+    #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+    #[test]
+    fn test_aegis256_invalid_size_decrypt() {
+        redoubt_aead_aegis_wycheproof::run_aegis256_invalid_size_decrypt_tests(
+            &mut Aegis256X86Backend,
+        );
+    }
+
+    #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+    #[test]
+    fn test_aegis256_generate_nonce() {
+        redoubt_aead_aegis_wycheproof::run_aegis256_generate_nonce_test(&mut Aegis256X86Backend);
+    }
 }
@@ -22,4 +22,12 @@ pub enum AeadError {
     /// Invalid tag size.
     #[error("invalid tag size")]
     InvalidTagSize,
+
+    /// Stream exceeded the maximum number of chunks (2^32).
+    #[error("stream counter overflow")]
+    StreamCounterOverflow,
+
+    /// Key commitment verification failed (wrong key or nonce).
+    #[error("key commitment mismatch")]
+    CommitmentMismatch,
 }
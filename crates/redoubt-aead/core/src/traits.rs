@@ -34,6 +34,10 @@ pub trait AeadBackend {
     );
 
     /// Decrypt ciphertext in-place after verifying authentication tag.
+    ///
+    /// Implementations must compare the computed tag against `tag` in constant
+    /// time (via `redoubt_util::constant_time_eq`), never with `==`, so the
+    /// position of the first mismatching byte is not observable.
     fn decrypt(
         &mut self,
         key: &Self::Key,
@@ -11,32 +11,50 @@
 //!
 //! ## Backend Selection
 //!
-//! - **WASI**: Always uses XChaCha20-Poly1305 (pure software)
+//! - **WASI**: No hardware backend: portable AEGIS-128L with the `pure-rust`
+//!   feature, XChaCha20-Poly1305 otherwise
 //! - **x86_64 (non-Windows) / aarch64 with AES**: Uses AEGIS-128L (hardware-accelerated)
+//! - **Otherwise, with the `pure-rust` feature**: Uses the portable constant-time
+//!   AEGIS-128L from `redoubt-aead-aegis-rust` (same outputs, slower)
 //! - **Otherwise**: Falls back to XChaCha20-Poly1305
+//!
+//! [`AeadVariant::Aegis256`] opts into AEGIS-256 (x86_64, non-Windows, with AES-NI)
+//! and uses the same fallback.
 
 extern crate alloc;
 
 use alloc::boxed::Box;
+use alloc::vec;
 use alloc::vec::Vec;
 
 use redoubt_aead_core::{AeadApi, AeadBackend, AeadError, EntropyError};
 use redoubt_aead_xchacha::XChacha20Poly1305;
+use redoubt_zero::FastZeroizable;
 
 use crate::feature_detector::FeatureDetector;
 
 #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
-use redoubt_aead_aegis_x86::Aegis128LX86Backend;
+use redoubt_aead_aegis_x86::{Aegis128LX86Backend, Aegis256X86Backend};
 
 #[cfg(target_arch = "aarch64")]
 use redoubt_aead_aegis_arm::Aegis128LArmBackend;
 
+#[cfg(feature = "pure-rust")]
+use redoubt_aead_aegis_rust::Aegis128LRustBackend;
+
+/// Authentication tag size in bytes, shared by every backend.
+pub const TAG_SIZE: usize = 16;
+
 /// Internal enum representing the selected backend implementation.
 enum AeadBackendImpl {
     #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
     Aegis128LX86(Aegis128LX86Backend),
+    #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+    Aegis256X86(Aegis256X86Backend),
     #[cfg(target_arch = "aarch64")]
     Aegis128LArm(Aegis128LArmBackend),
+    #[cfg(feature = "pure-rust")]
+    Aegis128LRust(Aegis128LRustBackend),
     XChacha20Poly1305(Box<XChacha20Poly1305<redoubt_rand::SystemEntropySource>>),
 }
 
@@ -47,6 +65,12 @@ pub enum AeadVariant {
     Auto,
     /// Force XChaCha20-Poly1305 regardless of hardware support.
     XChachaPoly1305,
+    /// Select AEGIS-256 (256-bit key and nonce) when hardware AES is available.
+    ///
+    /// Currently implemented for x86_64 (non-Windows). Elsewhere, or without
+    /// AES support, falls back to XChaCha20-Poly1305, which also uses a
+    /// 256-bit key.
+    Aegis256,
 }
 
 /// AEAD with automatic backend selection based on CPU capabilities.
@@ -70,6 +94,9 @@ impl From<AeadVariant> for Aead {
             AeadVariant::XChachaPoly1305 => Self {
                 backend: AeadBackendImpl::XChacha20Poly1305(Box::default()),
             },
+            AeadVariant::Aegis256 => {
+                Self::new_aegis256_with_feature_detector(FeatureDetector::new())
+            }
         }
     }
 }
@@ -98,6 +125,27 @@ impl Aead {
 
         let _ = feature_detector;
 
+        #[cfg(feature = "pure-rust")]
+        return Self {
+            backend: AeadBackendImpl::Aegis128LRust(Aegis128LRustBackend),
+        };
+
+        #[cfg(not(feature = "pure-rust"))]
+        Self {
+            backend: AeadBackendImpl::XChacha20Poly1305(Box::default()),
+        }
+    }
+
+    pub(crate) fn new_aegis256_with_feature_detector(feature_detector: FeatureDetector) -> Self {
+        #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+        if feature_detector.has_aes() {
+            return Self {
+                backend: AeadBackendImpl::Aegis256X86(Aegis256X86Backend),
+            };
+        }
+
+        let _ = feature_detector;
+
         Self {
             backend: AeadBackendImpl::XChacha20Poly1305(Box::default()),
         }
@@ -108,8 +156,12 @@ impl Aead {
         match &self.backend {
             #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
             AeadBackendImpl::Aegis128LX86(_) => "AEGIS-128L",
+            #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+            AeadBackendImpl::Aegis256X86(_) => "AEGIS-256",
             #[cfg(target_arch = "aarch64")]
             AeadBackendImpl::Aegis128LArm(_) => "AEGIS-128L",
+            #[cfg(feature = "pure-rust")]
+            AeadBackendImpl::Aegis128LRust(_) => "AEGIS-128L",
             AeadBackendImpl::XChacha20Poly1305(_) => "XChaCha20-Poly1305",
         }
     }
@@ -127,8 +179,12 @@ impl Aead {
         match &mut self.backend {
             #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
             AeadBackendImpl::Aegis128LX86(b) => b.api_encrypt(key, nonce, aad, data, tag),
+            #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+            AeadBackendImpl::Aegis256X86(b) => b.api_encrypt(key, nonce, aad, data, tag),
             #[cfg(target_arch = "aarch64")]
             AeadBackendImpl::Aegis128LArm(b) => b.api_encrypt(key, nonce, aad, data, tag),
+            #[cfg(feature = "pure-rust")]
+            AeadBackendImpl::Aegis128LRust(b) => b.api_encrypt(key, nonce, aad, data, tag),
             AeadBackendImpl::XChacha20Poly1305(b) => {
                 let key: &[u8; redoubt_aead_xchacha::KEY_SIZE] =
                     key.try_into().map_err(|_| AeadError::InvalidKeySize)?;
@@ -155,8 +211,12 @@ impl Aead {
         match &mut self.backend {
             #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
             AeadBackendImpl::Aegis128LX86(b) => b.api_decrypt(key, nonce, aad, data, tag),
+            #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+            AeadBackendImpl::Aegis256X86(b) => b.api_decrypt(key, nonce, aad, data, tag),
             #[cfg(target_arch = "aarch64")]
             AeadBackendImpl::Aegis128LArm(b) => b.api_decrypt(key, nonce, aad, data, tag),
+            #[cfg(feature = "pure-rust")]
+            AeadBackendImpl::Aegis128LRust(b) => b.api_decrypt(key, nonce, aad, data, tag),
             AeadBackendImpl::XChacha20Poly1305(b) => {
                 let key: &[u8; redoubt_aead_xchacha::KEY_SIZE] =
                     key.try_into().map_err(|_| AeadError::InvalidKeySize)?;
@@ -169,14 +229,84 @@ impl Aead {
         }
     }
 
+    /// Encrypts `plaintext` into a new buffer, returning `(ciphertext, tag)`.
+    ///
+    /// `plaintext` is zeroized once copied, so the only plaintext copy is the
+    /// one encrypted in place.
+    pub fn encrypt_detached(
+        &mut self,
+        key: &[u8],
+        nonce: &[u8],
+        aad: &[u8],
+        plaintext: &mut [u8],
+    ) -> Result<(Vec<u8>, Vec<u8>), AeadError> {
+        let mut ciphertext = plaintext.to_vec();
+        plaintext.fast_zeroize();
+
+        let mut tag = vec![0u8; self.tag_size()];
+
+        if let Err(e) = self.encrypt(key, nonce, aad, &mut ciphertext, &mut tag) {
+            ciphertext.fast_zeroize();
+            return Err(e);
+        }
+
+        Ok((ciphertext, tag))
+    }
+
+    /// Encrypts a fixed-size `block` in place with empty associated data and
+    /// returns the tag.
+    ///
+    /// Unlike [`encrypt_detached()`](Self::encrypt_detached), nothing is
+    /// heap-allocated: the block is encrypted where it lives and the tag comes
+    /// back as a [`TAG_SIZE`] array. Suited to fixed-size fields such as
+    /// 32-byte keys. The output matches [`encrypt()`](Self::encrypt) with an
+    /// empty `aad`.
+    pub fn encrypt_block<const N: usize>(
+        &mut self,
+        key: &[u8],
+        nonce: &[u8],
+        block: &mut [u8; N],
+    ) -> Result<[u8; TAG_SIZE], AeadError> {
+        let mut tag = [0u8; TAG_SIZE];
+
+        self.encrypt(key, nonce, &[], block, &mut tag)?;
+
+        Ok(tag)
+    }
+
+    /// Decrypts `ciphertext` with a detached `tag` into a new buffer.
+    ///
+    /// The returned plaintext is zeroized before returning if authentication fails.
+    pub fn decrypt_detached(
+        &mut self,
+        key: &[u8],
+        nonce: &[u8],
+        aad: &[u8],
+        ciphertext: &[u8],
+        tag: &[u8],
+    ) -> Result<Vec<u8>, AeadError> {
+        let mut plaintext = ciphertext.to_vec();
+
+        if let Err(e) = self.decrypt(key, nonce, aad, &mut plaintext, tag) {
+            plaintext.fast_zeroize();
+            return Err(e);
+        }
+
+        Ok(plaintext)
+    }
+
     /// Generates a cryptographically secure random nonce.
     #[inline]
     pub fn generate_nonce(&mut self) -> Result<Vec<u8>, EntropyError> {
         match &mut self.backend {
             #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
             AeadBackendImpl::Aegis128LX86(b) => b.api_generate_nonce(),
+            #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+            AeadBackendImpl::Aegis256X86(b) => b.api_generate_nonce(),
             #[cfg(target_arch = "aarch64")]
             AeadBackendImpl::Aegis128LArm(b) => b.api_generate_nonce(),
+            #[cfg(feature = "pure-rust")]
+            AeadBackendImpl::Aegis128LRust(b) => b.api_generate_nonce(),
             AeadBackendImpl::XChacha20Poly1305(b) => b
                 .generate_nonce()
                 .map(|n: [u8; redoubt_aead_xchacha::XNONCE_SIZE]| n.to_vec()),
@@ -189,8 +319,12 @@ impl Aead {
         match &self.backend {
             #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
             AeadBackendImpl::Aegis128LX86(b) => b.api_key_size(),
+            #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+            AeadBackendImpl::Aegis256X86(b) => b.api_key_size(),
             #[cfg(target_arch = "aarch64")]
             AeadBackendImpl::Aegis128LArm(b) => b.api_key_size(),
+            #[cfg(feature = "pure-rust")]
+            AeadBackendImpl::Aegis128LRust(b) => b.api_key_size(),
             AeadBackendImpl::XChacha20Poly1305(_) => redoubt_aead_xchacha::KEY_SIZE,
         }
     }
@@ -201,8 +335,12 @@ impl Aead {
         match &self.backend {
             #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
             AeadBackendImpl::Aegis128LX86(b) => b.api_nonce_size(),
+            #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+            AeadBackendImpl::Aegis256X86(b) => b.api_nonce_size(),
             #[cfg(target_arch = "aarch64")]
             AeadBackendImpl::Aegis128LArm(b) => b.api_nonce_size(),
+            #[cfg(feature = "pure-rust")]
+            AeadBackendImpl::Aegis128LRust(b) => b.api_nonce_size(),
             AeadBackendImpl::XChacha20Poly1305(_) => redoubt_aead_xchacha::XNONCE_SIZE,
         }
     }
@@ -213,8 +351,12 @@ impl Aead {
         match &self.backend {
             #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
             AeadBackendImpl::Aegis128LX86(b) => b.api_tag_size(),
+            #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+            AeadBackendImpl::Aegis256X86(b) => b.api_tag_size(),
             #[cfg(target_arch = "aarch64")]
             AeadBackendImpl::Aegis128LArm(b) => b.api_tag_size(),
+            #[cfg(feature = "pure-rust")]
+            AeadBackendImpl::Aegis128LRust(b) => b.api_tag_size(),
             AeadBackendImpl::XChacha20Poly1305(_) => redoubt_aead_xchacha::TAG_SIZE,
         }
     }
@@ -234,6 +376,14 @@ impl Aead {
         }
     }
 
+    #[cfg(test)]
+    #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+    pub(crate) fn with_aegis256() -> Self {
+        Self {
+            backend: AeadBackendImpl::Aegis256X86(Aegis256X86Backend),
+        }
+    }
+
     #[cfg(test)]
     #[cfg(target_arch = "aarch64")]
     pub(crate) fn with_aegis128l() -> Self {
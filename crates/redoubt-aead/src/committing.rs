@@ -0,0 +1,166 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! Key-committing wrapper for any [`AeadApi`] backend.
+//!
+//! AEGIS and ChaCha20-Poly1305 are not key-committing: an attacker can craft a
+//! single ciphertext that authenticates under several keys (partitioning
+//! oracle attacks). [`CommittingAead`] binds each ciphertext to exactly one
+//! `(key, nonce)` pair by adding a commitment:
+//!
+//! ```text
+//! commitment = HKDF-SHA256(salt = nonce, ikm = key, info = KEY_COMMITMENT_INFO)[..32]
+//! tag        = commitment || inner_tag
+//! ```
+//!
+//! On decryption the commitment is recomputed and compared in constant time
+//! before the inner AEAD is invoked, so a wrong key is rejected with
+//! [`AeadError::CommitmentMismatch`] and the ciphertext is left untouched.
+//!
+//! ## Overhead
+//!
+//! [`KEY_COMMITMENT_SIZE`] (32) extra bytes per message, carried in the tag
+//! (`api_tag_size()` grows by 32), plus one HKDF-SHA256 evaluation per call.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use redoubt_aead_core::{AeadApi, AeadError, EntropyError};
+use redoubt_zero::FastZeroizable;
+
+/// Size of the key commitment in bytes.
+pub const KEY_COMMITMENT_SIZE: usize = 32;
+
+/// HKDF `info` used to derive the key commitment.
+pub const KEY_COMMITMENT_INFO: &[u8] = b"REDOUBT-AEAD:KEY-COMMITMENT:0.0.1";
+
+/// Makes any [`AeadApi`] backend key-committing.
+///
+/// The commitment travels in the tag: callers simply size tags with
+/// [`api_tag_size`](AeadApi::api_tag_size), which reports the inner tag size
+/// plus [`KEY_COMMITMENT_SIZE`].
+///
+/// # Example
+///
+/// ```rust
+/// use redoubt_aead::{Aead, AeadApi, AeadError, CommittingAead};
+///
+/// let mut aead = CommittingAead::new(Aead::new());
+/// let key = vec![0x42u8; aead.api_key_size()];
+/// let nonce = aead.api_generate_nonce().unwrap();
+///
+/// let mut data = *b"secret";
+/// let mut tag = vec![0u8; aead.api_tag_size()];
+/// aead.api_encrypt(&key, &nonce, b"", &mut data, &mut tag).unwrap();
+///
+/// let wrong_key = vec![0x24u8; aead.api_key_size()];
+/// let result = aead.api_decrypt(&wrong_key, &nonce, b"", &mut data, &tag);
+/// assert_eq!(result, Err(AeadError::CommitmentMismatch));
+/// ```
+pub struct CommittingAead<A: AeadApi> {
+    inner: A,
+}
+
+impl<A: AeadApi> CommittingAead<A> {
+    /// Wraps `inner`, adding a key commitment to every message.
+    pub fn new(inner: A) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the wrapped backend.
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    fn commit(
+        key: &[u8],
+        nonce: &[u8],
+        out: &mut [u8; KEY_COMMITMENT_SIZE],
+    ) -> Result<(), AeadError> {
+        // Infallible: KEY_COMMITMENT_SIZE is far below 255 * HashLen
+        // Error branch kept for panic-free guarantees, cannot be tested
+        redoubt_hkdf::hkdf(nonce, key, KEY_COMMITMENT_INFO, out)
+            .map_err(|_| AeadError::CommitmentMismatch)
+    }
+
+    fn check_tag_size(&self, tag_len: usize) -> Result<(), AeadError> {
+        if tag_len != self.api_tag_size() {
+            return Err(AeadError::InvalidTagSize);
+        }
+
+        Ok(())
+    }
+}
+
+impl<A: AeadApi + Default> Default for CommittingAead<A> {
+    fn default() -> Self {
+        Self::new(A::default())
+    }
+}
+
+impl<A: AeadApi> AeadApi for CommittingAead<A> {
+    fn api_encrypt(
+        &mut self,
+        key: &[u8],
+        nonce: &[u8],
+        aad: &[u8],
+        data: &mut [u8],
+        tag: &mut [u8],
+    ) -> Result<(), AeadError> {
+        self.check_tag_size(tag.len())?;
+
+        let (commitment, inner_tag) = tag.split_at_mut(KEY_COMMITMENT_SIZE);
+
+        self.inner.api_encrypt(key, nonce, aad, data, inner_tag)?;
+
+        let mut computed = [0u8; KEY_COMMITMENT_SIZE];
+        let result = Self::commit(key, nonce, &mut computed);
+        commitment.copy_from_slice(&computed);
+        computed.fast_zeroize();
+
+        result
+    }
+
+    fn api_decrypt(
+        &mut self,
+        key: &[u8],
+        nonce: &[u8],
+        aad: &[u8],
+        data: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), AeadError> {
+        self.check_tag_size(tag.len())?;
+
+        let (commitment, inner_tag) = tag.split_at(KEY_COMMITMENT_SIZE);
+
+        let mut computed = [0u8; KEY_COMMITMENT_SIZE];
+        let result = Self::commit(key, nonce, &mut computed);
+        let matches = redoubt_util::constant_time_eq(&computed, commitment);
+        computed.fast_zeroize();
+        result?;
+
+        if !matches {
+            return Err(AeadError::CommitmentMismatch);
+        }
+
+        self.inner.api_decrypt(key, nonce, aad, data, inner_tag)
+    }
+
+    fn api_generate_nonce(&mut self) -> Result<Vec<u8>, EntropyError> {
+        self.inner.api_generate_nonce()
+    }
+
+    fn api_key_size(&self) -> usize {
+        self.inner.api_key_size()
+    }
+
+    fn api_nonce_size(&self) -> usize {
+        self.inner.api_nonce_size()
+    }
+
+    fn api_tag_size(&self) -> usize {
+        KEY_COMMITMENT_SIZE + self.inner.api_tag_size()
+    }
+}
@@ -2,6 +2,62 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // See LICENSE in the repository root for full license text.
 
+//! Runtime CPU feature detection and the public capability report.
+
+use crate::aead::Aead;
+
+/// What the current platform offers for AEAD, as seen at runtime.
+///
+/// Returned by [`detect_capabilities`]. Useful for logging which backend is
+/// in use, or for refusing to run without hardware acceleration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AeadCapabilities {
+    /// Hardware AES is available to a compiled-in backend (AES-NI on
+    /// x86_64 outside Windows, Crypto Extensions on aarch64).
+    ///
+    /// Always `false` on targets without a hardware AEGIS backend, even if
+    /// the CPU itself supports AES.
+    pub hardware_aes: bool,
+    /// The portable AEGIS-128L backend is compiled in (`pure-rust` feature).
+    pub portable_aegis: bool,
+    /// [`Aead::new()`] selects an AEGIS backend (hardware or portable).
+    pub aegis: bool,
+    /// Name of the backend [`Aead::new()`] selects, as reported by
+    /// [`Aead::backend_name()`].
+    pub backend_name: &'static str,
+}
+
+/// Reports the detected AEAD capabilities and the backend [`Aead::new()`]
+/// selects on this machine.
+pub fn detect_capabilities() -> AeadCapabilities {
+    detect_capabilities_with_feature_detector(FeatureDetector::new())
+}
+
+pub(crate) fn detect_capabilities_with_feature_detector(
+    feature_detector: FeatureDetector,
+) -> AeadCapabilities {
+    #[cfg(any(
+        all(target_arch = "x86_64", not(target_os = "windows")),
+        target_arch = "aarch64"
+    ))]
+    let hardware_aes = feature_detector.has_aes();
+
+    #[cfg(not(any(
+        all(target_arch = "x86_64", not(target_os = "windows")),
+        target_arch = "aarch64"
+    )))]
+    let hardware_aes = false;
+
+    let backend_name = Aead::new_with_feature_detector(feature_detector).backend_name();
+
+    AeadCapabilities {
+        hardware_aes,
+        portable_aegis: cfg!(feature = "pure-rust"),
+        aegis: backend_name.starts_with("AEGIS"),
+        backend_name,
+    }
+}
+
 #[cfg(test)]
 pub enum FeatureDetectorBehaviour {
     None,
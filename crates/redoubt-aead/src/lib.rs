@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // See LICENSE in the repository root for full license text.
 
-//! AEGIS-128L and XChaCha20-Poly1305 AEAD with automatic backend selection.
+//! AEGIS-128L, AEGIS-256 and XChaCha20-Poly1305 AEAD with automatic backend selection.
 //!
 //! ## License
 //!
@@ -17,16 +17,21 @@ extern crate alloc;
 mod tests;
 
 mod aead;
+mod committing;
 mod feature_detector;
+mod stream;
 
 /// Support module including test utilities.
 pub mod support;
 
-pub use aead::{Aead, AeadVariant};
+pub use aead::{Aead, AeadVariant, TAG_SIZE};
+pub use committing::{CommittingAead, KEY_COMMITMENT_INFO, KEY_COMMITMENT_SIZE};
+pub use feature_detector::{AeadCapabilities, detect_capabilities};
 pub use redoubt_aead_core::{AeadApi, AeadBackend, AeadError};
 pub use redoubt_aead_xchacha::{
     CHACHA20_BERNSTEIN_NONCE_SIZE, CHACHA20_NONCE_SIZE, ChaCha20, HChaCha20, Poly1305, XChaCha20,
 };
+pub use stream::{AeadStreamDecryptor, AeadStreamEncryptor, STREAM_NONCE_OVERHEAD};
 
 #[cfg(feature = "test-utils")]
 pub use support::test_utils;
@@ -0,0 +1,215 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! Chunked AEAD encryption using the STREAM construction.
+//!
+//! Large payloads are split into chunks, each sealed independently with
+//! [`AeadApi`]. The nonce of chunk `i` is derived as:
+//!
+//! ```text
+//! nonce_prefix (nonce_size - 5 bytes) || i (u32 big-endian) || last_flag (u8)
+//! ```
+//!
+//! where `last_flag` is `1` only for the final chunk. Since every nonce is
+//! bound to the chunk position and to whether it is the last one, chunks
+//! cannot be reordered, dropped, or truncated without failing authentication.
+//!
+//! Only the plaintext chunk currently being processed is ever held in memory.
+//! Consumed input chunks are zeroized on both the encrypt and decrypt side.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use redoubt_aead_core::{AeadApi, AeadError, EntropyError};
+use redoubt_zero::FastZeroizable;
+
+/// Bytes of the nonce reserved for the chunk counter and the last-chunk flag.
+pub const STREAM_NONCE_OVERHEAD: usize = 5;
+
+const LAST_CHUNK: u8 = 1;
+const NOT_LAST_CHUNK: u8 = 0;
+
+/// Shared nonce derivation for encryptor and decryptor.
+struct StreamNonce {
+    nonce: Vec<u8>,
+    counter: u64,
+}
+
+impl StreamNonce {
+    fn new(nonce_prefix: &[u8]) -> Self {
+        let mut nonce = Vec::with_capacity(nonce_prefix.len() + STREAM_NONCE_OVERHEAD);
+        nonce.extend_from_slice(nonce_prefix);
+        nonce.resize(nonce_prefix.len() + STREAM_NONCE_OVERHEAD, 0);
+
+        Self { nonce, counter: 0 }
+    }
+
+    fn prefix(&self) -> &[u8] {
+        &self.nonce[..self.nonce.len() - STREAM_NONCE_OVERHEAD]
+    }
+
+    /// Returns the nonce for the current chunk and advances the counter.
+    fn next(&mut self, last: bool) -> Result<&[u8], AeadError> {
+        let counter = u32::try_from(self.counter).map_err(|_| AeadError::StreamCounterOverflow)?;
+        let len = self.nonce.len();
+
+        self.nonce[len - STREAM_NONCE_OVERHEAD..len - 1].copy_from_slice(&counter.to_be_bytes());
+        self.nonce[len - 1] = if last { LAST_CHUNK } else { NOT_LAST_CHUNK };
+        self.counter += 1;
+
+        Ok(&self.nonce)
+    }
+}
+
+/// Encrypts a payload chunk by chunk.
+///
+/// Each call to [`update`](Self::update) returns `ciphertext || tag` for one
+/// chunk; [`finalize`](Self::finalize) seals the last one. The receiver needs
+/// [`nonce_prefix`](Self::nonce_prefix) to build an [`AeadStreamDecryptor`].
+///
+/// # Example
+///
+/// ```rust
+/// use redoubt_aead::{Aead, AeadStreamDecryptor, AeadStreamEncryptor};
+///
+/// let key = vec![0x42u8; Aead::new().key_size()];
+///
+/// let mut enc = AeadStreamEncryptor::new(Aead::new(), &key).unwrap();
+/// let c0 = enc.update(&mut [1u8; 64]).unwrap();
+/// let prefix = enc.nonce_prefix().to_vec();
+/// let c1 = enc.finalize(&mut [2u8; 10]).unwrap();
+///
+/// let mut dec = AeadStreamDecryptor::new(Aead::new(), &key, &prefix).unwrap();
+/// assert_eq!(dec.update(&mut c0.clone()).unwrap(), [1u8; 64]);
+/// assert_eq!(dec.finalize(&mut c1.clone()).unwrap(), [2u8; 10]);
+/// ```
+pub struct AeadStreamEncryptor<'a, A: AeadApi> {
+    aead: A,
+    key: &'a [u8],
+    nonce: StreamNonce,
+}
+
+impl<'a, A: AeadApi> AeadStreamEncryptor<'a, A> {
+    /// Creates an encryptor with a fresh random nonce prefix.
+    pub fn new(mut aead: A, key: &'a [u8]) -> Result<Self, EntropyError> {
+        let random = aead.api_generate_nonce()?;
+        let prefix_len = aead.api_nonce_size().saturating_sub(STREAM_NONCE_OVERHEAD);
+        let nonce = StreamNonce::new(&random[..prefix_len]);
+
+        Ok(Self { aead, key, nonce })
+    }
+
+    /// Returns the nonce prefix the decryptor must be created with.
+    pub fn nonce_prefix(&self) -> &[u8] {
+        self.nonce.prefix()
+    }
+
+    /// Encrypts a non-final chunk, zeroizing `chunk`.
+    ///
+    /// Returns `ciphertext || tag`.
+    pub fn update(&mut self, chunk: &mut [u8]) -> Result<Vec<u8>, AeadError> {
+        self.seal(chunk, false)
+    }
+
+    /// Encrypts the final chunk (may be empty), zeroizing `chunk`.
+    ///
+    /// Returns `ciphertext || tag`.
+    pub fn finalize(mut self, chunk: &mut [u8]) -> Result<Vec<u8>, AeadError> {
+        self.seal(chunk, true)
+    }
+
+    fn seal(&mut self, chunk: &mut [u8], last: bool) -> Result<Vec<u8>, AeadError> {
+        let tag_size = self.aead.api_tag_size();
+        let mut out = Vec::with_capacity(chunk.len() + tag_size);
+        out.extend_from_slice(chunk);
+        out.resize(chunk.len() + tag_size, 0);
+
+        // Plaintext now lives only in `out`, which is encrypted in place below
+        chunk.fast_zeroize();
+
+        let (data, tag) = out.split_at_mut(chunk.len());
+        let result = self
+            .nonce
+            .next(last)
+            .and_then(|nonce| self.aead.api_encrypt(self.key, nonce, &[], data, tag));
+
+        if let Err(e) = result {
+            out.fast_zeroize();
+            return Err(e);
+        }
+
+        Ok(out)
+    }
+}
+
+/// Decrypts a payload produced by [`AeadStreamEncryptor`], chunk by chunk.
+///
+/// The stream is only complete once [`finalize`](Self::finalize) succeeds: a
+/// stream that ends without a valid final chunk has been truncated.
+pub struct AeadStreamDecryptor<'a, A: AeadApi> {
+    aead: A,
+    key: &'a [u8],
+    nonce: StreamNonce,
+}
+
+impl<'a, A: AeadApi> AeadStreamDecryptor<'a, A> {
+    /// Creates a decryptor for the stream identified by `nonce_prefix`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AeadError::InvalidNonceSize`] if `nonce_prefix` does not match
+    /// the backend nonce size minus [`STREAM_NONCE_OVERHEAD`].
+    pub fn new(aead: A, key: &'a [u8], nonce_prefix: &[u8]) -> Result<Self, AeadError> {
+        if nonce_prefix.len() + STREAM_NONCE_OVERHEAD != aead.api_nonce_size() {
+            return Err(AeadError::InvalidNonceSize);
+        }
+
+        Ok(Self {
+            aead,
+            key,
+            nonce: StreamNonce::new(nonce_prefix),
+        })
+    }
+
+    /// Decrypts a non-final `ciphertext || tag` chunk, zeroizing `chunk`.
+    pub fn update(&mut self, chunk: &mut [u8]) -> Result<Vec<u8>, AeadError> {
+        self.open(chunk, false)
+    }
+
+    /// Decrypts the final `ciphertext || tag` chunk, zeroizing `chunk`.
+    ///
+    /// Fails with [`AeadError::AuthenticationFailed`] if `chunk` was not sealed
+    /// as the last one, which is how truncation is detected.
+    pub fn finalize(mut self, chunk: &mut [u8]) -> Result<Vec<u8>, AeadError> {
+        self.open(chunk, true)
+    }
+
+    fn open(&mut self, chunk: &mut [u8], last: bool) -> Result<Vec<u8>, AeadError> {
+        let tag_size = self.aead.api_tag_size();
+
+        if chunk.len() < tag_size {
+            chunk.fast_zeroize();
+            return Err(AeadError::InvalidTagSize);
+        }
+
+        let (ciphertext, tag) = chunk.split_at(chunk.len() - tag_size);
+        let mut out = Vec::with_capacity(ciphertext.len());
+        out.extend_from_slice(ciphertext);
+
+        let result = self
+            .nonce
+            .next(last)
+            .and_then(|nonce| self.aead.api_decrypt(self.key, nonce, &[], &mut out, tag));
+
+        chunk.fast_zeroize();
+
+        if let Err(e) = result {
+            out.fast_zeroize();
+            return Err(e);
+        }
+
+        Ok(out)
+    }
+}
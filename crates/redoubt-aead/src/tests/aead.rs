@@ -45,6 +45,17 @@ fn test_from_variant_xchacha() {
     assert_eq!(aead.backend_name(), "XChaCha20-Poly1305");
 }
 
+#[test]
+fn test_from_variant_aegis256() {
+    use crate::AeadVariant;
+
+    let aead = Aead::from(AeadVariant::Aegis256);
+    let name = aead.backend_name();
+
+    assert!(name == "AEGIS-256" || name == "XChaCha20-Poly1305");
+    assert_eq!(aead.key_size(), 32);
+}
+
 // =============================================================================
 // new_with_feature_detector()
 // =============================================================================
@@ -61,6 +72,7 @@ fn test_backend_detection_selects_aegis_when_aes_available() {
     assert_eq!(aead.backend_name(), "AEGIS-128L");
 }
 
+#[cfg(not(feature = "pure-rust"))]
 #[test]
 fn test_backend_detection_falls_back_to_xchacha() {
     use crate::feature_detector::{FeatureDetector, FeatureDetectorBehaviour};
@@ -72,6 +84,54 @@ fn test_backend_detection_falls_back_to_xchacha() {
     assert_eq!(aead.backend_name(), "XChaCha20-Poly1305");
 }
 
+#[cfg(feature = "pure-rust")]
+#[test]
+fn test_backend_detection_falls_back_to_portable_aegis() {
+    use crate::feature_detector::{FeatureDetector, FeatureDetectorBehaviour};
+
+    let mut fd = FeatureDetector::new();
+    fd.change_behaviour(FeatureDetectorBehaviour::ForceAesFalse);
+    let mut aead = Aead::new_with_feature_detector(fd);
+
+    assert_eq!(aead.backend_name(), "AEGIS-128L");
+    assert_eq!(aead.key_size(), redoubt_aead_aegis_rust::KEY_SIZE);
+
+    let key = [0x42u8; 16];
+    let nonce = aead.generate_nonce().expect("Failed to generate_nonce()");
+    let mut data = *b"portable fallback";
+    let mut tag = [0u8; 16];
+
+    aead.encrypt(&key, &nonce, b"aad", &mut data, &mut tag)
+        .expect("Failed to encrypt(..)");
+    aead.decrypt(&key, &nonce, b"aad", &mut data, &tag)
+        .expect("Failed to decrypt(..)");
+
+    assert_eq!(&data, b"portable fallback");
+}
+
+#[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+#[test]
+fn test_aegis256_detection_selects_aegis256_when_aes_available() {
+    use crate::feature_detector::{FeatureDetector, FeatureDetectorBehaviour};
+
+    let mut fd = FeatureDetector::new();
+    fd.change_behaviour(FeatureDetectorBehaviour::ForceAesTrue);
+    let aead = Aead::new_aegis256_with_feature_detector(fd);
+
+    assert_eq!(aead.backend_name(), "AEGIS-256");
+}
+
+#[test]
+fn test_aegis256_detection_falls_back_to_xchacha() {
+    use crate::feature_detector::{FeatureDetector, FeatureDetectorBehaviour};
+
+    let mut fd = FeatureDetector::new();
+    fd.change_behaviour(FeatureDetectorBehaviour::ForceAesFalse);
+    let aead = Aead::new_aegis256_with_feature_detector(fd);
+
+    assert_eq!(aead.backend_name(), "XChaCha20-Poly1305");
+}
+
 // =============================================================================
 // api_encrypt() (XChaCha20-Poly1305)
 // =============================================================================
@@ -238,6 +298,22 @@ fn test_api_decrypt_xchacha_reports_authentication_failed() {
     assert!(matches!(result, Err(AeadError::AuthenticationFailed)));
 }
 
+#[test]
+fn test_api_decrypt_xchacha_rejects_mismatched_aad() {
+    let mut aead = Aead::with_xchacha20poly1305();
+    let key = [0x42u8; 32];
+    let nonce = [0x24u8; 24];
+    let mut data = [0xAAu8; 32];
+    let mut tag = [0u8; 16];
+
+    aead.api_encrypt(&key, &nonce, b"field:0", &mut data, &mut tag)
+        .expect("Failed to api_encrypt(..)");
+
+    let result = aead.api_decrypt(&key, &nonce, b"field:1", &mut data, &tag);
+
+    assert!(matches!(result, Err(AeadError::AuthenticationFailed)));
+}
+
 /// draft-irtf-cfrg-xchacha Appendix A.1
 #[test]
 fn test_api_decrypt_xchacha_succeeds() {
@@ -446,6 +522,23 @@ fn test_api_decrypt_aegis_reports_authentication_failed() {
     assert!(matches!(result, Err(AeadError::AuthenticationFailed)));
 }
 
+#[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+#[test]
+fn test_api_decrypt_aegis_rejects_mismatched_aad() {
+    let mut aead = Aead::with_aegis128l();
+    let key = [0x42u8; 16];
+    let nonce = [0x24u8; 16];
+    let mut data = [0xAAu8; 32];
+    let mut tag = [0u8; 16];
+
+    aead.api_encrypt(&key, &nonce, b"field:0", &mut data, &mut tag)
+        .expect("Failed to api_encrypt(..)");
+
+    let result = aead.api_decrypt(&key, &nonce, b"field:1", &mut data, &tag);
+
+    assert!(matches!(result, Err(AeadError::AuthenticationFailed)));
+}
+
 /// AEGIS-128L RFC Test Vector A.2.2 - Test Vector 1
 #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
 #[test]
@@ -479,6 +572,210 @@ fn test_api_decrypt_aegis_succeeds() {
     assert_eq!(&data, &[0x00; 16]);
 }
 
+// =============================================================================
+// api_encrypt() / api_decrypt() (AEGIS-256)
+// =============================================================================
+
+/// AEGIS-256 RFC Test Vector A.3.5 - Test Vector 4
+#[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+#[test]
+fn test_api_encrypt_decrypt_aegis256_succeeds() {
+    let mut aead = Aead::with_aegis256();
+
+    let mut key = [0u8; 32];
+    key[..2].copy_from_slice(&[0x10, 0x01]);
+    let mut nonce = [0u8; 32];
+    nonce[..3].copy_from_slice(&[0x10, 0x00, 0x02]);
+    let aad: [u8; 8] = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+    let plaintext: [u8; 14] = core::array::from_fn(|i| i as u8);
+    #[rustfmt::skip]
+    let expected_ct: [u8; 14] = [
+        0xf3, 0x73, 0x07, 0x9e, 0xd8, 0x4b, 0x27, 0x09,
+        0xfa, 0xee, 0x37, 0x35, 0x84, 0x58,
+    ];
+    #[rustfmt::skip]
+    let expected_tag: [u8; 16] = [
+        0xc6, 0x0b, 0x9c, 0x2d, 0x33, 0xce, 0xb0, 0x58,
+        0xf9, 0x6e, 0x6d, 0xd0, 0x3c, 0x21, 0x56, 0x52,
+    ];
+
+    let mut data = plaintext;
+    let mut tag = [0u8; 16];
+    aead.api_encrypt(&key, &nonce, &aad, &mut data, &mut tag)
+        .expect("Failed to api_encrypt(..)");
+
+    assert_eq!(data, expected_ct);
+    assert_eq!(tag, expected_tag);
+
+    aead.api_decrypt(&key, &nonce, &aad, &mut data, &tag)
+        .expect("Failed to api_decrypt(..)");
+
+    assert_eq!(data, plaintext);
+}
+
+#[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+#[test]
+fn test_api_decrypt_aegis256_reports_authentication_failed() {
+    let mut aead = Aead::with_aegis256();
+    let key = [0u8; 32];
+    let nonce = [0u8; 32];
+    let mut data = [1u8; 8];
+    let tag = [0u8; 16];
+
+    let result = aead.api_decrypt(&key, &nonce, &[], &mut data, &tag);
+
+    assert!(matches!(result, Err(AeadError::AuthenticationFailed)));
+}
+
+// =============================================================================
+// encrypt_detached() / decrypt_detached()
+// =============================================================================
+
+#[test]
+fn test_detached_roundtrip() {
+    use crate::AeadVariant;
+
+    for variant in [
+        AeadVariant::Auto,
+        AeadVariant::XChachaPoly1305,
+        AeadVariant::Aegis256,
+    ] {
+        let mut aead = Aead::from(variant);
+        let key = vec![0x42u8; aead.key_size()];
+        let nonce = aead.generate_nonce().expect("Failed to generate_nonce()");
+        let mut plaintext = *b"detached mode roundtrip";
+
+        let (ciphertext, tag) = aead
+            .encrypt_detached(&key, &nonce, b"aad", &mut plaintext)
+            .expect("Failed to encrypt_detached(..)");
+
+        assert!(plaintext.iter().all(|b| *b == 0));
+        assert_eq!(ciphertext.len(), plaintext.len());
+        assert_eq!(tag.len(), aead.tag_size());
+
+        let decrypted = aead
+            .decrypt_detached(&key, &nonce, b"aad", &ciphertext, &tag)
+            .expect("Failed to decrypt_detached(..)");
+
+        assert_eq!(decrypted, b"detached mode roundtrip");
+    }
+}
+
+#[test]
+fn test_decrypt_detached_rejects_flipped_tag() {
+    use crate::AeadVariant;
+
+    for variant in [
+        AeadVariant::Auto,
+        AeadVariant::XChachaPoly1305,
+        AeadVariant::Aegis256,
+    ] {
+        let mut aead = Aead::from(variant);
+        let key = vec![0x42u8; aead.key_size()];
+        let nonce = aead.generate_nonce().expect("Failed to generate_nonce()");
+        let mut plaintext = [0xAAu8; 40];
+
+        let (ciphertext, mut tag) = aead
+            .encrypt_detached(&key, &nonce, &[], &mut plaintext)
+            .expect("Failed to encrypt_detached(..)");
+
+        tag[0] ^= 0x01;
+
+        let result = aead.decrypt_detached(&key, &nonce, &[], &ciphertext, &tag);
+
+        assert!(matches!(result, Err(AeadError::AuthenticationFailed)));
+    }
+}
+
+#[test]
+fn test_decrypt_detached_rejects_tag_differing_in_first_or_last_byte() {
+    use crate::AeadVariant;
+
+    for variant in [
+        AeadVariant::Auto,
+        AeadVariant::XChachaPoly1305,
+        AeadVariant::Aegis256,
+    ] {
+        let mut aead = Aead::from(variant);
+        let key = vec![0x42u8; aead.key_size()];
+        let nonce = aead.generate_nonce().expect("Failed to generate_nonce()");
+        let mut plaintext = [0xAAu8; 40];
+
+        let (ciphertext, tag) = aead
+            .encrypt_detached(&key, &nonce, &[], &mut plaintext)
+            .expect("Failed to encrypt_detached(..)");
+
+        for idx in [0, tag.len() - 1] {
+            let mut tampered = tag.clone();
+            tampered[idx] ^= 0x80;
+
+            let result = aead.decrypt_detached(&key, &nonce, &[], &ciphertext, &tampered);
+
+            assert!(matches!(result, Err(AeadError::AuthenticationFailed)));
+        }
+    }
+}
+
+#[test]
+fn test_encrypt_detached_reports_invalid_key_size() {
+    let mut aead = Aead::with_xchacha20poly1305();
+    let mut plaintext = [0xAAu8; 8];
+
+    let result = aead.encrypt_detached(&[0u8; 31], &[0u8; 24], &[], &mut plaintext);
+
+    assert!(matches!(result, Err(AeadError::InvalidKeySize)));
+}
+
+// =============================================================================
+// encrypt_block()
+// =============================================================================
+
+#[test]
+fn test_encrypt_block_matches_generic_path() {
+    use crate::{AeadVariant, TAG_SIZE};
+
+    for variant in [
+        AeadVariant::Auto,
+        AeadVariant::XChachaPoly1305,
+        AeadVariant::Aegis256,
+    ] {
+        let mut aead = Aead::from(variant);
+        let key = vec![0x42u8; aead.key_size()];
+        let nonce = aead.generate_nonce().expect("Failed to generate_nonce()");
+        let mut block = [0xA5u8; 32];
+        let mut generic = block;
+        let mut generic_tag = vec![0u8; aead.tag_size()];
+
+        let tag = aead
+            .encrypt_block(&key, &nonce, &mut block)
+            .expect("Failed to encrypt_block(..)");
+        aead.encrypt(&key, &nonce, &[], &mut generic, &mut generic_tag)
+            .expect("Failed to encrypt(..)");
+
+        assert_eq!(aead.tag_size(), TAG_SIZE);
+        assert_eq!(block, generic);
+        assert_eq!(tag.as_slice(), generic_tag.as_slice());
+        assert_ne!(block, [0xA5u8; 32]);
+
+        aead.decrypt(&key, &nonce, &[], &mut block, &tag)
+            .expect("Failed to decrypt(..)");
+
+        assert_eq!(block, [0xA5u8; 32]);
+    }
+}
+
+#[test]
+fn test_encrypt_block_reports_invalid_key_size() {
+    let mut aead = Aead::from(crate::AeadVariant::XChachaPoly1305);
+    let nonce = aead.generate_nonce().expect("Failed to generate_nonce()");
+    let mut block = [0xA5u8; 32];
+
+    let result = aead.encrypt_block(&[0u8; 7], &nonce, &mut block);
+
+    assert_eq!(result, Err(AeadError::InvalidKeySize));
+    assert_eq!(block, [0xA5u8; 32]);
+}
+
 // =============================================================================
 // api_generate_nonce()
 // =============================================================================
@@ -519,6 +816,22 @@ fn test_api_sizes_xchacha() {
     assert_eq!(aead.api_tag_size(), 16);
 }
 
+#[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+#[test]
+fn test_api_sizes_aegis256() {
+    let mut aead = Aead::with_aegis256();
+
+    assert_eq!(aead.api_key_size(), 32);
+    assert_eq!(aead.api_nonce_size(), 32);
+    assert_eq!(aead.api_tag_size(), 16);
+    assert_eq!(
+        aead.api_generate_nonce()
+            .expect("Failed to generate nonce")
+            .len(),
+        32
+    );
+}
+
 #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
 #[test]
 fn test_api_sizes_aegis() {
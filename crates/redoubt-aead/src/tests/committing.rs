@@ -0,0 +1,152 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use redoubt_aead_core::{AeadApi, AeadError};
+
+use crate::aead::{Aead, AeadVariant};
+use crate::committing::{CommittingAead, KEY_COMMITMENT_SIZE};
+
+const VARIANTS: [AeadVariant; 2] = [AeadVariant::Auto, AeadVariant::XChachaPoly1305];
+
+struct Sealed {
+    key: Vec<u8>,
+    nonce: Vec<u8>,
+    data: Vec<u8>,
+    tag: Vec<u8>,
+}
+
+fn seal(aead: &mut CommittingAead<Aead>, plaintext: &[u8]) -> Sealed {
+    let key = vec![0x42u8; aead.api_key_size()];
+    let nonce = aead
+        .api_generate_nonce()
+        .expect("Failed to api_generate_nonce()");
+    let mut data = plaintext.to_vec();
+    let mut tag = vec![0u8; aead.api_tag_size()];
+
+    aead.api_encrypt(&key, &nonce, b"aad", &mut data, &mut tag)
+        .expect("Failed to api_encrypt(..)");
+
+    Sealed {
+        key,
+        nonce,
+        data,
+        tag,
+    }
+}
+
+// =============================================================================
+// api_tag_size()
+// =============================================================================
+
+#[test]
+fn test_committing_tag_size_adds_commitment() {
+    for variant in VARIANTS {
+        let aead = CommittingAead::new(Aead::from(variant));
+
+        assert_eq!(
+            aead.api_tag_size(),
+            KEY_COMMITMENT_SIZE + aead.inner().api_tag_size()
+        );
+        assert_eq!(aead.api_key_size(), aead.inner().api_key_size());
+        assert_eq!(aead.api_nonce_size(), aead.inner().api_nonce_size());
+    }
+}
+
+// =============================================================================
+// api_encrypt() / api_decrypt()
+// =============================================================================
+
+#[test]
+fn test_committing_roundtrip() {
+    for variant in VARIANTS {
+        let mut aead = CommittingAead::new(Aead::from(variant));
+        let mut sealed = seal(&mut aead, b"attack at dawn");
+
+        assert_ne!(sealed.data, b"attack at dawn");
+
+        aead.api_decrypt(
+            &sealed.key,
+            &sealed.nonce,
+            b"aad",
+            &mut sealed.data,
+            &sealed.tag,
+        )
+        .expect("Failed to api_decrypt(..)");
+
+        assert_eq!(sealed.data, b"attack at dawn");
+    }
+}
+
+#[test]
+fn test_committing_wrong_key_fails_at_commitment() {
+    for variant in VARIANTS {
+        let mut aead = CommittingAead::new(Aead::from(variant));
+        let mut sealed = seal(&mut aead, b"attack at dawn");
+        let ciphertext = sealed.data.clone();
+        let wrong_key = vec![0x24u8; aead.api_key_size()];
+
+        let result = aead.api_decrypt(
+            &wrong_key,
+            &sealed.nonce,
+            b"aad",
+            &mut sealed.data,
+            &sealed.tag,
+        );
+
+        assert_eq!(result, Err(AeadError::CommitmentMismatch));
+        // Rejected before the inner AEAD touched the data
+        assert_eq!(sealed.data, ciphertext);
+    }
+}
+
+#[test]
+fn test_committing_tampered_inner_tag_fails_at_tag() {
+    let mut aead = CommittingAead::new(Aead::new());
+    let mut sealed = seal(&mut aead, b"attack at dawn");
+
+    sealed.tag[KEY_COMMITMENT_SIZE] ^= 0x01;
+
+    let result = aead.api_decrypt(
+        &sealed.key,
+        &sealed.nonce,
+        b"aad",
+        &mut sealed.data,
+        &sealed.tag,
+    );
+
+    assert_eq!(result, Err(AeadError::AuthenticationFailed));
+}
+
+#[test]
+fn test_committing_tampered_commitment_fails() {
+    let mut aead = CommittingAead::new(Aead::new());
+    let mut sealed = seal(&mut aead, b"attack at dawn");
+
+    sealed.tag[0] ^= 0x01;
+
+    let result = aead.api_decrypt(
+        &sealed.key,
+        &sealed.nonce,
+        b"aad",
+        &mut sealed.data,
+        &sealed.tag,
+    );
+
+    assert_eq!(result, Err(AeadError::CommitmentMismatch));
+}
+
+#[test]
+fn test_committing_rejects_inner_sized_tag() {
+    let mut aead = CommittingAead::new(Aead::new());
+    let key = vec![0u8; aead.api_key_size()];
+    let nonce = vec![0u8; aead.api_nonce_size()];
+    let mut data = [0u8; 8];
+    let mut tag = vec![0u8; aead.inner().api_tag_size()];
+
+    let result = aead.api_encrypt(&key, &nonce, &[], &mut data, &mut tag);
+    assert_eq!(result, Err(AeadError::InvalidTagSize));
+
+    let result = aead.api_decrypt(&key, &nonce, &[], &mut data, &tag);
+    assert_eq!(result, Err(AeadError::InvalidTagSize));
+}
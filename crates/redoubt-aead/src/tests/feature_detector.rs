@@ -0,0 +1,70 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use crate::aead::Aead;
+use crate::feature_detector::{
+    AeadCapabilities, FeatureDetector, FeatureDetectorBehaviour, detect_capabilities,
+    detect_capabilities_with_feature_detector,
+};
+
+fn assert_consistent(capabilities: &AeadCapabilities) {
+    assert_eq!(
+        capabilities.aegis,
+        capabilities.backend_name == "AEGIS-128L"
+    );
+    assert_eq!(capabilities.portable_aegis, cfg!(feature = "pure-rust"));
+
+    if capabilities.hardware_aes || capabilities.portable_aegis {
+        assert!(capabilities.aegis);
+    } else {
+        assert_eq!(capabilities.backend_name, "XChaCha20-Poly1305");
+    }
+}
+
+// =============================================================================
+// detect_capabilities()
+// =============================================================================
+
+#[test]
+fn test_detect_capabilities_matches_aead_new() {
+    let capabilities = detect_capabilities();
+
+    assert_consistent(&capabilities);
+    assert_eq!(capabilities.backend_name, Aead::new().backend_name());
+}
+
+#[test]
+fn test_detect_capabilities_is_stable() {
+    assert_eq!(detect_capabilities(), detect_capabilities());
+}
+
+#[test]
+fn test_detect_capabilities_consistent_for_forced_behaviours() {
+    let behaviours = [
+        FeatureDetectorBehaviour::None,
+        #[cfg(any(
+            all(target_arch = "x86_64", not(target_os = "windows")),
+            target_arch = "aarch64"
+        ))]
+        FeatureDetectorBehaviour::ForceAesTrue,
+        FeatureDetectorBehaviour::ForceAesFalse,
+    ];
+
+    for behaviour in behaviours {
+        let mut fd = FeatureDetector::new();
+        fd.change_behaviour(behaviour);
+
+        assert_consistent(&detect_capabilities_with_feature_detector(fd));
+    }
+}
+
+#[test]
+fn test_detect_capabilities_without_aes() {
+    let mut fd = FeatureDetector::new();
+    fd.change_behaviour(FeatureDetectorBehaviour::ForceAesFalse);
+    let capabilities = detect_capabilities_with_feature_detector(fd);
+
+    assert!(!capabilities.hardware_aes);
+    assert_eq!(capabilities.aegis, cfg!(feature = "pure-rust"));
+}
@@ -3,4 +3,7 @@
 // See LICENSE in the repository root for full license text.
 
 mod aead;
+mod committing;
+mod feature_detector;
+mod stream;
 mod support;
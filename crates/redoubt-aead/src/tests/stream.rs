@@ -0,0 +1,209 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use redoubt_aead_core::AeadError;
+use redoubt_util::is_slice_zeroized;
+
+use crate::aead::{Aead, AeadVariant};
+use crate::stream::{AeadStreamDecryptor, AeadStreamEncryptor, STREAM_NONCE_OVERHEAD};
+
+const VARIANTS: [AeadVariant; 2] = [AeadVariant::Auto, AeadVariant::XChachaPoly1305];
+
+fn key_for(variant: AeadVariant) -> Vec<u8> {
+    vec![0x42u8; Aead::from(variant).key_size()]
+}
+
+/// Encrypts `chunks` (last one via finalize) and returns (nonce_prefix, sealed chunks).
+fn seal_chunks(variant: AeadVariant, key: &[u8], chunks: &[&[u8]]) -> (Vec<u8>, Vec<Vec<u8>>) {
+    let mut enc = AeadStreamEncryptor::new(Aead::from(variant), key)
+        .expect("Failed to AeadStreamEncryptor::new(..)");
+    let prefix = enc.nonce_prefix().to_vec();
+    let mut sealed = Vec::new();
+
+    let (last, rest) = chunks.split_last().expect("Failed to split_last()");
+
+    for chunk in rest {
+        let mut chunk = chunk.to_vec();
+        sealed.push(enc.update(&mut chunk).expect("Failed to update(..)"));
+    }
+
+    let mut last = last.to_vec();
+    sealed.push(enc.finalize(&mut last).expect("Failed to finalize(..)"));
+
+    (prefix, sealed)
+}
+
+// =============================================================================
+// new()
+// =============================================================================
+
+#[test]
+fn test_stream_nonce_prefix_size() {
+    for variant in VARIANTS {
+        let key = key_for(variant);
+        let enc = AeadStreamEncryptor::new(Aead::from(variant), &key)
+            .expect("Failed to AeadStreamEncryptor::new(..)");
+
+        assert_eq!(
+            enc.nonce_prefix().len() + STREAM_NONCE_OVERHEAD,
+            Aead::from(variant).nonce_size()
+        );
+    }
+}
+
+#[test]
+fn test_stream_decryptor_rejects_invalid_prefix_size() {
+    let key = key_for(AeadVariant::Auto);
+
+    let result = AeadStreamDecryptor::new(Aead::new(), &key, &[0u8; 3]);
+
+    assert!(matches!(result, Err(AeadError::InvalidNonceSize)));
+}
+
+// =============================================================================
+// update() / finalize()
+// =============================================================================
+
+#[test]
+fn test_stream_roundtrip_three_chunks() {
+    for variant in VARIANTS {
+        let key = key_for(variant);
+        let chunks: [&[u8]; 3] = [&[0xAA; 100], &[0xBB; 37], &[0xCC; 5]];
+
+        let (prefix, mut sealed) = seal_chunks(variant, &key, &chunks);
+
+        let mut dec = AeadStreamDecryptor::new(Aead::from(variant), &key, &prefix)
+            .expect("Failed to AeadStreamDecryptor::new(..)");
+
+        let p0 = dec.update(&mut sealed[0]).expect("Failed to update(..)");
+        let p1 = dec.update(&mut sealed[1]).expect("Failed to update(..)");
+        let p2 = dec
+            .finalize(&mut sealed[2])
+            .expect("Failed to finalize(..)");
+
+        assert_eq!(p0, chunks[0]);
+        assert_eq!(p1, chunks[1]);
+        assert_eq!(p2, chunks[2]);
+    }
+}
+
+#[test]
+fn test_stream_roundtrip_empty_final_chunk() {
+    let key = key_for(AeadVariant::Auto);
+    let (prefix, mut sealed) = seal_chunks(AeadVariant::Auto, &key, &[&[0x11; 16], &[]]);
+
+    let mut dec = AeadStreamDecryptor::new(Aead::new(), &key, &prefix)
+        .expect("Failed to AeadStreamDecryptor::new(..)");
+
+    assert_eq!(
+        dec.update(&mut sealed[0]).expect("Failed to update(..)"),
+        [0x11; 16]
+    );
+    assert!(
+        dec.finalize(&mut sealed[1])
+            .expect("Failed to finalize(..)")
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_stream_update_zeroizes_plaintext_chunk() {
+    let key = key_for(AeadVariant::Auto);
+    let mut enc = AeadStreamEncryptor::new(Aead::new(), &key)
+        .expect("Failed to AeadStreamEncryptor::new(..)");
+
+    let mut chunk = [0xAAu8; 64];
+    enc.update(&mut chunk).expect("Failed to update(..)");
+    assert!(is_slice_zeroized(&chunk));
+
+    let mut chunk = [0xBBu8; 64];
+    enc.finalize(&mut chunk).expect("Failed to finalize(..)");
+    assert!(is_slice_zeroized(&chunk));
+}
+
+#[test]
+fn test_stream_decrypt_zeroizes_consumed_chunk() {
+    let key = key_for(AeadVariant::Auto);
+    let (prefix, mut sealed) = seal_chunks(AeadVariant::Auto, &key, &[&[0xAA; 32], &[0xBB; 32]]);
+
+    let mut dec = AeadStreamDecryptor::new(Aead::new(), &key, &prefix)
+        .expect("Failed to AeadStreamDecryptor::new(..)");
+
+    dec.update(&mut sealed[0]).expect("Failed to update(..)");
+    assert!(is_slice_zeroized(&sealed[0]));
+
+    dec.finalize(&mut sealed[1])
+        .expect("Failed to finalize(..)");
+    assert!(is_slice_zeroized(&sealed[1]));
+}
+
+// =============================================================================
+// Tampering
+// =============================================================================
+
+#[test]
+fn test_stream_detects_truncation() {
+    for variant in VARIANTS {
+        let key = key_for(variant);
+        let (prefix, mut sealed) =
+            seal_chunks(variant, &key, &[&[0xAA; 32], &[0xBB; 32], &[0xCC; 32]]);
+
+        let mut dec = AeadStreamDecryptor::new(Aead::from(variant), &key, &prefix)
+            .expect("Failed to AeadStreamDecryptor::new(..)");
+
+        dec.update(&mut sealed[0]).expect("Failed to update(..)");
+
+        // Attacker drops the last chunk: the second one was not sealed as final
+        let result = dec.finalize(&mut sealed[1]);
+
+        assert!(matches!(result, Err(AeadError::AuthenticationFailed)));
+    }
+}
+
+#[test]
+fn test_stream_detects_final_chunk_used_as_update() {
+    let key = key_for(AeadVariant::Auto);
+    let (prefix, mut sealed) = seal_chunks(AeadVariant::Auto, &key, &[&[0xAA; 32]]);
+
+    let mut dec = AeadStreamDecryptor::new(Aead::new(), &key, &prefix)
+        .expect("Failed to AeadStreamDecryptor::new(..)");
+
+    let result = dec.update(&mut sealed[0]);
+
+    assert!(matches!(result, Err(AeadError::AuthenticationFailed)));
+}
+
+#[test]
+fn test_stream_detects_reordering() {
+    let key = key_for(AeadVariant::Auto);
+    let (prefix, mut sealed) = seal_chunks(
+        AeadVariant::Auto,
+        &key,
+        &[&[0xAA; 32], &[0xBB; 32], &[0xCC; 32]],
+    );
+
+    let mut dec = AeadStreamDecryptor::new(Aead::new(), &key, &prefix)
+        .expect("Failed to AeadStreamDecryptor::new(..)");
+
+    let result = dec.update(&mut sealed[1]);
+
+    assert!(matches!(result, Err(AeadError::AuthenticationFailed)));
+}
+
+#[test]
+fn test_stream_rejects_chunk_shorter_than_tag() {
+    let key = key_for(AeadVariant::Auto);
+    let mut dec = AeadStreamDecryptor::new(
+        Aead::new(),
+        &key,
+        &vec![0u8; Aead::new().nonce_size() - STREAM_NONCE_OVERHEAD],
+    )
+    .expect("Failed to AeadStreamDecryptor::new(..)");
+
+    let mut chunk = [0xEEu8; 4];
+    let result = dec.update(&mut chunk);
+
+    assert!(matches!(result, Err(AeadError::InvalidTagSize)));
+    assert!(is_slice_zeroized(&chunk));
+}
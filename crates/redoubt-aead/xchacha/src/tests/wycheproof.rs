@@ -173,6 +173,13 @@ fn run_test_case(tc: &TestCase) -> Result<(), String> {
             // Size validation errors (invalid test vectors)
             Ok(())
         }
+        (
+            TestResult::Invalid,
+            Err(e @ (AeadError::StreamCounterOverflow | AeadError::CommitmentMismatch)),
+        ) => Err(format!(
+            "tc_id {} ({}): unexpected {:?} error",
+            tc.tc_id, tc.comment, e
+        )),
     }
 }
 
@@ -3,8 +3,10 @@
 // See LICENSE in the repository root for full license text.
 
 use alloc::vec::Vec;
+use core::marker::PhantomData;
 
 use crate::error::AllockedVecError;
+use crate::thread_marker::ThreadMarker;
 use redoubt_zero::{
     FastZeroizable, RedoubtZero, ZeroizationProbe, ZeroizeMetadata, ZeroizeOnDropSentinel,
 };
@@ -115,6 +117,8 @@ where
     has_been_sealed: bool,
     #[cfg(any(test, feature = "test-utils"))]
     behaviour: AllockedVecBehaviour,
+    #[fast_zeroize(skip)]
+    __thread_marker: ThreadMarker,
     __sentinel: ZeroizeOnDropSentinel,
 }
 
@@ -204,6 +208,7 @@ where
             has_been_sealed: false,
             #[cfg(any(test, feature = "test-utils"))]
             behaviour: AllockedVecBehaviour::default(),
+            __thread_marker: PhantomData,
             __sentinel: ZeroizeOnDropSentinel::default(),
         }
     }
@@ -310,6 +315,95 @@ where
         Ok(())
     }
 
+    /// Inserts a value at `index`, shifting all elements after it to the right.
+    ///
+    /// Like [`push()`](Self::push), this never reallocates.
+    ///
+    /// # Errors
+    ///
+    /// - [`AllockedVecError::IndexOutOfBounds`] if `index > len()`.
+    /// - [`AllockedVecError::CapacityExceeded`] if the vector is at capacity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use redoubt_alloc::{AllockedVec, AllockedVecError};
+    ///
+    /// fn example() -> Result<(), AllockedVecError> {
+    ///     let mut vec = AllockedVec::with_capacity(3);
+    ///     vec.push(1u8)?;
+    ///     vec.push(3u8)?;
+    ///     vec.try_insert(1, 2u8)?;
+    ///
+    ///     assert_eq!(vec.as_slice(), &[1, 2, 3]);
+    ///     // Full
+    ///     assert!(vec.try_insert(0, 0u8).is_err());
+    ///     Ok(())
+    /// }
+    /// # example().unwrap();
+    /// ```
+    pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), AllockedVecError> {
+        if index > self.len() {
+            return Err(AllockedVecError::IndexOutOfBounds);
+        }
+
+        if self.len() >= self.capacity() {
+            return Err(AllockedVecError::CapacityExceeded);
+        }
+
+        self.inner.insert(index, value);
+        Ok(())
+    }
+
+    /// Removes and returns the element at `index`, shifting all elements after it to the left.
+    ///
+    /// Returns `None` if `index >= len()`.
+    ///
+    /// # Security
+    ///
+    /// Shifting leaves a stale copy of the last element in the slot just past the
+    /// new `len()`. That slot is zeroized so no copy remains in spare capacity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use redoubt_alloc::{AllockedVec, AllockedVecError};
+    ///
+    /// fn example() -> Result<(), AllockedVecError> {
+    ///     let mut vec = AllockedVec::with_capacity(3);
+    ///     vec.push(1u8)?;
+    ///     vec.push(2u8)?;
+    ///     vec.push(3u8)?;
+    ///
+    ///     assert_eq!(vec.remove(1), Some(2));
+    ///     assert_eq!(vec.as_slice(), &[1, 3]);
+    ///     assert_eq!(vec.remove(5), None);
+    ///     Ok(())
+    /// }
+    /// # example().unwrap();
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let value = self.inner.remove(index);
+
+        unsafe {
+            // SAFETY (PRECONDITIONS ARE MET): the slot at len() was occupied before
+            // remove(), so it lies within the allocation
+            let vacated_ptr = self.inner.as_mut_ptr().add(self.inner.len()) as *mut u8;
+            let byte_len = core::mem::size_of::<T>();
+            core::ptr::write_bytes(vacated_ptr, 0, byte_len);
+            // Volatile read prevents the optimizer from removing the write_bytes
+            if byte_len > 0 {
+                core::ptr::read_volatile(vacated_ptr);
+            }
+        }
+
+        Some(value)
+    }
+
     /// Returns the number of elements in the vector.
     ///
     /// # Example
@@ -380,6 +474,9 @@ where
 
     /// Returns a mutable slice view of the vector.
     ///
+    /// The slice covers only `0..len`, never spare capacity, and cannot be used
+    /// to grow the vector or change its capacity.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -400,6 +497,32 @@ where
         &mut self.inner
     }
 
+    /// Returns an iterator over mutable references to the elements.
+    ///
+    /// Like [`as_mut_slice`](Self::as_mut_slice), only `0..len` is visited.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use redoubt_alloc::{AllockedVec, AllockedVecError};
+    ///
+    /// fn example() -> Result<(), AllockedVecError> {
+    ///     let mut vec = AllockedVec::with_capacity(3);
+    ///     vec.push(1u8)?;
+    ///     vec.push(2u8)?;
+    ///
+    ///     for x in vec.iter_mut() {
+    ///         *x *= 10;
+    ///     }
+    ///     assert_eq!(vec.as_slice(), &[10, 20]);
+    ///     Ok(())
+    /// }
+    /// # example().unwrap();
+    /// ```
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.inner.iter_mut()
+    }
+
     /// Truncates the vector to the specified length, zeroizing removed elements.
     ///
     /// If `new_len` is greater than or equal to the current length, this is a no-op.
@@ -447,6 +570,36 @@ where
         }
     }
 
+    /// Clears the vector for reuse, zeroizing the entire allocation.
+    ///
+    /// Unlike `Vec::clear`, which leaves old bytes in spare capacity, this wipes
+    /// `0..capacity()` and sets `len()` to 0. Capacity and the sealed state are
+    /// preserved, so the buffer can be refilled without reallocating.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use redoubt_alloc::{AllockedVec, AllockedVecError};
+    ///
+    /// fn example() -> Result<(), AllockedVecError> {
+    ///     let mut vec = AllockedVec::with_capacity(5);
+    ///     vec.push(1u8)?;
+    ///     vec.push(2u8)?;
+    ///
+    ///     vec.clear_and_zeroize();
+    ///
+    ///     assert!(vec.is_empty());
+    ///     assert_eq!(vec.capacity(), 5);
+    ///     Ok(())
+    /// }
+    /// # example().unwrap();
+    /// ```
+    pub fn clear_and_zeroize(&mut self) {
+        self.inner.fast_zeroize();
+        self.inner.clear();
+        redoubt_util::fast_zeroize_vec(&mut self.inner);
+    }
+
     /// Drains values from a mutable slice into the vector.
     ///
     /// The source slice is zeroized after draining (each element replaced with `T::default()`).
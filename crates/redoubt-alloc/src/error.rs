@@ -24,6 +24,14 @@ pub enum AllockedVecError {
     /// Attempted to push beyond the vector's capacity.
     #[error("Capacity exceeded: cannot push beyond sealed capacity")]
     CapacityExceeded,
+
+    /// Index is out of range for the current length.
+    #[error("Index out of bounds")]
+    IndexOutOfBounds,
+
+    /// Source and destination lengths differ.
+    #[error("Length mismatch: source and destination lengths differ")]
+    LengthMismatch,
 }
 
 /// Error type for `RedoubtOption` operations.
@@ -33,3 +41,15 @@ pub enum RedoubtOptionError {
     #[error("RedoubtOption is empty")]
     Empty,
 }
+
+/// Error type for `RedoubtArray` hex decoding.
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum RedoubtArrayError {
+    /// The input contains a character outside `0-9`, `a-f`, `A-F`.
+    #[error("Invalid hex: input contains a non-hexadecimal character")]
+    InvalidHex,
+
+    /// The decoded length differs from the array length.
+    #[error("Length mismatch: hex input does not decode to exactly N bytes")]
+    LengthMismatch,
+}
@@ -19,6 +19,10 @@
 //! - **Automatic zeroization**: All data is zeroized on drop via `#[fast_zeroize(drop)]`
 //! - **Fallible operations**: `push()` and `reserve_exact()` fail instead of reallocating,
 //!   preventing unintended copies of data
+//! - **Optional thread-locality**: With the `single-thread` feature, every container is
+//!   `!Send + !Sync` (see [`ThreadMarker`])
+//! - **Optional zeroizing allocator**: With the `alloc-wrapper` feature,
+//!   `ZeroizingAllocator` wraps any `GlobalAlloc` and zeroizes every block on `dealloc`
 //!
 //! # Example: Basic Usage
 //!
@@ -114,16 +118,22 @@ mod redoubt_array;
 mod redoubt_option;
 mod redoubt_string;
 mod redoubt_vec;
+mod thread_marker;
+#[cfg(any(test, feature = "alloc-wrapper"))]
+mod zeroizing_allocator;
 
 #[cfg(test)]
 mod tests;
 
 pub use allocked_vec::AllockedVec;
-pub use error::{AllockedVecError, RedoubtOptionError};
+pub use error::{AllockedVecError, RedoubtArrayError, RedoubtOptionError};
 pub use redoubt_array::RedoubtArray;
 pub use redoubt_option::RedoubtOption;
 pub use redoubt_string::RedoubtString;
 pub use redoubt_vec::RedoubtVec;
+pub use thread_marker::ThreadMarker;
+#[cfg(any(test, feature = "alloc-wrapper"))]
+pub use zeroizing_allocator::ZeroizingAllocator;
 
 #[cfg(any(test, feature = "test-utils"))]
 pub use allocked_vec::AllockedVecBehaviour;
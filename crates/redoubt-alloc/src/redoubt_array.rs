@@ -3,8 +3,12 @@
 // See LICENSE in the repository root for full license text.
 
 use alloc::boxed::Box;
-use core::ops::{Deref, DerefMut};
+use alloc::string::String;
+use core::marker::PhantomData;
+use core::ops::{BitXorAssign, Deref, DerefMut};
 
+use crate::error::{AllockedVecError, RedoubtArrayError};
+use crate::thread_marker::ThreadMarker;
 use redoubt_zero::{
     FastZeroizable, RedoubtZero, ZeroizationProbe, ZeroizeMetadata, ZeroizeOnDropSentinel,
 };
@@ -33,22 +37,52 @@ where
     T: FastZeroizable + ZeroizeMetadata + ZeroizationProbe,
 {
     inner: Box<[T; N]>,
+    #[fast_zeroize(skip)]
+    __thread_marker: ThreadMarker,
     __sentinel: ZeroizeOnDropSentinel,
 }
 
+/// Constant-time equality for byte arrays.
+///
+/// This intentionally overrides the variable-time, short-circuiting comparison
+/// of `[u8; N]`: arrays commonly hold PIN hashes or MACs, and an early exit
+/// would leak how many leading bytes match. Only available for `u8` elements
+/// outside of tests.
+#[cfg(not(any(test, feature = "test-utils")))]
+impl<const N: usize> PartialEq for RedoubtArray<u8, N> {
+    fn eq(&self, other: &Self) -> bool {
+        // Skip __sentinel (metadata that changes during zeroization)
+        self.ct_eq(other.as_slice())
+    }
+}
+
+#[cfg(not(any(test, feature = "test-utils")))]
+impl<const N: usize> Eq for RedoubtArray<u8, N> {}
+
+/// Element-wise equality for tests, over any `PartialEq` element type.
+///
+/// Byte arrays still go through [`ct_eq`](RedoubtArray::ct_eq), so enabling
+/// `test-utils` does not change their timing behavior.
 #[cfg(any(test, feature = "test-utils"))]
-impl<T: FastZeroizable + ZeroizeMetadata + ZeroizationProbe + PartialEq, const N: usize> PartialEq
-    for RedoubtArray<T, N>
+impl<T, const N: usize> PartialEq for RedoubtArray<T, N>
+where
+    T: FastZeroizable + ZeroizeMetadata + ZeroizationProbe + PartialEq + 'static,
 {
     fn eq(&self, other: &Self) -> bool {
         // Skip __sentinel (metadata that changes during zeroization)
-        self.inner == other.inner
+        let bytes = (&*self.inner as &dyn core::any::Any).downcast_ref::<[u8; N]>();
+        let other_bytes = (&*other.inner as &dyn core::any::Any).downcast_ref::<[u8; N]>();
+
+        match (bytes, other_bytes) {
+            (Some(bytes), Some(other_bytes)) => redoubt_util::constant_time_eq(bytes, other_bytes),
+            _ => self.inner == other.inner,
+        }
     }
 }
 
 #[cfg(any(test, feature = "test-utils"))]
-impl<T: FastZeroizable + ZeroizeMetadata + Eq + ZeroizationProbe, const N: usize> Eq
-    for RedoubtArray<T, N>
+impl<T, const N: usize> Eq for RedoubtArray<T, N> where
+    T: FastZeroizable + ZeroizeMetadata + ZeroizationProbe + Eq + 'static
 {
 }
 
@@ -75,6 +109,7 @@ where
     {
         Self {
             inner: Box::new(core::array::from_fn(|_| T::default())),
+            __thread_marker: PhantomData,
             __sentinel: ZeroizeOnDropSentinel::default(),
         }
     }
@@ -120,6 +155,28 @@ where
         src.fast_zeroize();
     }
 
+    /// Copies the array into `dst`, which must hold exactly `N` elements.
+    ///
+    /// Unlike `dst.copy_from_slice(..)`, a length mismatch is reported instead
+    /// of panicking, and no partial copy is ever performed. The caller is
+    /// responsible for zeroizing `dst` once it is no longer needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AllockedVecError::LengthMismatch`] if `dst.len() != N`.
+    pub fn try_copy_into_slice(&self, dst: &mut [T]) -> Result<(), AllockedVecError>
+    where
+        T: Copy,
+    {
+        if dst.len() != N {
+            return Err(AllockedVecError::LengthMismatch);
+        }
+
+        dst.copy_from_slice(self.inner.as_ref());
+
+        Ok(())
+    }
+
     /// Returns a slice containing the entire array.
     #[inline]
     pub fn as_slice(&self) -> &[T] {
@@ -145,6 +202,117 @@ where
     }
 }
 
+impl<const N: usize> RedoubtArray<u8, N> {
+    /// Compares the array against `other` in constant time.
+    ///
+    /// Returns `false` if `other.len() != N`. Otherwise every byte is compared
+    /// via [`redoubt_util::constant_time_eq`], regardless of where they differ.
+    #[inline]
+    pub fn ct_eq(&self, other: &[u8]) -> bool {
+        redoubt_util::constant_time_eq(self.as_slice(), other)
+    }
+
+    /// XORs `other` into the array in place, byte by byte over all `N` bytes.
+    ///
+    /// Keeps key mixing inside the zeroizing container instead of exposing raw
+    /// slices. Also available as `^=` via [`BitXorAssign`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use redoubt_alloc::RedoubtArray;
+    ///
+    /// let mut key = RedoubtArray::from_mut_array(&mut [0b1100u8; 4]);
+    /// let pad = RedoubtArray::from_mut_array(&mut [0b1010u8; 4]);
+    ///
+    /// key.xor_assign(&pad);
+    /// assert_eq!(key.as_slice(), &[0b0110; 4]);
+    /// ```
+    #[inline]
+    pub fn xor_assign(&mut self, other: &Self) {
+        for (dst, src) in self.inner.iter_mut().zip(other.inner.iter()) {
+            *dst ^= *src;
+        }
+    }
+
+    /// Decodes a hex string (e.g. a test vector) into a new array.
+    ///
+    /// Bytes are decoded straight into the array's heap allocation, so no
+    /// temporary buffer holds the decoded value. On an invalid character the
+    /// partially decoded array is zeroized before the error is returned.
+    ///
+    /// # Errors
+    ///
+    /// - [`RedoubtArrayError::LengthMismatch`] if `hex.len() != 2 * N`
+    /// - [`RedoubtArrayError::InvalidHex`] if `hex` contains a non-hex character
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use redoubt_alloc::RedoubtArray;
+    ///
+    /// let arr = RedoubtArray::<u8, 4>::try_from_hex("deadBEEF").unwrap();
+    /// assert_eq!(arr.as_slice(), &[0xde, 0xad, 0xbe, 0xef]);
+    /// ```
+    pub fn try_from_hex(hex: &str) -> Result<Self, RedoubtArrayError> {
+        let hex = hex.as_bytes();
+
+        if hex.len() != 2 * N {
+            return Err(RedoubtArrayError::LengthMismatch);
+        }
+
+        let mut arr = Self::new();
+
+        for (byte, pair) in arr.inner.iter_mut().zip(hex.chunks_exact(2)) {
+            match (hex_nibble(pair[0]), hex_nibble(pair[1])) {
+                (Some(hi), Some(lo)) => *byte = (hi << 4) | lo,
+                _ => {
+                    arr.fast_zeroize();
+                    return Err(RedoubtArrayError::InvalidHex);
+                }
+            }
+        }
+
+        Ok(arr)
+    }
+
+    /// Encodes the array as a lowercase hex string.
+    ///
+    /// **This leaks the contents**: the returned `String` is an ordinary,
+    /// non-zeroizing copy. Only use it for debugging or for arrays that do not
+    /// hold secrets.
+    pub fn to_hex(&self) -> String {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+        let mut hex = String::with_capacity(2 * N);
+
+        for byte in self.inner.iter() {
+            hex.push(DIGITS[(byte >> 4) as usize] as char);
+            hex.push(DIGITS[(byte & 0x0f) as usize] as char);
+        }
+
+        hex
+    }
+}
+
+/// Decodes a single ASCII hex digit.
+#[inline(always)]
+fn hex_nibble(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+impl<const N: usize> BitXorAssign<&RedoubtArray<u8, N>> for RedoubtArray<u8, N> {
+    #[inline]
+    fn bitxor_assign(&mut self, other: &RedoubtArray<u8, N>) {
+        self.xor_assign(other);
+    }
+}
+
 impl<T, const N: usize> Default for RedoubtArray<T, N>
 where
     T: FastZeroizable + ZeroizeMetadata + ZeroizationProbe + Default,
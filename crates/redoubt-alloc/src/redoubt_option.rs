@@ -7,6 +7,7 @@ use redoubt_zero::{
 };
 
 use crate::error::RedoubtOptionError;
+use crate::thread_marker::ThreadMarker;
 
 /// An optional value wrapper with automatic zeroization.
 #[derive(RedoubtZero, Default)]
@@ -15,6 +16,8 @@ where
     T: FastZeroizable + ZeroizeMetadata + ZeroizationProbe,
 {
     inner: Option<T>,
+    #[fast_zeroize(skip)]
+    __thread_marker: ThreadMarker,
     __sentinel: ZeroizeOnDropSentinel,
 }
 
@@ -59,6 +62,42 @@ where
         self.inner.take().ok_or(RedoubtOptionError::Empty)
     }
 
+    /// Maps the inner value into a new `RedoubtOption<U>`, leaving `None` in its place.
+    ///
+    /// The closure receives the old value by mutable reference. Once it returns,
+    /// the old value is zeroized (this is why `T: FastZeroizable` is required)
+    /// before being dropped. Returns `None` without calling `f` if empty.
+    pub fn map<U, F>(&mut self, f: F) -> RedoubtOption<U>
+    where
+        U: FastZeroizable + ZeroizeMetadata + ZeroizationProbe,
+        F: FnOnce(&mut T) -> U,
+    {
+        let mut mapped = RedoubtOption::<U> {
+            inner: None,
+            __thread_marker: ThreadMarker::default(),
+            __sentinel: ZeroizeOnDropSentinel::default(),
+        };
+
+        if let Some(old) = &mut self.inner {
+            mapped.inner = Some(f(old));
+            // Zeroize old value after the closure ran
+            old.fast_zeroize();
+            self.inner = None;
+        }
+
+        mapped
+    }
+
+    /// Returns a mutable reference to the inner value, inserting the result of `f` if `None`.
+    ///
+    /// `f` is not called when the option already contains a value.
+    pub fn get_or_insert_with<F>(&mut self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        self.inner.get_or_insert_with(f)
+    }
+
     /// Returns `true` if the option contains a value.
     pub fn is_some(&self) -> bool {
         self.inner.is_some()
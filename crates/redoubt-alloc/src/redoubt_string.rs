@@ -3,10 +3,13 @@
 // See LICENSE in the repository root for full license text.
 
 use alloc::string::String;
-use core::ops::{Deref, DerefMut};
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut, Range};
 
 use redoubt_zero::{FastZeroizable, RedoubtZero, ZeroizeOnDropSentinel};
 
+use crate::thread_marker::ThreadMarker;
+
 /// A String wrapper with automatic zeroization and safe reallocation.
 ///
 /// When capacity is exceeded, `RedoubtString` performs a safe reallocation:
@@ -34,6 +37,8 @@ use redoubt_zero::{FastZeroizable, RedoubtZero, ZeroizeOnDropSentinel};
 #[derive(RedoubtZero)]
 pub struct RedoubtString {
     inner: String,
+    #[fast_zeroize(skip)]
+    __thread_marker: ThreadMarker,
     __sentinel: ZeroizeOnDropSentinel,
 }
 
@@ -63,6 +68,7 @@ impl RedoubtString {
     pub fn new() -> Self {
         Self {
             inner: String::new(),
+            __thread_marker: PhantomData,
             __sentinel: ZeroizeOnDropSentinel::default(),
         }
     }
@@ -71,6 +77,7 @@ impl RedoubtString {
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             inner: String::with_capacity(capacity),
+            __thread_marker: PhantomData,
             __sentinel: ZeroizeOnDropSentinel::default(),
         }
     }
@@ -176,12 +183,63 @@ impl RedoubtString {
         self.inner.push_str(src);
     }
 
+    /// Replaces the bytes in `range` with `replacement`, zeroizing the old bytes.
+    ///
+    /// 1. Zeroizes the bytes currently occupying `range`
+    /// 2. Grows via the safe reallocation path if the result exceeds capacity
+    /// 3. Splices `replacement` in
+    /// 4. Zeroizes the spare capacity, which holds stale tail bytes when
+    ///    `replacement` is shorter than `range`
+    ///
+    /// An empty `replacement` performs a pure deletion (still wiped).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.start > range.end`, if `range.end > len()`, or if either
+    /// bound does not lie on a UTF-8 `char` boundary. Nothing is modified in
+    /// that case.
+    pub fn replace_range(&mut self, range: Range<usize>, replacement: &str) {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "replace_range: range {}..{} out of bounds for length {}",
+            range.start,
+            range.end,
+            self.len()
+        );
+        assert!(
+            self.inner.is_char_boundary(range.start) && self.inner.is_char_boundary(range.end),
+            "replace_range: range {}..{} is not on a char boundary",
+            range.start,
+            range.end
+        );
+
+        // SAFETY: zeroes are valid single-byte UTF-8 (NUL)
+        unsafe { self.inner.as_mut_vec()[range.clone()].fast_zeroize() };
+
+        self.maybe_grow_to(self.len() - range.len() + replacement.len());
+
+        self.inner.replace_range(range, replacement);
+
+        // SAFETY: only spare capacity (beyond len) is written
+        redoubt_util::zeroize_spare_capacity(unsafe { self.inner.as_mut_vec() });
+    }
+
     /// Clears the string, removing all contents.
     pub fn clear(&mut self) {
         self.inner.fast_zeroize();
         self.inner.clear();
     }
 
+    /// Compares the string against `other` in constant time.
+    ///
+    /// The UTF-8 bytes are compared via [`redoubt_util::constant_time_eq`].
+    /// Returns `false` if the lengths differ, so the length itself is not
+    /// hidden; that is acceptable for passphrase verification.
+    #[inline]
+    pub fn ct_eq_str(&self, other: &str) -> bool {
+        redoubt_util::constant_time_eq(self.inner.as_bytes(), other.as_bytes())
+    }
+
     /// Returns a string slice containing the entire string.
     pub fn as_str(&self) -> &str {
         &self.inner
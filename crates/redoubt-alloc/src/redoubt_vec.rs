@@ -3,12 +3,16 @@
 // See LICENSE in the repository root for full license text.
 
 use alloc::vec::Vec;
-use core::ops::{Deref, DerefMut};
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut, Range};
 
 use redoubt_zero::{
     FastZeroizable, RedoubtZero, ZeroizationProbe, ZeroizeMetadata, ZeroizeOnDropSentinel,
 };
 
+use crate::error::AllockedVecError;
+use crate::thread_marker::ThreadMarker;
+
 /// A Vec wrapper with automatic zeroization and safe reallocation.
 ///
 /// When capacity is exceeded, `RedoubtVec` performs a safe reallocation:
@@ -39,6 +43,8 @@ where
     T: FastZeroizable + ZeroizeMetadata + ZeroizationProbe,
 {
     inner: Vec<T>,
+    #[fast_zeroize(skip)]
+    __thread_marker: ThreadMarker,
     __sentinel: ZeroizeOnDropSentinel,
 }
 
@@ -76,6 +82,7 @@ where
     pub fn new() -> Self {
         Self {
             inner: Vec::new(),
+            __thread_marker: PhantomData,
             __sentinel: ZeroizeOnDropSentinel::default(),
         }
     }
@@ -84,6 +91,7 @@ where
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             inner: Vec::with_capacity(capacity),
+            __thread_marker: PhantomData,
             __sentinel: ZeroizeOnDropSentinel::default(),
         }
     }
@@ -205,6 +213,38 @@ where
         src.fast_zeroize();
     }
 
+    /// Appends all of `data` without ever reallocating.
+    ///
+    /// Fail-fast counterpart of [`extend_from_mut_slice`](Self::extend_from_mut_slice),
+    /// in the spirit of [`AllockedVec`](crate::AllockedVec): remaining capacity is
+    /// checked up front, so the call either appends the whole slice or nothing.
+    ///
+    /// `data` is borrowed immutably and is NOT zeroized; the caller remains
+    /// responsible for wiping it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AllockedVecError::CapacityExceeded`] if `len() + data.len()`
+    /// exceeds `capacity()`. The vector is left untouched.
+    pub fn try_extend_from_slice(&mut self, data: &[T]) -> Result<(), AllockedVecError>
+    where
+        T: Copy,
+    {
+        if data.len() > self.capacity() - self.len() {
+            return Err(AllockedVecError::CapacityExceeded);
+        }
+
+        unsafe {
+            // SAFETY (PRECONDITIONS ARE MET): capacity was checked above, so
+            // len()..len() + data.len() lies within the allocation
+            let dst_ptr = self.inner.as_mut_ptr().add(self.len());
+            core::ptr::copy_nonoverlapping(data.as_ptr(), dst_ptr, data.len());
+            self.inner.set_len(self.len() + data.len());
+        }
+
+        Ok(())
+    }
+
     /// Replaces the vector contents with data from a mutable slice, zeroizing both
     /// the old contents and the source.
     pub fn replace_from_mut_slice(&mut self, src: &mut [T])
@@ -233,6 +273,107 @@ where
         self.inner.clear();
     }
 
+    /// Removes the elements in `range`, zeroizing them and the vacated tail.
+    ///
+    /// Unlike `Vec::drain`, the removed elements are not yielded: handing them
+    /// out would defeat the wipe. The steps are:
+    ///
+    /// 1. Zeroize the elements in `range`
+    /// 2. Remove them, shifting the tail down
+    /// 3. Zeroize the now-unused region between the new and the old `len()`,
+    ///    which still holds stale copies of the shifted tail
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range.start > range.end` or `range.end > len()`.
+    pub fn drain_zeroizing(&mut self, range: Range<usize>) {
+        let old_len = self.len();
+
+        self.inner[range.clone()].fast_zeroize();
+        self.inner.drain(range);
+
+        let new_len = self.len();
+        let vacated_bytes = (old_len - new_len) * core::mem::size_of::<T>();
+
+        if vacated_bytes == 0 {
+            return;
+        }
+
+        unsafe {
+            // SAFETY (PRECONDITIONS ARE MET): new_len..old_len lies within the
+            // allocation (old_len <= capacity)
+            let vacated_ptr = self.inner.as_mut_ptr().add(new_len) as *mut u8;
+            core::ptr::write_bytes(vacated_ptr, 0, vacated_bytes);
+            // Volatile read prevents the optimizer from removing the write_bytes
+            core::ptr::read_volatile(vacated_ptr);
+        }
+    }
+
+    /// Splits the vector in two at `at`, returning the tail `[at, len)`.
+    ///
+    /// The tail is moved into a freshly allocated `RedoubtVec`, so it owns its
+    /// own zeroizing allocation. The region of `self` it occupied is zeroized
+    /// and becomes spare capacity; `self`'s capacity is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len()`.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len();
+        assert!(
+            at <= len,
+            "`at` split index (is {at}) should be <= len (is {len})"
+        );
+
+        let tail_len = len - at;
+        let mut tail = Self::with_capacity(tail_len);
+
+        if tail_len == 0 {
+            return tail;
+        }
+
+        unsafe {
+            // SAFETY (PRECONDITIONS ARE MET): at..len lies within self's
+            // initialized elements, tail has capacity for tail_len elements.
+            // Ownership moves bitwise: self is truncated to `at` before the
+            // source bytes are wiped, so nothing is dropped twice.
+            let src_ptr = self.inner.as_mut_ptr().add(at);
+            core::ptr::copy_nonoverlapping(src_ptr, tail.inner.as_mut_ptr(), tail_len);
+            tail.inner.set_len(tail_len);
+            self.inner.set_len(at);
+
+            let vacated_ptr = src_ptr as *mut u8;
+            core::ptr::write_bytes(vacated_ptr, 0, tail_len * core::mem::size_of::<T>());
+            // Volatile read prevents the optimizer from removing the write_bytes
+            core::ptr::read_volatile(vacated_ptr);
+        }
+
+        tail
+    }
+
+    /// Binary searches a sorted vector of byte keys for `target`.
+    ///
+    /// Each comparison uses [`redoubt_util::constant_time_cmp`], so the compare
+    /// itself does not leak how many leading bytes of a key match `target`.
+    ///
+    /// # Timing
+    ///
+    /// This only hardens the per-element comparison. The sequence of probed
+    /// indices (and therefore memory access pattern and running time) still
+    /// depends on where `target` sorts. For a fully constant-time lookup, use a
+    /// linear scan that compares every element.
+    ///
+    /// Returns `Some(index)` if found (any match if keys repeat), `None` otherwise.
+    /// The vector must be sorted by byte-wise lexicographic order.
+    pub fn binary_search_ct(&self, target: &[u8]) -> Option<usize>
+    where
+        T: AsRef<[u8]>,
+    {
+        self.inner
+            .binary_search_by(|key| redoubt_util::constant_time_cmp(key.as_ref(), target))
+            .ok()
+    }
+
     /// Returns a slice containing the entire vector.
     pub fn as_slice(&self) -> &[T] {
         &self.inner
@@ -302,6 +443,36 @@ where
     }
 }
 
+impl RedoubtVec<u8> {
+    /// Returns `true` if `needle` occurs anywhere in the vector, in constant time.
+    ///
+    /// Every byte is compared and the matches are OR-ed into a mask, so the
+    /// running time depends only on the length, never on whether or where
+    /// `needle` occurs. Use this instead of `iter().position()` to check
+    /// secret content (e.g. for a forbidden byte) without a timing oracle.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use redoubt_alloc::RedoubtVec;
+    ///
+    /// let vec = RedoubtVec::from_mut_slice(&mut [0x61u8, 0x00, 0x62]);
+    ///
+    /// assert!(vec.ct_contains(0x00));
+    /// assert!(!vec.ct_contains(0x0A));
+    /// ```
+    pub fn ct_contains(&self, needle: u8) -> bool {
+        let mut found = 0u8;
+
+        for &byte in self.inner.iter() {
+            // Borrow bit of the 16-bit subtraction: 1 iff byte == needle
+            found |= (((byte ^ needle) as u16).wrapping_sub(1) >> 8) as u8 & 1;
+        }
+
+        core::hint::black_box(found) == 1
+    }
+}
+
 impl<T> Default for RedoubtVec<T>
 where
     T: FastZeroizable + ZeroizeMetadata + ZeroizationProbe,
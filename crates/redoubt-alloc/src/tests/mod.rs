@@ -6,4 +6,6 @@ mod redoubt_array;
 mod redoubt_option;
 mod redoubt_string;
 mod redoubt_vec;
+mod thread_marker;
 mod vec;
+mod zeroizing_allocator;
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // See LICENSE in the repository root for full license text.
 
-use crate::RedoubtArray;
+use crate::{AllockedVecError, RedoubtArray, RedoubtArrayError};
 use redoubt_zero::ZeroizationProbe;
 
 // =============================================================================
@@ -101,6 +101,44 @@ fn test_replace_from_mut_array_pattern() {
     assert!(src.is_zeroized());
 }
 
+// =============================================================================
+// try_copy_into_slice()
+// =============================================================================
+
+#[test]
+fn test_try_copy_into_slice() {
+    let mut src = [7u8; 32];
+    let arr = RedoubtArray::from_mut_array(&mut src);
+
+    let mut dst = vec![0u8; 32];
+    arr.try_copy_into_slice(&mut dst)
+        .expect("Failed to try_copy_into_slice");
+
+    assert_eq!(dst.as_slice(), arr.as_slice());
+}
+
+#[test]
+fn test_try_copy_into_slice_length_mismatch() {
+    let mut src = [7u8; 32];
+    let arr = RedoubtArray::from_mut_array(&mut src);
+
+    let mut shorter = vec![0u8; 31];
+    let mut longer = vec![0u8; 33];
+
+    assert_eq!(
+        arr.try_copy_into_slice(&mut shorter),
+        Err(AllockedVecError::LengthMismatch)
+    );
+    assert_eq!(
+        arr.try_copy_into_slice(&mut longer),
+        Err(AllockedVecError::LengthMismatch)
+    );
+
+    // No partial copy on error
+    assert!(shorter.is_zeroized());
+    assert!(longer.is_zeroized());
+}
+
 // =============================================================================
 // as_slice()
 // =============================================================================
@@ -259,6 +297,66 @@ fn test_partial_eq_different_arrays() {
     assert!(arr1 != arr2);
 }
 
+#[test]
+fn test_partial_eq_differs_in_last_byte() {
+    let mut arr1 = RedoubtArray::<u8, 32>::new();
+    let mut arr2 = RedoubtArray::<u8, 32>::new();
+
+    let mut src1 = [7u8; 32];
+    let mut src2 = [7u8; 32];
+    src2[31] = 8;
+
+    arr1.replace_from_mut_array(&mut src1);
+    arr2.replace_from_mut_array(&mut src2);
+
+    assert!(arr1 != arr2);
+}
+
+#[test]
+fn test_partial_eq_non_u8_elements() {
+    let mut arr1 = RedoubtArray::<u32, 4>::new();
+    let mut arr2 = RedoubtArray::<u32, 4>::new();
+    let mut arr3 = RedoubtArray::<u32, 4>::new();
+
+    arr1.replace_from_mut_array(&mut [1, 2, 3, 4]);
+    arr2.replace_from_mut_array(&mut [1, 2, 3, 4]);
+    arr3.replace_from_mut_array(&mut [1, 2, 3, 5]);
+
+    assert!(arr1 == arr2);
+    assert!(arr1 != arr3);
+}
+
+// =============================================================================
+// ct_eq()
+// =============================================================================
+
+#[test]
+fn test_ct_eq_equal_slice() {
+    let mut arr = RedoubtArray::<u8, 4>::new();
+    arr.replace_from_mut_array(&mut [1, 2, 3, 4]);
+
+    assert!(arr.ct_eq(&[1, 2, 3, 4]));
+}
+
+#[test]
+fn test_ct_eq_different_slice() {
+    let mut arr = RedoubtArray::<u8, 4>::new();
+    arr.replace_from_mut_array(&mut [1, 2, 3, 4]);
+
+    assert!(!arr.ct_eq(&[1, 2, 3, 5]));
+    assert!(!arr.ct_eq(&[0, 2, 3, 4]));
+}
+
+#[test]
+fn test_ct_eq_length_mismatch() {
+    let mut arr = RedoubtArray::<u8, 4>::new();
+    arr.replace_from_mut_array(&mut [1, 2, 3, 4]);
+
+    assert!(!arr.ct_eq(&[1, 2, 3]));
+    assert!(!arr.ct_eq(&[1, 2, 3, 4, 5]));
+    assert!(!arr.ct_eq(&[]));
+}
+
 // =============================================================================
 // Deref / DerefMut
 // =============================================================================
@@ -282,6 +380,100 @@ fn test_deref() {
     assert_eq!(arr[0], 99);
 }
 
+// =============================================================================
+// xor_assign(), BitXorAssign
+// =============================================================================
+
+#[test]
+fn test_xor_assign() {
+    let mut a_src = [0u8; 32];
+    let mut b_src = [0u8; 32];
+    for i in 0..32 {
+        a_src[i] = i as u8;
+        b_src[i] = 0xA5 ^ (i as u8).wrapping_mul(3);
+    }
+
+    let expected: Vec<u8> = a_src.iter().zip(b_src.iter()).map(|(a, b)| a ^ b).collect();
+
+    let mut a = RedoubtArray::from_mut_array(&mut a_src);
+    let b = RedoubtArray::from_mut_array(&mut b_src);
+
+    a.xor_assign(&b);
+    assert_eq!(a.as_slice(), expected.as_slice());
+
+    // XOR is its own inverse
+    a ^= &b;
+    for (i, byte) in a.iter().enumerate() {
+        assert_eq!(*byte, i as u8);
+    }
+}
+
+#[test]
+fn test_xor_assign_with_equal_array_zeroizes() {
+    let mut a = RedoubtArray::from_mut_array(&mut [0x5Au8; 32]);
+    let same = RedoubtArray::from_mut_array(&mut [0x5Au8; 32]);
+
+    a ^= &same;
+
+    assert!(a.is_zeroized());
+}
+
+// =============================================================================
+// try_from_hex(), to_hex()
+// =============================================================================
+
+#[test]
+fn test_try_from_hex() {
+    let arr = RedoubtArray::<u8, 4>::try_from_hex("00ffA5e1").expect("Failed to try_from_hex(..)");
+
+    assert_eq!(arr.as_slice(), &[0x00, 0xff, 0xa5, 0xe1]);
+}
+
+#[test]
+fn test_try_from_hex_length_mismatch() {
+    assert_eq!(
+        RedoubtArray::<u8, 4>::try_from_hex("00ffa5").unwrap_err(),
+        RedoubtArrayError::LengthMismatch
+    );
+    assert_eq!(
+        RedoubtArray::<u8, 4>::try_from_hex("00ffa5e1ff").unwrap_err(),
+        RedoubtArrayError::LengthMismatch
+    );
+    // Odd number of digits never decodes to whole bytes
+    assert_eq!(
+        RedoubtArray::<u8, 4>::try_from_hex("00ffa5e").unwrap_err(),
+        RedoubtArrayError::LengthMismatch
+    );
+}
+
+#[test]
+fn test_try_from_hex_invalid_character() {
+    assert_eq!(
+        RedoubtArray::<u8, 4>::try_from_hex("00ffa5zz").unwrap_err(),
+        RedoubtArrayError::InvalidHex
+    );
+    assert_eq!(
+        RedoubtArray::<u8, 4>::try_from_hex("0xffa5e1").unwrap_err(),
+        RedoubtArrayError::InvalidHex
+    );
+}
+
+#[test]
+fn test_to_hex_roundtrip() {
+    let mut src = [0u8; 32];
+    for (i, b) in src.iter_mut().enumerate() {
+        *b = (i as u8).wrapping_mul(37);
+    }
+    let arr = RedoubtArray::from_mut_array(&mut src);
+
+    let hex = arr.to_hex();
+    assert_eq!(hex.len(), 64);
+    assert_eq!(&hex[..6], "00254a");
+
+    let decoded = RedoubtArray::<u8, 32>::try_from_hex(&hex).expect("Failed to try_from_hex(..)");
+    assert!(decoded.ct_eq(arr.as_slice()));
+}
+
 // =============================================================================
 // Debug
 // =============================================================================
@@ -2,8 +2,11 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // See LICENSE in the repository root for full license text.
 
+use alloc::rc::Rc;
+use core::cell::Cell;
+
 use crate::{RedoubtOption, RedoubtOptionError};
-use redoubt_zero::ZeroizationProbe;
+use redoubt_zero::{FastZeroizable, ZeroizationProbe, ZeroizeMetadata, ZeroizeOnDropSentinel};
 
 #[test]
 fn test_redoubt_option_is_none_by_default() {
@@ -121,3 +124,114 @@ fn test_redoubt_option_as_mut_option() {
 
     assert_eq!(*opt.as_ref().expect("Failed to get as_ref"), 99);
 }
+
+#[test]
+fn test_redoubt_option_map_some() {
+    let mut opt = RedoubtOption::<u64>::default();
+    let mut value = 21u64;
+    opt.replace(&mut value);
+
+    let mapped = opt.map(|v| u32::try_from(*v * 2).expect("Failed to convert"));
+
+    assert!(opt.is_none());
+    assert_eq!(*mapped.as_ref().expect("Failed to get as_ref"), 42u32);
+}
+
+#[test]
+fn test_redoubt_option_map_none() {
+    let mut opt = RedoubtOption::<u64>::default();
+    let mut called = false;
+
+    let mapped = opt.map(|v| {
+        called = true;
+        *v
+    });
+
+    assert!(!called);
+    assert!(opt.is_none());
+    assert!(mapped.is_none());
+}
+
+#[test]
+fn test_redoubt_option_map_zeroizes_source() {
+    // Its Drop does not zeroize: it only records, through the sentinel,
+    // whether the value was already wiped when it started dropping.
+    #[derive(Default)]
+    struct WipeProbe {
+        value: u8,
+        wiped_before_drop: Rc<Cell<bool>>,
+        __sentinel: ZeroizeOnDropSentinel,
+    }
+
+    impl ZeroizeMetadata for WipeProbe {
+        const CAN_BE_BULK_ZEROIZED: bool = false;
+    }
+
+    impl FastZeroizable for WipeProbe {
+        fn fast_zeroize(&mut self) {
+            self.value = 0;
+            self.__sentinel.fast_zeroize();
+        }
+    }
+
+    impl ZeroizationProbe for WipeProbe {
+        fn is_zeroized(&self) -> bool {
+            self.value == 0
+        }
+    }
+
+    impl Drop for WipeProbe {
+        fn drop(&mut self) {
+            self.wiped_before_drop.set(self.__sentinel.is_zeroized());
+        }
+    }
+
+    let wiped_before_drop = Rc::new(Cell::new(false));
+    let mut opt = RedoubtOption::<WipeProbe>::default();
+    let mut probe = WipeProbe {
+        value: 0xAB,
+        wiped_before_drop: Rc::clone(&wiped_before_drop),
+        __sentinel: ZeroizeOnDropSentinel::default(),
+    };
+    opt.replace(&mut probe);
+
+    let mut sentinel = None;
+    let mapped = opt.map(|probe| {
+        sentinel = Some(probe.__sentinel.clone());
+        probe.value
+    });
+
+    assert_eq!(*mapped.as_ref().expect("Failed to get as_ref"), 0xAB);
+    assert!(opt.is_none());
+
+    let sentinel = sentinel.expect("Failed to capture sentinel");
+    assert!(sentinel.has_been_dropped());
+    assert!(wiped_before_drop.get());
+}
+
+#[test]
+fn test_redoubt_option_get_or_insert_with_none() {
+    let mut opt = RedoubtOption::<u64>::default();
+
+    let value = opt.get_or_insert_with(|| 42);
+    assert_eq!(*value, 42);
+
+    *value = 99;
+    assert_eq!(*opt.as_ref().expect("Failed to get as_ref"), 99);
+}
+
+#[test]
+fn test_redoubt_option_get_or_insert_with_some_skips_closure() {
+    let mut opt = RedoubtOption::<u64>::default();
+    let mut value = 42u64;
+    opt.replace(&mut value);
+
+    let mut called = false;
+    let value = opt.get_or_insert_with(|| {
+        called = true;
+        99
+    });
+
+    assert_eq!(*value, 42);
+    assert!(!called);
+}
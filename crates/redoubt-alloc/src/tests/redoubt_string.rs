@@ -4,6 +4,7 @@
 
 use crate::RedoubtString;
 use alloc::string::String;
+use redoubt_util::is_spare_capacity_zeroized;
 use redoubt_zero::ZeroizationProbe;
 
 // =============================================================================
@@ -236,6 +237,83 @@ fn test_extend_from_str_single_allocation() {
     assert!(s.capacity() >= 128);
 }
 
+// =============================================================================
+// replace_range()
+// =============================================================================
+
+fn is_redoubt_string_spare_capacity_zeroized(s: &mut RedoubtString) -> bool {
+    is_spare_capacity_zeroized(unsafe { s.as_mut_string().as_mut_vec() })
+}
+
+#[test]
+fn test_replace_range_same_length() {
+    let mut s = RedoubtString::from_str("hello world");
+
+    s.replace_range(6..11, "rusty");
+
+    assert_eq!(s.as_str(), "hello rusty");
+}
+
+#[test]
+fn test_replace_range_empty_replacement_wipes() {
+    let mut s = RedoubtString::from_str("user:secret");
+    assert_eq!(s.capacity(), 16);
+
+    s.replace_range(4..11, "");
+
+    assert_eq!(s.as_str(), "user");
+    assert_eq!(s.capacity(), 16);
+    // Stale tail bytes ("secret" shifted out) must not survive in spare capacity
+    assert!(is_redoubt_string_spare_capacity_zeroized(&mut s));
+}
+
+#[test]
+fn test_replace_range_shorter_replacement_wipes_tail() {
+    let mut s = RedoubtString::from_str("key=0123456789;end");
+
+    s.replace_range(4..14, "xx");
+
+    assert_eq!(s.as_str(), "key=xx;end");
+    assert!(is_redoubt_string_spare_capacity_zeroized(&mut s));
+}
+
+#[test]
+fn test_replace_range_longer_replacement_grows() {
+    let mut s = RedoubtString::from_str("ab");
+    assert_eq!(s.capacity(), 2);
+
+    s.replace_range(1..2, "0123456789");
+
+    assert_eq!(s.as_str(), "a0123456789");
+    assert_eq!(s.capacity(), 16);
+}
+
+#[test]
+fn test_replace_range_multibyte_boundaries() {
+    let mut s = RedoubtString::from_str("añb");
+
+    // 'ñ' occupies bytes 1..3
+    s.replace_range(1..3, "n");
+
+    assert_eq!(s.as_str(), "anb");
+}
+
+#[test]
+#[should_panic(expected = "char boundary")]
+fn test_replace_range_not_char_boundary_panics() {
+    let mut s = RedoubtString::from_str("añb");
+
+    s.replace_range(1..2, "n");
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_replace_range_out_of_bounds_panics() {
+    let mut s = RedoubtString::from_str("abc");
+
+    s.replace_range(1..4, "x");
+}
+
 // =============================================================================
 // clear()
 // =============================================================================
@@ -251,6 +329,33 @@ fn test_clear() {
     assert!(s.is_empty());
 }
 
+// =============================================================================
+// ct_eq_str()
+// =============================================================================
+
+#[test]
+fn test_ct_eq_str_equal() {
+    let s = RedoubtString::from_str("correct horse");
+
+    assert!(s.ct_eq_str("correct horse"));
+}
+
+#[test]
+fn test_ct_eq_str_length_mismatch() {
+    let s = RedoubtString::from_str("correct horse");
+
+    assert!(!s.ct_eq_str("correct horse battery"));
+    assert!(!s.ct_eq_str(""));
+}
+
+#[test]
+fn test_ct_eq_str_same_length_different() {
+    let s = RedoubtString::from_str("correct horse");
+
+    assert!(!s.ct_eq_str("correct house"));
+    assert!(!s.ct_eq_str("Correct horse"));
+}
+
 // =============================================================================
 // as_str()
 // =============================================================================
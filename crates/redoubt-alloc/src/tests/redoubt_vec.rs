@@ -2,7 +2,8 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // See LICENSE in the repository root for full license text.
 
-use crate::RedoubtVec;
+use crate::{AllockedVecError, RedoubtVec};
+use redoubt_util::is_spare_capacity_zeroized;
 use redoubt_zero::ZeroizationProbe;
 
 // =============================================================================
@@ -222,6 +223,54 @@ fn test_maybe_grow_to_single_allocation() {
     }
 }
 
+// =============================================================================
+// try_extend_from_slice()
+// =============================================================================
+
+#[test]
+fn test_try_extend_from_slice_exact_fit() {
+    let mut vec = RedoubtVec::<u8>::with_capacity(4);
+    let capacity = vec.capacity();
+
+    vec.try_extend_from_slice(&[1, 2])
+        .expect("Failed to try_extend_from_slice(..)");
+    vec.try_extend_from_slice(&[3, 4])
+        .expect("Failed to try_extend_from_slice(..)");
+
+    assert_eq!(vec.as_slice(), &[1, 2, 3, 4]);
+    assert_eq!(vec.capacity(), capacity);
+}
+
+#[test]
+fn test_try_extend_from_slice_one_over_fails() {
+    let mut vec = RedoubtVec::<u8>::with_capacity(4);
+    let capacity = vec.capacity();
+
+    vec.try_extend_from_slice(&[1, 2])
+        .expect("Failed to try_extend_from_slice(..)");
+
+    let result = vec.try_extend_from_slice(&[3, 4, 5]);
+
+    assert!(matches!(result, Err(AllockedVecError::CapacityExceeded)));
+    assert_eq!(vec.len(), 2);
+    assert_eq!(vec.as_slice(), &[1, 2]);
+    assert_eq!(vec.capacity(), capacity);
+}
+
+#[test]
+fn test_try_extend_from_slice_empty() {
+    let mut vec = RedoubtVec::<u8>::new();
+
+    vec.try_extend_from_slice(&[])
+        .expect("Failed to try_extend_from_slice(..)");
+
+    assert!(vec.is_empty());
+    assert!(matches!(
+        vec.try_extend_from_slice(&[1]),
+        Err(AllockedVecError::CapacityExceeded)
+    ));
+}
+
 // =============================================================================
 // replace_from_mut_slice()
 // =============================================================================
@@ -295,6 +344,190 @@ fn test_clear() {
     assert!(vec.is_empty());
 }
 
+// =============================================================================
+// drain_zeroizing()
+// =============================================================================
+
+#[test]
+fn test_drain_zeroizing_middle() {
+    let mut data = [1u8, 2, 3, 4, 5, 6, 7, 8];
+    let mut vec = RedoubtVec::from_mut_slice(&mut data);
+    assert_eq!(vec.capacity(), 8);
+
+    vec.drain_zeroizing(2..5);
+
+    assert_eq!(vec.as_slice(), [1, 2, 6, 7, 8]);
+    assert_eq!(vec.capacity(), 8);
+    // Vacated region [5..8] held stale copies of the tail
+    assert!(is_spare_capacity_zeroized(vec.as_vec()));
+}
+
+#[test]
+fn test_drain_zeroizing_all() {
+    let mut data = [0xAAu8; 16];
+    let mut vec = RedoubtVec::from_mut_slice(&mut data);
+
+    vec.drain_zeroizing(0..16);
+
+    assert!(vec.is_empty());
+    assert!(is_spare_capacity_zeroized(vec.as_vec()));
+}
+
+#[test]
+fn test_drain_zeroizing_empty_range() {
+    let mut data = [1u8, 2, 3, 4];
+    let mut vec = RedoubtVec::from_mut_slice(&mut data);
+
+    vec.drain_zeroizing(2..2);
+
+    assert_eq!(vec.as_slice(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn test_drain_zeroizing_complex_elements() {
+    let mut vec: RedoubtVec<RedoubtVec<u8>> = RedoubtVec::with_capacity(4);
+    for i in 1u8..=4 {
+        let mut inner = RedoubtVec::from_mut_slice(&mut [i; 4]);
+        vec.drain_value(&mut inner);
+    }
+
+    vec.drain_zeroizing(1..3);
+
+    assert_eq!(vec.len(), 2);
+    assert_eq!(vec[0].as_slice(), [1u8; 4]);
+    assert_eq!(vec[1].as_slice(), [4u8; 4]);
+    assert!(is_spare_capacity_zeroized(vec.as_vec()));
+}
+
+#[test]
+#[should_panic]
+fn test_drain_zeroizing_out_of_bounds_panics() {
+    let mut data = [1u8, 2, 3];
+    let mut vec = RedoubtVec::from_mut_slice(&mut data);
+
+    vec.drain_zeroizing(1..4);
+}
+
+// =============================================================================
+// split_off()
+// =============================================================================
+
+#[test]
+fn test_split_off_middle() {
+    let mut data = [1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    let mut vec = RedoubtVec::from_mut_slice(&mut data);
+    let capacity = vec.capacity();
+
+    let tail = vec.split_off(4);
+
+    assert_eq!(vec.as_slice(), [1, 2, 3, 4]);
+    assert_eq!(vec.capacity(), capacity);
+    assert_eq!(tail.as_slice(), [5, 6, 7, 8, 9, 10]);
+    assert_ne!(tail.as_ptr(), vec.as_ptr());
+    // Vacated region [4..10] held the tail that was moved out
+    assert!(is_spare_capacity_zeroized(vec.as_vec()));
+}
+
+#[test]
+fn test_split_off_at_len_returns_empty() {
+    let mut data = [1u8, 2, 3];
+    let mut vec = RedoubtVec::from_mut_slice(&mut data);
+
+    let tail = vec.split_off(3);
+
+    assert!(tail.is_empty());
+    assert_eq!(vec.as_slice(), [1, 2, 3]);
+}
+
+#[test]
+fn test_split_off_complex_elements() {
+    let mut vec: RedoubtVec<RedoubtVec<u8>> = RedoubtVec::with_capacity(4);
+    for i in 1u8..=4 {
+        let mut inner = RedoubtVec::from_mut_slice(&mut [i; 4]);
+        vec.drain_value(&mut inner);
+    }
+
+    let tail = vec.split_off(1);
+
+    assert_eq!(vec.len(), 1);
+    assert_eq!(vec[0].as_slice(), [1u8; 4]);
+    assert_eq!(tail.len(), 3);
+    assert_eq!(tail[2].as_slice(), [4u8; 4]);
+    assert!(is_spare_capacity_zeroized(vec.as_vec()));
+}
+
+#[test]
+#[should_panic]
+fn test_split_off_out_of_bounds_panics() {
+    let mut data = [1u8, 2, 3];
+    let mut vec = RedoubtVec::from_mut_slice(&mut data);
+
+    let _ = vec.split_off(4);
+}
+
+// =============================================================================
+// binary_search_ct()
+// =============================================================================
+
+#[test]
+fn test_binary_search_ct_found() {
+    let mut keys = [[0x01u8, 0x00], [0x01, 0x7F], [0x02, 0x00], [0xFE, 0xFF]];
+    let vec = RedoubtVec::from_mut_slice(&mut keys);
+
+    assert_eq!(vec.binary_search_ct(&[0x01, 0x00]), Some(0));
+    assert_eq!(vec.binary_search_ct(&[0x01, 0x7F]), Some(1));
+    assert_eq!(vec.binary_search_ct(&[0x02, 0x00]), Some(2));
+    assert_eq!(vec.binary_search_ct(&[0xFE, 0xFF]), Some(3));
+}
+
+#[test]
+fn test_binary_search_ct_not_found() {
+    let mut keys = [[0x01u8, 0x00], [0x02, 0x00], [0x03, 0x00]];
+    let vec = RedoubtVec::from_mut_slice(&mut keys);
+
+    assert_eq!(vec.binary_search_ct(&[0x00, 0x00]), None);
+    assert_eq!(vec.binary_search_ct(&[0x02, 0x01]), None);
+    assert_eq!(vec.binary_search_ct(&[0xFF, 0xFF]), None);
+    // Length mismatch never matches
+    assert_eq!(vec.binary_search_ct(&[0x02]), None);
+}
+
+#[test]
+fn test_binary_search_ct_empty() {
+    let vec: RedoubtVec<[u8; 4]> = RedoubtVec::new();
+
+    assert_eq!(vec.binary_search_ct(&[0u8; 4]), None);
+}
+
+// =============================================================================
+// ct_contains()
+// =============================================================================
+
+#[test]
+fn test_ct_contains_matches_naive_search() {
+    let inputs: [&[u8]; 6] = [
+        &[],
+        &[0x00],
+        &[0xFF],
+        &[0x01, 0x02, 0x03, 0x04],
+        &[0x00, 0x80, 0xFF, 0x7F, 0x01],
+        b"correct horse battery staple",
+    ];
+
+    for input in inputs {
+        let mut data = input.to_vec();
+        let vec = RedoubtVec::from_mut_slice(&mut data);
+
+        for needle in 0..=u8::MAX {
+            assert_eq!(
+                vec.ct_contains(needle),
+                input.contains(&needle),
+                "needle {needle:#04x} in {input:?}"
+            );
+        }
+    }
+}
+
 // =============================================================================
 // as_slice()
 // =============================================================================
@@ -0,0 +1,51 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use crate::{AllockedVec, RedoubtArray, RedoubtOption, RedoubtString, RedoubtVec};
+
+/// Fails to compile if `$ty` implements `$trait`: with an impl for both
+/// `()` and `Invalid`, inferring `_` becomes ambiguous.
+#[cfg(feature = "single-thread")]
+macro_rules! assert_not_impl {
+    ($ty:ty: $trait:path) => {{
+        trait AmbiguousIfImpl<A> {
+            fn some_item() {}
+        }
+
+        impl<T: ?Sized> AmbiguousIfImpl<()> for T {}
+
+        struct Invalid;
+
+        impl<T: ?Sized + $trait> AmbiguousIfImpl<Invalid> for T {}
+
+        let _ = <$ty as AmbiguousIfImpl<_>>::some_item;
+    }};
+}
+
+#[cfg(not(feature = "single-thread"))]
+#[test]
+fn test_containers_are_send_sync_without_single_thread() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<AllockedVec<u8>>();
+    assert_send_sync::<RedoubtArray<u8, 32>>();
+    assert_send_sync::<RedoubtOption<u64>>();
+    assert_send_sync::<RedoubtString>();
+    assert_send_sync::<RedoubtVec<u8>>();
+}
+
+#[cfg(feature = "single-thread")]
+#[test]
+fn test_containers_are_not_send_sync_with_single_thread() {
+    assert_not_impl!(AllockedVec<u8>: Send);
+    assert_not_impl!(AllockedVec<u8>: Sync);
+    assert_not_impl!(RedoubtArray<u8, 32>: Send);
+    assert_not_impl!(RedoubtArray<u8, 32>: Sync);
+    assert_not_impl!(RedoubtOption<u64>: Send);
+    assert_not_impl!(RedoubtOption<u64>: Sync);
+    assert_not_impl!(RedoubtString: Send);
+    assert_not_impl!(RedoubtString: Sync);
+    assert_not_impl!(RedoubtVec<u8>: Send);
+    assert_not_impl!(RedoubtVec<u8>: Sync);
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // See LICENSE in the repository root for full license text.
 
-use redoubt_util::is_vec_fully_zeroized;
+use redoubt_util::{is_spare_capacity_zeroized, is_vec_fully_zeroized};
 use redoubt_zero::{AssertZeroizeOnDrop, ZeroizationProbe};
 
 use crate::allocked_vec::{AllockedVec, AllockedVecBehaviour};
@@ -128,6 +128,91 @@ fn test_allocked_vec_push_exceeds_capacity() {
     assert!(!vec.is_zeroized());
 }
 
+// =============================================================================
+// try_insert()
+// =============================================================================
+
+#[test]
+fn test_allocked_vec_try_insert_shifts_elements() {
+    let mut vec = AllockedVec::with_capacity(4);
+    vec.push(1u8).expect("Failed to vec.push(1)");
+    vec.push(3u8).expect("Failed to vec.push(3)");
+
+    vec.try_insert(1, 2u8)
+        .expect("Failed to vec.try_insert(1, 2)");
+    vec.try_insert(3, 4u8)
+        .expect("Failed to vec.try_insert(3, 4)");
+
+    assert_eq!(vec.as_slice(), &[1, 2, 3, 4]);
+    assert_eq!(vec.capacity(), 4);
+}
+
+#[test]
+fn test_allocked_vec_try_insert_full_fails() {
+    let mut vec = AllockedVec::with_capacity(2);
+    vec.push(1u8).expect("Failed to vec.push(1)");
+    vec.push(2u8).expect("Failed to vec.push(2)");
+
+    let result = vec.try_insert(0, 0u8);
+
+    assert!(matches!(result, Err(AllockedVecError::CapacityExceeded)));
+    assert_eq!(vec.as_slice(), &[1, 2]);
+    assert_eq!(vec.capacity(), 2);
+}
+
+#[test]
+fn test_allocked_vec_try_insert_out_of_bounds_fails() {
+    let mut vec = AllockedVec::with_capacity(4);
+    vec.push(1u8).expect("Failed to vec.push(1)");
+
+    let result = vec.try_insert(2, 0u8);
+
+    assert!(matches!(result, Err(AllockedVecError::IndexOutOfBounds)));
+    assert_eq!(vec.as_slice(), &[1]);
+}
+
+// =============================================================================
+// remove()
+// =============================================================================
+
+#[test]
+fn test_allocked_vec_remove_middle_wipes_tail_slot() {
+    let mut vec = AllockedVec::with_capacity(4);
+    vec.drain_from(&mut [0xA1u8, 0xB2, 0xC3, 0xD4])
+        .expect("Failed to vec.drain_from(..)");
+
+    assert_eq!(vec.remove(1), Some(0xB2));
+    assert_eq!(vec.as_slice(), &[0xA1, 0xC3, 0xD4]);
+
+    // Shifted-out copy of 0xD4 must not remain at index 3
+    vec.__unsafe_expose_inner_for_tests(|inner| {
+        assert!(is_spare_capacity_zeroized(inner));
+    });
+}
+
+#[test]
+fn test_allocked_vec_remove_last() {
+    let mut vec = AllockedVec::with_capacity(2);
+    vec.push(7u64).expect("Failed to vec.push(7)");
+    vec.push(9u64).expect("Failed to vec.push(9)");
+
+    assert_eq!(vec.remove(1), Some(9));
+    assert_eq!(vec.as_slice(), &[7]);
+
+    vec.__unsafe_expose_inner_for_tests(|inner| {
+        assert!(is_spare_capacity_zeroized(inner));
+    });
+}
+
+#[test]
+fn test_allocked_vec_remove_out_of_bounds_returns_none() {
+    let mut vec = AllockedVec::with_capacity(2);
+    vec.push(1u8).expect("Failed to vec.push(1)");
+
+    assert_eq!(vec.remove(1), None);
+    assert_eq!(vec.as_slice(), &[1]);
+}
+
 // =============================================================================
 // len(), capacity(), is_empty()
 // =============================================================================
@@ -161,7 +246,52 @@ fn test_allocked_vec_as_slice_and_as_mut_slice() {
 // as_mut_slice()
 // =============================================================================
 
-// Tested by test_allocked_vec_as_slice_and_as_mut_slice
+#[test]
+fn test_allocked_vec_as_mut_slice_len_not_capacity() {
+    let mut vec = AllockedVec::with_capacity(5);
+
+    vec.push(1u8).expect("Failed to vec.push(1)");
+    vec.push(2u8).expect("Failed to vec.push(2)");
+
+    let slice = vec.as_mut_slice();
+    assert_eq!(slice.len(), 2);
+
+    assert_eq!(vec.len(), 2);
+    assert_eq!(vec.capacity(), 5);
+}
+
+// =============================================================================
+// iter_mut()
+// =============================================================================
+
+#[test]
+fn test_allocked_vec_iter_mut() {
+    let mut vec = AllockedVec::with_capacity(5);
+
+    vec.push(1u8).expect("Failed to vec.push(1)");
+    vec.push(2u8).expect("Failed to vec.push(2)");
+    vec.push(3u8).expect("Failed to vec.push(3)");
+
+    assert_eq!(vec.iter_mut().count(), 3);
+
+    for x in vec.iter_mut() {
+        *x *= 10;
+    }
+
+    assert_eq!(vec.as_slice(), &[10, 20, 30]);
+    assert_eq!(vec.len(), 3);
+    assert_eq!(vec.capacity(), 5);
+
+    // Spare capacity is untouched.
+    vec.__unsafe_expose_inner_for_tests(|inner| assert!(is_spare_capacity_zeroized(inner)));
+}
+
+#[test]
+fn test_allocked_vec_iter_mut_empty() {
+    let mut vec = AllockedVec::<u8>::with_capacity(5);
+
+    assert_eq!(vec.iter_mut().count(), 0);
+}
 
 // =============================================================================
 // truncate()
@@ -194,6 +324,33 @@ fn test_allocked_vec_truncate_zeroizes_removed_elements() {
     assert!(!vec.is_zeroized());
 }
 
+// =============================================================================
+// clear_and_zeroize()
+// =============================================================================
+
+#[test]
+fn test_allocked_vec_clear_and_zeroize() {
+    let mut vec = AllockedVec::with_capacity(5);
+
+    vec.push(1u8).expect("Failed to push");
+    vec.push(2u8).expect("Failed to push");
+    vec.push(3u8).expect("Failed to push");
+
+    vec.clear_and_zeroize();
+
+    assert!(vec.is_empty());
+    assert_eq!(vec.capacity(), 5);
+    vec.__unsafe_expose_inner_for_tests(|inner| {
+        assert!(is_vec_fully_zeroized(inner));
+    });
+
+    // Still sealed and reusable
+    for i in 0..5u8 {
+        vec.push(i).expect("Failed to push");
+    }
+    assert!(vec.push(5u8).is_err());
+}
+
 // =============================================================================
 // drain_from()
 // =============================================================================
@@ -0,0 +1,132 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use core::alloc::{GlobalAlloc, Layout};
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::alloc::System;
+
+use crate::ZeroizingAllocator;
+
+/// Inner allocator that records whether each freed block was zeroized.
+#[derive(Default)]
+struct ProbeAllocator {
+    zeroized_deallocs: AtomicUsize,
+    dirty_deallocs: AtomicUsize,
+}
+
+unsafe impl GlobalAlloc for ProbeAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let block = unsafe { core::slice::from_raw_parts(ptr, layout.size()) };
+
+        if block.iter().all(|b| *b == 0) {
+            self.zeroized_deallocs.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.dirty_deallocs.fetch_add(1, Ordering::Relaxed);
+        }
+
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+fn fill(ptr: *mut u8, len: usize, byte: u8) {
+    unsafe { core::ptr::write_bytes(ptr, byte, len) };
+}
+
+// =============================================================================
+// dealloc()
+// =============================================================================
+
+#[test]
+fn test_dealloc_zeroizes_block() {
+    let allocator = ZeroizingAllocator::new(ProbeAllocator::default());
+    let probe = allocator.__unsafe_inner_for_tests();
+    let layout = Layout::from_size_align(64, 8).expect("Failed to Layout::from_size_align(..)");
+
+    let ptr = unsafe { allocator.alloc(layout) };
+    assert!(!ptr.is_null());
+    fill(ptr, 64, 0xAA);
+
+    unsafe { allocator.dealloc(ptr, layout) };
+
+    assert_eq!(probe.zeroized_deallocs.load(Ordering::Relaxed), 1);
+    assert_eq!(probe.dirty_deallocs.load(Ordering::Relaxed), 0);
+}
+
+// =============================================================================
+// alloc_zeroed()
+// =============================================================================
+
+#[test]
+fn test_alloc_zeroed() {
+    let allocator = ZeroizingAllocator::new(ProbeAllocator::default());
+    let layout = Layout::from_size_align(32, 8).expect("Failed to Layout::from_size_align(..)");
+
+    let ptr = unsafe { allocator.alloc_zeroed(layout) };
+    assert!(!ptr.is_null());
+
+    let block = unsafe { core::slice::from_raw_parts(ptr, 32) };
+    assert!(block.iter().all(|b| *b == 0));
+
+    unsafe { allocator.dealloc(ptr, layout) };
+}
+
+// =============================================================================
+// realloc()
+// =============================================================================
+
+#[test]
+fn test_realloc_grow_preserves_data_and_zeroizes_old_block() {
+    let allocator = ZeroizingAllocator::new(ProbeAllocator::default());
+    let probe = allocator.__unsafe_inner_for_tests();
+    let layout = Layout::from_size_align(16, 8).expect("Failed to Layout::from_size_align(..)");
+
+    let ptr = unsafe { allocator.alloc(layout) };
+    assert!(!ptr.is_null());
+    fill(ptr, 16, 0xBB);
+
+    let new_ptr = unsafe { allocator.realloc(ptr, layout, 64) };
+    assert!(!new_ptr.is_null());
+
+    let preserved = unsafe { core::slice::from_raw_parts(new_ptr, 16) };
+    assert!(preserved.iter().all(|b| *b == 0xBB));
+
+    // Old block was zeroized before being freed.
+    assert_eq!(probe.zeroized_deallocs.load(Ordering::Relaxed), 1);
+    assert_eq!(probe.dirty_deallocs.load(Ordering::Relaxed), 0);
+
+    let new_layout = Layout::from_size_align(64, 8).expect("Failed to Layout::from_size_align(..)");
+    unsafe { allocator.dealloc(new_ptr, new_layout) };
+
+    assert_eq!(probe.zeroized_deallocs.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn test_realloc_shrink_preserves_prefix() {
+    let allocator = ZeroizingAllocator::new(ProbeAllocator::default());
+    let probe = allocator.__unsafe_inner_for_tests();
+    let layout = Layout::from_size_align(64, 8).expect("Failed to Layout::from_size_align(..)");
+
+    let ptr = unsafe { allocator.alloc(layout) };
+    assert!(!ptr.is_null());
+    fill(ptr, 64, 0xCC);
+
+    let new_ptr = unsafe { allocator.realloc(ptr, layout, 8) };
+    assert!(!new_ptr.is_null());
+
+    let preserved = unsafe { core::slice::from_raw_parts(new_ptr, 8) };
+    assert!(preserved.iter().all(|b| *b == 0xCC));
+
+    // Old block (including the dropped tail) was zeroized before being freed.
+    assert_eq!(probe.zeroized_deallocs.load(Ordering::Relaxed), 1);
+    assert_eq!(probe.dirty_deallocs.load(Ordering::Relaxed), 0);
+
+    let new_layout = Layout::from_size_align(8, 8).expect("Failed to Layout::from_size_align(..)");
+    unsafe { allocator.dealloc(new_ptr, new_layout) };
+
+    assert_eq!(probe.zeroized_deallocs.load(Ordering::Relaxed), 2);
+}
@@ -0,0 +1,19 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! Thread-locality marker for secret containers.
+
+#[cfg(feature = "single-thread")]
+type Marker = *const ();
+
+#[cfg(not(feature = "single-thread"))]
+type Marker = ();
+
+/// Zero-sized marker embedded in every secret container.
+///
+/// With the `single-thread` feature enabled this is `PhantomData<*const ()>`,
+/// which makes the containing type `!Send + !Sync`: a secret decrypted on one
+/// thread can then never be moved to or shared with another one. Without the
+/// feature it is `PhantomData<()>` and has no effect on auto traits.
+pub type ThreadMarker = core::marker::PhantomData<Marker>;
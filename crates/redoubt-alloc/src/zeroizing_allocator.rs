@@ -0,0 +1,101 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! Global allocator wrapper that zeroizes every block before freeing it.
+
+use core::alloc::{GlobalAlloc, Layout};
+
+/// A [`GlobalAlloc`] wrapper that zeroizes blocks on `dealloc`.
+///
+/// Every allocation released through this wrapper is overwritten with zeros
+/// before being handed back to the inner allocator, so no freed block retains
+/// secrets, including data that reached the heap through untyped paths the
+/// per-container zeroization never sees.
+///
+/// # Performance
+///
+/// This is a blunt instrument:
+///
+/// - Every `dealloc` costs a memset proportional to the block size.
+/// - `realloc` never resizes in place: it allocates a new block, copies, and
+///   zeroizes + frees the old one, so growth is always O(n).
+///
+/// Prefer the typed containers ([`RedoubtVec`](crate::RedoubtVec),
+/// [`RedoubtString`](crate::RedoubtString), ...) where possible and reserve
+/// this for targets where every secret cannot be tracked individually.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use redoubt_alloc::ZeroizingAllocator;
+/// use std::alloc::System;
+///
+/// #[global_allocator]
+/// static GLOBAL: ZeroizingAllocator<System> = ZeroizingAllocator::new(System);
+/// ```
+pub struct ZeroizingAllocator<A> {
+    inner: A,
+}
+
+impl<A> ZeroizingAllocator<A> {
+    /// Wraps `inner`, zeroizing every block it frees.
+    pub const fn new(inner: A) -> Self {
+        Self { inner }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn __unsafe_inner_for_tests(&self) -> &A {
+        &self.inner
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for ZeroizingAllocator<A> {
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: Caller upholds the `GlobalAlloc::alloc` contract
+        unsafe { self.inner.alloc(layout) }
+    }
+
+    #[inline]
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: Caller upholds the `GlobalAlloc::alloc_zeroed` contract
+        unsafe { self.inner.alloc_zeroed(layout) }
+    }
+
+    #[inline]
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: `ptr` was allocated with `layout`, so `layout.size()` bytes are writable
+        unsafe {
+            core::ptr::write_bytes(ptr, 0, layout.size());
+            // Volatile read prevents the optimizer from removing the write_bytes
+            if layout.size() > 0 {
+                core::ptr::read_volatile(ptr);
+            }
+            self.inner.dealloc(ptr, layout);
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // Never delegate to the inner realloc: it may move the block and free
+        // the old one without zeroizing it.
+        // SAFETY: Caller guarantees `new_size`, rounded up to `layout.align()`, does not overflow
+        let new_layout = unsafe { Layout::from_size_align_unchecked(new_size, layout.align()) };
+        // SAFETY: `new_layout` has non-zero size per the `realloc` contract
+        let new_ptr = unsafe { self.inner.alloc(new_layout) };
+
+        if !new_ptr.is_null() {
+            // SAFETY: Both blocks are valid for `min(old, new)` bytes and do not overlap
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    ptr,
+                    new_ptr,
+                    core::cmp::min(layout.size(), new_size),
+                );
+                self.dealloc(ptr, layout);
+            }
+        }
+
+        new_ptr
+    }
+}
@@ -24,6 +24,9 @@ pub enum PageError {
 
     #[error("madvise(MADV_DONTDUMP) failed")]
     Madvise = 4,
+
+    #[error("mprotect(PROT_NONE) on guard pages failed")]
+    Guard = 5,
 }
 
 /// Errors that can occur when working with buffers.
@@ -41,6 +44,15 @@ pub enum BufferError {
     #[error("callback error: {0:?}")]
     CallbackError(Box<dyn core::fmt::Debug + Send + Sync + 'static>),
 
+    /// Source and destination buffers have different lengths.
+    #[error("buffer size mismatch: source is {src} bytes, destination is {dst} bytes")]
+    SizeMismatch {
+        /// Length of the source buffer.
+        src: usize,
+        /// Length of the destination buffer.
+        dst: usize,
+    },
+
     /// A mutex was poisoned.
     #[error("mutex poisoned")]
     MutexPoisoned,
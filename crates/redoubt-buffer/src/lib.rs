@@ -17,14 +17,14 @@
 //! - No platform-specific protections
 //! - Available on all platforms
 //!
-//! ## PageBuffer (Unix only)
+//! ## PageBuffer (Unix and Windows)
 //!
 //! Platform-specific buffer with memory protection:
-//! - Uses `mmap` for allocation
-//! - Optional `mlock` to prevent swapping to disk
-//! - Optional `mprotect` to make pages read-only when not in use
+//! - Uses `mmap` (`VirtualAlloc` on Windows) for allocation
+//! - `mlock` (`VirtualLock`) to prevent swapping to disk
+//! - Optional `mprotect` (`VirtualProtect`) to make pages inaccessible when not in use
 //! - Automatic zeroization on drop
-//! - Only available on Unix platforms
+//! - Only available on Unix and Windows platforms
 //!
 //! # Protection Strategies
 //!
@@ -62,7 +62,7 @@
 //! # Example: PageBuffer with Protection
 //!
 //! ```rust
-//! #[cfg(unix)]
+//! #[cfg(any(unix, windows))]
 //! fn example() -> Result<(), redoubt_buffer::BufferError> {
 //!     use redoubt_buffer::{Buffer, PageBuffer, ProtectionStrategy};
 //!
@@ -83,7 +83,7 @@
 //!     // Page is automatically unprotected, zeroized, and freed on drop
 //!     Ok(())
 //! }
-//! # #[cfg(unix)]
+//! # #[cfg(any(unix, windows))]
 //! # example().unwrap();
 //! ```
 //!
@@ -99,17 +99,17 @@ extern crate alloc;
 #[cfg(test)]
 mod tests;
 
-#[cfg(unix)]
+#[cfg(any(unix, windows))]
 mod page_buffer;
 
-#[cfg(unix)]
+#[cfg(any(unix, windows))]
 mod page;
 
 mod error;
 mod portable_buffer;
 mod traits;
 
-#[cfg(unix)]
+#[cfg(any(unix, windows))]
 pub use page_buffer::{PageBuffer, ProtectionStrategy};
 
 pub use error::BufferError;
@@ -4,9 +4,11 @@
 
 //! Page - Low-level memory page with protection primitives.
 //!
-//! Wraps a single mmap'd page with mlock/mprotect operations.
+//! Wraps mmap'd pages with mlock/mprotect operations.
+//! On Windows the same primitives map to VirtualAlloc/VirtualLock/VirtualProtect.
 //! Each syscall is exposed separately for granular testing.
 
+#[cfg(unix)]
 use core::ptr;
 use core::sync::atomic::{AtomicBool, Ordering};
 
@@ -18,25 +20,99 @@ use super::error::PageError;
 ///
 /// Provides granular control over mmap/mlock/mprotect.
 /// Tracks protection state internally via AtomicBool.
+///
+/// A guarded page ([`Page::new_guarded`]) is mapped as
+/// `[guard | data | guard]`, where both guard pages are permanently
+/// inaccessible so any access just outside the data pages faults.
 #[derive(Debug)]
 pub struct Page {
     ptr: *mut u8,
     capacity: usize,
     is_protected: AtomicBool,
+    guard_len: usize,
 }
 
 unsafe impl Send for Page {}
 unsafe impl Sync for Page {}
 
 impl Page {
-    /// Allocates a new page via mmap. Does NOT lock or protect.
+    /// Returns the system page size.
+    #[cfg(unix)]
+    pub fn size() -> usize {
+        unsafe { libc::sysconf(libc::_SC_PAGESIZE) as usize }
+    }
+
+    /// Returns the system page size.
+    #[cfg(windows)]
+    pub fn size() -> usize {
+        use windows_sys::Win32::System::SystemInformation::{GetSystemInfo, SYSTEM_INFO};
+
+        let mut info = SYSTEM_INFO::default();
+        unsafe { GetSystemInfo(&mut info) };
+
+        info.dwPageSize as usize
+    }
+
+    /// Allocates a new page. Does NOT lock or protect.
+    #[cfg(test)]
     pub fn new() -> Result<Self, PageError> {
-        let capacity = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+        Self::with_capacity(1, false)
+    }
+
+    /// Allocates a data page between two inaccessible guard pages.
+    /// Does NOT lock or protect the data page.
+    #[cfg(test)]
+    pub fn new_guarded() -> Result<Self, PageError> {
+        Self::with_capacity(1, true)
+    }
+
+    /// Allocates enough whole pages to hold `min_len` bytes (at least one),
+    /// optionally between two inaccessible guard pages.
+    /// Does NOT lock or protect the data pages.
+    pub fn with_capacity(min_len: usize, guarded: bool) -> Result<Self, PageError> {
+        let page_size = Self::size();
+        let guard_len = if guarded { page_size } else { 0 };
+
+        let capacity = min_len
+            .max(1)
+            .div_ceil(page_size)
+            .checked_mul(page_size)
+            .ok_or(PageError::Create)?;
+        let total = capacity
+            .checked_add(2 * guard_len)
+            .ok_or(PageError::Create)?;
+
+        let base = Self::map(total)?;
+        let mut page = Self {
+            capacity,
+            ptr: unsafe { base.add(guard_len) },
+            is_protected: AtomicBool::new(false),
+            guard_len,
+        };
+
+        let guards_failed = guarded
+            && unsafe {
+                !Self::seal(base, guard_len)
+                    || !Self::seal(base.add(guard_len + capacity), guard_len)
+            };
+
+        if guards_failed {
+            page.munmap();
+            return Err(PageError::Guard);
+        }
 
+        unsafe { page.zeroize() };
+
+        Ok(page)
+    }
+
+    /// Maps `len` bytes of read/write memory via mmap.
+    #[cfg(unix)]
+    fn map(len: usize) -> Result<*mut u8, PageError> {
         let ptr = unsafe {
             libc::mmap(
                 ptr::null_mut(),
-                capacity,
+                len,
                 libc::PROT_READ | libc::PROT_WRITE,
                 libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
                 -1,
@@ -48,18 +124,76 @@ impl Page {
             return Err(PageError::Create);
         }
 
-        let mut page = Self {
-            capacity,
-            ptr: ptr as *mut u8,
-            is_protected: AtomicBool::new(false),
+        Ok(ptr as *mut u8)
+    }
+
+    /// Commits `len` bytes of read/write memory via VirtualAlloc.
+    #[cfg(windows)]
+    fn map(len: usize) -> Result<*mut u8, PageError> {
+        use windows_sys::Win32::System::Memory::{
+            MEM_COMMIT, MEM_RESERVE, PAGE_READWRITE, VirtualAlloc,
         };
 
-        unsafe { page.zeroize() };
+        let ptr = unsafe {
+            VirtualAlloc(
+                core::ptr::null(),
+                len,
+                MEM_COMMIT | MEM_RESERVE,
+                PAGE_READWRITE,
+            )
+        };
 
-        Ok(page)
+        if ptr.is_null() {
+            return Err(PageError::Create);
+        }
+
+        Ok(ptr as *mut u8)
+    }
+
+    /// Makes `len` bytes at `ptr` permanently inaccessible. Returns false on failure.
+    #[cfg(unix)]
+    unsafe fn seal(ptr: *mut u8, len: usize) -> bool {
+        unsafe { libc::mprotect(ptr as *mut _, len, libc::PROT_NONE) == 0 }
+    }
+
+    /// Makes `len` bytes at `ptr` permanently inaccessible. Returns false on failure.
+    #[cfg(windows)]
+    unsafe fn seal(ptr: *mut u8, len: usize) -> bool {
+        use windows_sys::Win32::System::Memory::{PAGE_NOACCESS, VirtualProtect};
+
+        let mut old = 0;
+
+        unsafe { VirtualProtect(ptr as *const _, len, PAGE_NOACCESS, &mut old) != 0 }
+    }
+
+    /// Returns true if the page is surrounded by guard pages.
+    #[cfg(test)]
+    pub fn is_guarded(&self) -> bool {
+        self.guard_len != 0
+    }
+
+    /// Returns true if the data pages are currently protected.
+    #[cfg(test)]
+    pub fn is_protected(&self) -> bool {
+        self.is_protected.load(Ordering::Acquire)
+    }
+
+    /// Returns the start of the data pages.
+    #[cfg(test)]
+    pub fn as_ptr(&self) -> *const u8 {
+        self.ptr
+    }
+
+    /// Returns the start and length of the whole mapping, guard pages included.
+    fn mapping(&self) -> (*mut u8, usize) {
+        (
+            unsafe { self.ptr.sub(self.guard_len) },
+            self.capacity + 2 * self.guard_len,
+        )
     }
 
     /// Locks page in RAM (prevents swapping to disk).
+    #[cfg(unix)]
     pub fn lock(&self) -> Result<(), PageError> {
         let failed = unsafe { libc::mlock(self.ptr as *const _, self.capacity) } != 0;
 
@@ -70,6 +204,20 @@ impl Page {
         Ok(())
     }
 
+    /// Locks page in the process working set (prevents paging to disk).
+    #[cfg(windows)]
+    pub fn lock(&self) -> Result<(), PageError> {
+        use windows_sys::Win32::System::Memory::VirtualLock;
+
+        let failed = unsafe { VirtualLock(self.ptr as *const _, self.capacity) } == 0;
+
+        if failed {
+            return Err(PageError::Lock);
+        }
+
+        Ok(())
+    }
+
     /// Marks page as non-dumpable (excludes from core dumps).
     #[cfg(target_os = "linux")]
     pub fn mark_dontdump(&self) -> Result<(), PageError> {
@@ -95,6 +243,7 @@ impl Page {
     }
 
     /// Sets page to PROT_NONE (no read/write access).
+    #[cfg(unix)]
     pub fn protect(&self) -> Result<(), PageError> {
         let failed =
             unsafe { libc::mprotect(self.ptr as *mut _, self.capacity, libc::PROT_NONE) } != 0;
@@ -109,6 +258,7 @@ impl Page {
     }
 
     /// Sets page to PROT_WRITE (allows write access).
+    #[cfg(unix)]
     pub fn unprotect(&self) -> Result<(), PageError> {
         let failed =
             unsafe { libc::mprotect(self.ptr as *mut _, self.capacity, libc::PROT_WRITE) } != 0;
@@ -122,6 +272,48 @@ impl Page {
         Ok(())
     }
 
+    /// Sets page to PAGE_NOACCESS (no read/write access).
+    #[cfg(windows)]
+    pub fn protect(&self) -> Result<(), PageError> {
+        use windows_sys::Win32::System::Memory::PAGE_NOACCESS;
+
+        if !self.virtual_protect(PAGE_NOACCESS) {
+            return Err(PageError::Protect);
+        }
+
+        self.is_protected.store(true, Ordering::Release);
+
+        Ok(())
+    }
+
+    /// Sets page to PAGE_READWRITE (allows write access).
+    #[cfg(windows)]
+    pub fn unprotect(&self) -> Result<(), PageError> {
+        use windows_sys::Win32::System::Memory::PAGE_READWRITE;
+
+        if !self.virtual_protect(PAGE_READWRITE) {
+            return Err(PageError::Unprotect);
+        }
+
+        self.is_protected.store(false, Ordering::Release);
+
+        Ok(())
+    }
+
+    /// Applies `protection` to the page. Returns false on failure.
+    #[cfg(windows)]
+    fn virtual_protect(
+        &self,
+        protection: windows_sys::Win32::System::Memory::PAGE_PROTECTION_FLAGS,
+    ) -> bool {
+        use windows_sys::Win32::System::Memory::VirtualProtect;
+
+        // VirtualProtect fails if the out-parameter for the old protection is null
+        let mut old = 0;
+
+        unsafe { VirtualProtect(self.ptr as *const _, self.capacity, protection, &mut old) != 0 }
+    }
+
     /// Returns a slice view of the page. Caller must ensure page is unprotected.
     ///
     /// # Safety
@@ -147,10 +339,19 @@ impl Page {
     }
 
     /// Unlocks page (allows swapping). Called in Drop.
+    #[cfg(unix)]
     pub fn munlock(&self) {
         unsafe { libc::munlock(self.ptr as *const _, self.capacity) };
     }
 
+    /// Unlocks page (allows paging). Called in Drop.
+    #[cfg(windows)]
+    pub fn munlock(&self) {
+        use windows_sys::Win32::System::Memory::VirtualUnlock;
+
+        unsafe { VirtualUnlock(self.ptr as *const _, self.capacity) };
+    }
+
     pub fn dispose(&mut self) {
         // Best effort: try to unprotect and zeroize before unmapping
         // If unprotect fails, page stays protected (safe)
@@ -167,8 +368,22 @@ impl Page {
         self.munmap();
     }
 
-    /// Unmaps the page. Called in Drop.
+    /// Unmaps the page, guard pages included. Called in Drop.
+    #[cfg(unix)]
     fn munmap(&self) {
-        unsafe { libc::munmap(self.ptr as *mut libc::c_void, self.capacity) };
+        let (base, len) = self.mapping();
+
+        unsafe { libc::munmap(base as *mut libc::c_void, len) };
+    }
+
+    /// Releases the page, guard pages included. Called in Drop.
+    #[cfg(windows)]
+    fn munmap(&self) {
+        use windows_sys::Win32::System::Memory::{MEM_RELEASE, VirtualFree};
+
+        let (base, _) = self.mapping();
+
+        // MEM_RELEASE requires a size of 0
+        unsafe { VirtualFree(base as *mut _, 0, MEM_RELEASE) };
     }
 }
@@ -18,6 +18,60 @@ pub enum ProtectionStrategy {
     MemProtected,
     /// mlock only (no mprotect toggling)
     MemNonProtected,
+    /// `MemProtected` plus inaccessible guard pages before and after the data
+    /// page, so writes running off either end of the page fault immediately.
+    ///
+    /// Guards sit at page boundaries: an overflow past `len` but still inside
+    /// the data page is not caught.
+    MemProtectedGuarded,
+}
+
+impl ProtectionStrategy {
+    fn toggles_protection(self) -> bool {
+        matches!(self, Self::MemProtected | Self::MemProtectedGuarded)
+    }
+}
+
+/// Keeps a page unprotected while alive.
+///
+/// Dropping the guard re-protects the page, so an early return or a panic in
+/// the caller's closure cannot leave it accessible. If that fails, the page is
+/// disposed and the process aborts.
+struct UnprotectGuard<'a> {
+    page: &'a mut Page,
+    armed: bool,
+}
+
+impl<'a> UnprotectGuard<'a> {
+    fn new(page: &'a mut Page, strategy: ProtectionStrategy) -> Result<Self, PageError> {
+        let armed = strategy.toggles_protection();
+
+        if armed {
+            page.unprotect()?;
+        }
+
+        Ok(Self { page, armed })
+    }
+
+    /// Re-protects the page, reporting failure to the caller instead of aborting.
+    fn reprotect(mut self) -> Result<(), PageError> {
+        if core::mem::replace(&mut self.armed, false) {
+            self.page.protect()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for UnprotectGuard<'_> {
+    fn drop(&mut self) {
+        if self.armed
+            && let Err(e) = self.page.protect()
+        {
+            self.page.dispose();
+            PageBuffer::abort(e);
+        }
+    }
 }
 
 /// A buffer backed by a memory-locked page with optional memory protection.
@@ -33,21 +87,30 @@ impl PageBuffer {
         #[cfg(test)]
         std::process::exit(error as i32);
 
-        #[cfg(not(test))]
+        #[cfg(all(not(test), unix))]
         {
             let _ = error;
             unsafe { libc::abort() }
         }
+
+        // TerminateProcess skips DLL detach notifications, like abort() skips atexit
+        #[cfg(all(not(test), windows))]
+        {
+            use windows_sys::Win32::System::Threading::{GetCurrentProcess, TerminateProcess};
+
+            unsafe { TerminateProcess(GetCurrentProcess(), error as u32) };
+            unreachable!("TerminateProcess on the current process does not return")
+        }
     }
 
     /// Creates a new PageBuffer with the specified protection strategy and length.
+    ///
+    /// The backing page is rounded up to whole pages, so `len` may exceed the
+    /// system page size.
     pub fn new(strategy: ProtectionStrategy, len: usize) -> Result<Self, PageError> {
-        let page = Page::new()?;
+        let page = Self::allocate(strategy, len)?;
 
-        page.lock()?;
-        page.mark_dontdump()?;
-
-        if strategy == ProtectionStrategy::MemProtected {
+        if strategy.toggles_protection() {
             page.protect()?;
         }
 
@@ -58,17 +121,84 @@ impl PageBuffer {
         })
     }
 
-    fn maybe_unprotect(&mut self) -> Result<(), PageError> {
-        if self.strategy == ProtectionStrategy::MemProtected {
-            self.page.unprotect()?;
+    /// Allocates a locked, non-dumpable (but unprotected) page for `len` bytes.
+    fn allocate(strategy: ProtectionStrategy, len: usize) -> Result<Page, PageError> {
+        let mut page =
+            Page::with_capacity(len, strategy == ProtectionStrategy::MemProtectedGuarded)?;
+
+        if let Err(e) = page.lock().and_then(|_| page.mark_dontdump()) {
+            page.dispose();
+            return Err(e);
+        }
+
+        Ok(page)
+    }
+
+    /// Resizes the buffer to `new_size` bytes, moving its contents into a
+    /// fresh allocation with the same [`ProtectionStrategy`].
+    ///
+    /// Existing bytes are kept up to `min(len, new_size)`; any new tail is
+    /// zero. The old region is zeroized and unmapped.
+    ///
+    /// If the new region cannot be allocated, the error is returned and the
+    /// buffer is left untouched. Protection errors while copying abort the
+    /// process, as in [`Buffer::open`].
+    pub fn resize(&mut self, new_size: usize) -> Result<(), BufferError> {
+        let new_page = Self::allocate(self.strategy, new_size)?;
+        let result = self.try_move_into(new_page, new_size);
+
+        if let Err(BufferError::Page(e)) = &result {
+            self.page.dispose();
+            Self::abort(*e);
+        }
+
+        result
+    }
+
+    fn try_move_into(&mut self, mut new_page: Page, new_size: usize) -> Result<(), BufferError> {
+        let copied = self.len.min(new_size);
+
+        if let Err(e) = self.maybe_unprotect() {
+            new_page.dispose();
+            return Err(e.into());
         }
 
+        unsafe {
+            new_page.as_mut_slice()[..copied].copy_from_slice(&self.page.as_slice()[..copied])
+        };
+
+        if self.strategy.toggles_protection()
+            && let Err(e) = new_page.protect()
+        {
+            new_page.dispose();
+            return Err(e.into());
+        }
+
+        let mut old_page = core::mem::replace(&mut self.page, new_page);
+        old_page.dispose();
+        self.len = new_size;
+
         Ok(())
     }
 
-    fn maybe_protect(&mut self) -> Result<(), PageError> {
-        if self.strategy == ProtectionStrategy::MemProtected {
-            self.page.protect()?;
+    #[cfg(test)]
+    pub(crate) fn page_ptr(&self) -> *const u8 {
+        self.page.as_ptr()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn is_page_protected(&self) -> bool {
+        self.page.is_protected()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn strategy(&self) -> ProtectionStrategy {
+        self.strategy
+    }
+
+    fn maybe_unprotect(&mut self) -> Result<(), PageError> {
+        if self.strategy.toggles_protection() {
+            self.page.unprotect()?;
         }
 
         Ok(())
@@ -78,12 +208,12 @@ impl PageBuffer {
         &mut self,
         f: &mut dyn FnMut(&[u8]) -> Result<(), BufferError>,
     ) -> Result<(), BufferError> {
-        self.maybe_unprotect()?;
+        let guard = UnprotectGuard::new(&mut self.page, self.strategy)?;
 
-        let slice = unsafe { self.page.as_slice() };
+        let slice = unsafe { guard.page.as_slice() };
         f(&slice[..self.len])?;
 
-        self.maybe_protect()?;
+        guard.reprotect()?;
 
         Ok(())
     }
@@ -92,12 +222,12 @@ impl PageBuffer {
         &mut self,
         f: &mut dyn FnMut(&mut [u8]) -> Result<(), BufferError>,
     ) -> Result<(), BufferError> {
-        self.maybe_unprotect()?;
+        let guard = UnprotectGuard::new(&mut self.page, self.strategy)?;
 
-        let slice = unsafe { self.page.as_mut_slice() };
+        let slice = unsafe { guard.page.as_mut_slice() };
         f(&mut slice[..self.len])?;
 
-        self.maybe_protect()?;
+        guard.reprotect()?;
 
         Ok(())
     }
@@ -5,7 +5,7 @@
 mod page;
 mod portable_buffer;
 
-#[cfg(unix)]
+#[cfg(any(unix, windows))]
 mod page_buffer;
 #[cfg(target_os = "linux")]
 mod utils;
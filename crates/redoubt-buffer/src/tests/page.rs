@@ -4,13 +4,12 @@
 
 //! Exhaustive tests for Page.
 
-#[cfg(all(test, unix))]
+#[cfg(all(test, any(unix, windows)))]
 mod page_tests {
     use serial_test::serial;
 
     use redoubt_zero::ZeroizationProbe;
 
-    #[cfg(target_os = "linux")]
     use crate::error::PageError;
     use crate::page::Page;
 
@@ -38,6 +37,20 @@ mod page_tests {
         assert_eq!(slice.len(), system_page_size);
     }
 
+    #[test]
+    #[serial(page)]
+    #[cfg(windows)]
+    fn test_slice_len_matches_page_size() {
+        use windows_sys::Win32::System::SystemInformation::{GetSystemInfo, SYSTEM_INFO};
+
+        let page = Page::new().expect("Failed to new()");
+        let mut info = SYSTEM_INFO::default();
+        unsafe { GetSystemInfo(&mut info) };
+        let slice = unsafe { page.as_slice() };
+
+        assert_eq!(slice.len(), info.dwPageSize as usize);
+    }
+
     // TODO: Run this test in a subprocess to safely cover the MAP_FAILED branch
     // without causing stack allocation failures in the main test process.
     // This would allow including it in coverage reports without flakiness.
@@ -437,6 +450,166 @@ mod page_tests {
         assert!(unsafe { page.as_slice() }.is_zeroized());
     }
 
+    // =============================================================================
+    // with_capacity()
+    // =============================================================================
+
+    #[test]
+    #[serial(page)]
+    fn test_with_capacity_rounds_up_to_whole_pages() {
+        let page_size = Page::size();
+
+        for (min_len, expected) in [
+            (0, page_size),
+            (1, page_size),
+            (page_size, page_size),
+            (page_size + 1, 2 * page_size),
+        ] {
+            let mut page =
+                Page::with_capacity(min_len, false).expect("Failed to with_capacity(..)");
+            assert_eq!(unsafe { page.as_slice() }.len(), expected);
+            page.dispose();
+        }
+    }
+
+    #[test]
+    #[serial(page)]
+    fn test_with_capacity_guarded_multi_page() {
+        let page_size = Page::size();
+        let mut page =
+            Page::with_capacity(2 * page_size, true).expect("Failed to with_capacity(..)");
+
+        assert!(page.is_guarded());
+        assert!(unsafe { page.as_slice() }.is_zeroized());
+
+        unsafe { page.as_mut_slice().fill(0x77) };
+        page.protect().expect("Failed to protect()");
+        page.unprotect().expect("Failed to unprotect()");
+
+        assert_eq!(unsafe { page.as_slice() }.len(), 2 * page_size);
+        assert!(unsafe { page.as_slice() }.iter().all(|b| *b == 0x77));
+
+        page.dispose();
+    }
+
+    #[test]
+    #[serial(page)]
+    fn test_with_capacity_fails_on_overflow() {
+        let result = Page::with_capacity(usize::MAX, false);
+
+        assert!(matches!(result, Err(PageError::Create)));
+    }
+
+    // =============================================================================
+    // new_guarded()
+    // =============================================================================
+
+    #[test]
+    #[serial(page)]
+    fn test_new_guarded_page_is_zeroized() {
+        let page = Page::new_guarded().expect("Failed to new_guarded()");
+        let slice = unsafe { page.as_slice() };
+
+        assert!(page.is_guarded());
+        assert!(slice.is_zeroized());
+    }
+
+    #[test]
+    #[serial(page)]
+    fn test_new_page_is_not_guarded() {
+        let page = Page::new().expect("Failed to new()");
+
+        assert!(!page.is_guarded());
+    }
+
+    #[test]
+    #[serial(page)]
+    fn test_guarded_page_lifecycle() {
+        let mut page = Page::new_guarded().expect("Failed to new_guarded()");
+
+        page.lock().expect("Failed to lock()");
+        unsafe { page.as_mut_slice().fill(0x5A) };
+
+        page.protect().expect("Failed to protect()");
+        page.unprotect().expect("Failed to unprotect()");
+
+        assert!(unsafe { page.as_slice() }.iter().all(|b| *b == 0x5A));
+
+        page.dispose();
+    }
+
+    #[cfg(target_os = "linux")]
+    mod guard_faults {
+        use super::*;
+        use crate::tests::utils::{
+            block_mprotect, run_test_as_subprocess, run_test_as_subprocess_signal,
+        };
+
+        #[test]
+        #[ignore]
+        fn subprocess_test_write_past_end_faults() {
+            let mut page = Page::new_guarded().expect("Failed to new_guarded()");
+            let slice = unsafe { page.as_mut_slice() };
+
+            // First byte of the trailing guard page
+            unsafe { core::ptr::write_volatile(slice.as_mut_ptr().add(slice.len()), 0xFF) };
+        }
+
+        #[test]
+        #[serial(page)]
+        fn test_write_past_end_faults() {
+            let signal = run_test_as_subprocess_signal(
+                "tests::page::page_tests::guard_faults::subprocess_test_write_past_end_faults",
+            );
+
+            assert_eq!(signal, Some(libc::SIGSEGV));
+        }
+
+        #[test]
+        #[ignore]
+        fn subprocess_test_write_before_start_faults() {
+            let mut page = Page::new_guarded().expect("Failed to new_guarded()");
+            let slice = unsafe { page.as_mut_slice() };
+
+            // Last byte of the leading guard page
+            unsafe { core::ptr::write_volatile(slice.as_mut_ptr().sub(1), 0xFF) };
+        }
+
+        #[test]
+        #[serial(page)]
+        fn test_write_before_start_faults() {
+            let signal = run_test_as_subprocess_signal(
+                "tests::page::page_tests::guard_faults::subprocess_test_write_before_start_faults",
+            );
+
+            assert_eq!(signal, Some(libc::SIGSEGV));
+        }
+
+        #[test]
+        #[ignore]
+        fn subprocess_test_new_guarded_fails_when_mprotect_blocked() {
+            block_mprotect();
+
+            let result = Page::new_guarded();
+
+            assert!(matches!(result, Err(PageError::Guard)));
+        }
+
+        #[test]
+        #[serial(page)]
+        fn test_new_guarded_fails_when_mprotect_blocked() {
+            let exit_code = run_test_as_subprocess(
+                "tests::page::page_tests::guard_faults::subprocess_test_new_guarded_fails_when_mprotect_blocked",
+            );
+
+            assert_eq!(
+                exit_code,
+                Some(0),
+                "Subprocess should exit cleanly after assertion"
+            );
+        }
+    }
+
     // =============================================================================
     // dispose()
     // =============================================================================
@@ -4,7 +4,7 @@
 
 //! Tests for PageBuffer.
 
-#[cfg(all(test, unix))]
+#[cfg(all(test, any(unix, windows)))]
 mod page_buffer_tests {
     use serial_test::serial;
 
@@ -35,6 +35,15 @@ mod page_buffer_tests {
         assert!(debug_output.contains("MemNonProtected"));
     }
 
+    #[test]
+    #[serial(page_buffer)]
+    fn test_new_mem_protected_guarded() {
+        let buffer = PageBuffer::new(ProtectionStrategy::MemProtectedGuarded, 32)
+            .expect("Failed to new(..)");
+        let debug_output = format!("{:?}", buffer);
+        assert!(debug_output.contains("MemProtectedGuarded"));
+    }
+
     // TODO: Run this test in a subprocess to safely cover the MAP_FAILED branch
     // without causing stack allocation failures in the main test process.
     // This would allow including it in coverage reports without flakiness.
@@ -190,6 +199,28 @@ mod page_buffer_tests {
             .expect("Failed to open(..)");
     }
 
+    #[test]
+    #[serial(page_buffer)]
+    fn test_open_mem_protected_guarded() {
+        let mut buffer = PageBuffer::new(ProtectionStrategy::MemProtectedGuarded, 32)
+            .expect("Failed to new(..)");
+
+        buffer
+            .open_mut(&mut |bytes| {
+                bytes.fill(0xEF);
+                Ok(())
+            })
+            .expect("Failed to open_mut(..)");
+
+        buffer
+            .open(&mut |bytes| {
+                assert_eq!(bytes.len(), 32);
+                assert!(bytes.iter().all(|b| *b == 0xEF));
+                Ok(())
+            })
+            .expect("Failed to open(..)");
+    }
+
     #[test]
     #[serial(page_buffer)]
     fn test_open_propagates_callback_error() {
@@ -363,6 +394,249 @@ mod page_buffer_tests {
         }
     }
 
+    // =============================================================================
+    // open_with() / open_mut_with()
+    // =============================================================================
+
+    #[test]
+    #[serial(page_buffer)]
+    fn test_open_with_returns_value() {
+        for strategy in STRATEGIES {
+            let mut buffer = PageBuffer::new(strategy, 32).expect("Failed to new(..)");
+
+            buffer
+                .open_mut(&mut |bytes| {
+                    bytes[7] = 0x99;
+                    Ok(())
+                })
+                .expect("Failed to open_mut(..)");
+
+            let byte = buffer
+                .open_with(|bytes| bytes[7])
+                .expect("Failed to open_with(..)");
+
+            assert_eq!(byte, 0x99);
+            assert_eq!(
+                buffer.is_page_protected(),
+                strategy != ProtectionStrategy::MemNonProtected
+            );
+        }
+    }
+
+    #[test]
+    #[serial(page_buffer)]
+    fn test_open_mut_with_returns_value() {
+        for strategy in STRATEGIES {
+            let mut buffer = PageBuffer::new(strategy, 32).expect("Failed to new(..)");
+
+            let previous = buffer
+                .open_mut_with(|bytes| core::mem::replace(&mut bytes[0], 0x3C))
+                .expect("Failed to open_mut_with(..)");
+            let byte = buffer
+                .open_with(|bytes| bytes[0])
+                .expect("Failed to open_with(..)");
+
+            assert_eq!(previous, 0);
+            assert_eq!(byte, 0x3C);
+            assert_eq!(
+                buffer.is_page_protected(),
+                strategy != ProtectionStrategy::MemNonProtected
+            );
+        }
+    }
+
+    #[test]
+    #[serial(page_buffer)]
+    fn test_open_with_reprotects_on_panic() {
+        let mut buffer =
+            PageBuffer::new(ProtectionStrategy::MemProtected, 32).expect("Failed to new(..)");
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _ = buffer.open_mut_with(|bytes| {
+                bytes[0] = 0x5A;
+                panic!("closure panicked");
+            });
+        }));
+
+        assert!(result.is_err());
+        assert!(buffer.is_page_protected());
+
+        let byte = buffer
+            .open_with(|bytes| bytes[0])
+            .expect("Failed to open_with(..)");
+        assert_eq!(byte, 0x5A);
+    }
+
+    #[test]
+    #[serial(page_buffer)]
+    fn test_open_mut_reprotects_on_panic_and_reopens() {
+        for strategy in STRATEGIES {
+            let mut buffer = PageBuffer::new(strategy, 32).expect("Failed to new(..)");
+
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                let _ = buffer.open_mut(&mut |bytes| {
+                    bytes[3] = 0xA5;
+                    panic!("closure panicked");
+                });
+            }));
+
+            assert!(result.is_err());
+            assert_eq!(
+                buffer.is_page_protected(),
+                strategy != ProtectionStrategy::MemNonProtected
+            );
+
+            buffer
+                .open_mut(&mut |bytes| {
+                    bytes[4] = 0x5A;
+                    Ok(())
+                })
+                .expect("Failed to open_mut(..)");
+
+            let (written_before_panic, written_after) = buffer
+                .open_with(|bytes| (bytes[3], bytes[4]))
+                .expect("Failed to open_with(..)");
+
+            assert_eq!(written_before_panic, 0xA5);
+            assert_eq!(written_after, 0x5A);
+            assert_eq!(
+                buffer.is_page_protected(),
+                strategy != ProtectionStrategy::MemNonProtected
+            );
+        }
+    }
+
+    #[test]
+    #[serial(page_buffer)]
+    fn test_open_reprotects_on_callback_error() {
+        use crate::error::BufferError;
+
+        let mut buffer =
+            PageBuffer::new(ProtectionStrategy::MemProtected, 32).expect("Failed to new(..)");
+
+        let result = buffer.open(&mut |_| Err(BufferError::callback_error("test error")));
+
+        assert!(result.is_err());
+        assert!(buffer.is_page_protected());
+    }
+
+    // =============================================================================
+    // clone_contents_into()
+    // =============================================================================
+
+    #[test]
+    #[serial(page_buffer)]
+    fn test_clone_contents_into() {
+        for strategy in STRATEGIES {
+            let mut src = PageBuffer::new(strategy, 32).expect("Failed to new(..)");
+            let mut dst = PageBuffer::new(strategy, 32).expect("Failed to new(..)");
+
+            src.open_mut(&mut |bytes| {
+                bytes.fill(0x6B);
+                Ok(())
+            })
+            .expect("Failed to open_mut(..)");
+
+            src.clone_contents_into(&mut dst)
+                .expect("Failed to clone_contents_into(..)");
+
+            dst.open(&mut |bytes| {
+                assert_eq!(bytes, [0x6B; 32]);
+                Ok(())
+            })
+            .expect("Failed to open(..)");
+
+            let protected = strategy != ProtectionStrategy::MemNonProtected;
+            assert_eq!(src.is_page_protected(), protected);
+            assert_eq!(dst.is_page_protected(), protected);
+        }
+    }
+
+    #[test]
+    #[serial(page_buffer)]
+    fn test_clone_contents_into_portable_buffer() {
+        let mut src =
+            PageBuffer::new(ProtectionStrategy::MemProtected, 32).expect("Failed to new(..)");
+        let mut dst = crate::portable_buffer::PortableBuffer::create(32);
+
+        src.open_mut(&mut |bytes| {
+            bytes.fill(0x1F);
+            Ok(())
+        })
+        .expect("Failed to open_mut(..)");
+
+        src.clone_contents_into(&mut dst)
+            .expect("Failed to clone_contents_into(..)");
+
+        let byte = dst
+            .open_with(|bytes| bytes[31])
+            .expect("Failed to open_with(..)");
+        assert_eq!(byte, 0x1F);
+    }
+
+    #[test]
+    #[serial(page_buffer)]
+    fn test_clone_contents_into_size_mismatch() {
+        use crate::error::BufferError;
+
+        let mut src =
+            PageBuffer::new(ProtectionStrategy::MemProtected, 32).expect("Failed to new(..)");
+        let mut dst =
+            PageBuffer::new(ProtectionStrategy::MemProtected, 64).expect("Failed to new(..)");
+
+        let result = src.clone_contents_into(&mut dst);
+
+        assert!(matches!(
+            result,
+            Err(BufferError::SizeMismatch { src: 32, dst: 64 })
+        ));
+        assert!(src.is_page_protected());
+        assert!(dst.is_page_protected());
+    }
+
+    // =============================================================================
+    // zeroize_now()
+    // =============================================================================
+
+    #[test]
+    #[serial(page_buffer)]
+    fn test_zeroize_now() {
+        for strategy in STRATEGIES {
+            let mut buffer = PageBuffer::new(strategy, 32).expect("Failed to new(..)");
+
+            buffer
+                .open_mut(&mut |bytes| {
+                    bytes.fill(0xAB);
+                    Ok(())
+                })
+                .expect("Failed to open_mut(..)");
+
+            buffer.zeroize_now().expect("Failed to zeroize_now()");
+
+            assert_eq!(
+                buffer.is_page_protected(),
+                strategy != ProtectionStrategy::MemNonProtected
+            );
+
+            let zeroized = buffer
+                .open_with(|bytes| bytes.is_zeroized())
+                .expect("Failed to open_with(..)");
+            assert!(zeroized);
+
+            buffer
+                .open_mut(&mut |bytes| {
+                    bytes[0] = 0x42;
+                    Ok(())
+                })
+                .expect("Failed to open_mut(..)");
+
+            let byte = buffer
+                .open_with(|bytes| bytes[0])
+                .expect("Failed to open_with(..)");
+            assert_eq!(byte, 0x42);
+        }
+    }
+
     // =============================================================================
     // len() / is_empty()
     // =============================================================================
@@ -391,6 +665,141 @@ mod page_buffer_tests {
         assert!(buffer.is_empty());
     }
 
+    // =============================================================================
+    // resize()
+    // =============================================================================
+
+    const STRATEGIES: [ProtectionStrategy; 3] = [
+        ProtectionStrategy::MemProtected,
+        ProtectionStrategy::MemNonProtected,
+        ProtectionStrategy::MemProtectedGuarded,
+    ];
+
+    /// Returns true if `ptr` no longer belongs to a mapping.
+    #[cfg(unix)]
+    fn is_unmapped(ptr: *const u8) -> bool {
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+        let mut residency = [0u8; 1];
+        #[cfg(target_os = "linux")]
+        let residency_ptr = residency.as_mut_ptr();
+        #[cfg(not(target_os = "linux"))]
+        let residency_ptr = residency.as_mut_ptr() as *mut libc::c_char;
+
+        let result = unsafe { libc::mincore(ptr as *mut _, page_size, residency_ptr) };
+
+        result != 0 && std::io::Error::last_os_error().raw_os_error() == Some(libc::ENOMEM)
+    }
+
+    #[test]
+    #[serial(page_buffer)]
+    fn test_resize_grow_preserves_contents() {
+        for strategy in STRATEGIES {
+            let mut buffer = PageBuffer::new(strategy, 32).expect("Failed to new(..)");
+
+            buffer
+                .open_mut(&mut |bytes| {
+                    for (i, byte) in bytes.iter_mut().enumerate() {
+                        *byte = i as u8;
+                    }
+                    Ok(())
+                })
+                .expect("Failed to open_mut(..)");
+
+            let old_ptr = buffer.page_ptr();
+
+            buffer.resize(128).expect("Failed to resize(..)");
+
+            assert_eq!(buffer.len(), 128);
+            assert_eq!(buffer.strategy(), strategy);
+            assert_ne!(buffer.page_ptr(), old_ptr);
+            #[cfg(unix)]
+            assert!(is_unmapped(old_ptr));
+
+            buffer
+                .open(&mut |bytes| {
+                    assert_eq!(bytes.len(), 128);
+                    for (i, byte) in bytes[..32].iter().enumerate() {
+                        assert_eq!(*byte, i as u8);
+                    }
+                    assert!(bytes[32..].is_zeroized());
+                    Ok(())
+                })
+                .expect("Failed to open(..)");
+        }
+    }
+
+    #[test]
+    #[serial(page_buffer)]
+    fn test_resize_shrink_truncates_contents() {
+        for strategy in STRATEGIES {
+            let mut buffer = PageBuffer::new(strategy, 64).expect("Failed to new(..)");
+
+            buffer
+                .open_mut(&mut |bytes| {
+                    bytes.fill(0xAB);
+                    Ok(())
+                })
+                .expect("Failed to open_mut(..)");
+
+            buffer.resize(16).expect("Failed to resize(..)");
+
+            assert_eq!(buffer.len(), 16);
+            buffer
+                .open(&mut |bytes| {
+                    assert_eq!(bytes, [0xAB; 16]);
+                    Ok(())
+                })
+                .expect("Failed to open(..)");
+        }
+    }
+
+    #[test]
+    #[serial(page_buffer)]
+    fn test_resize_beyond_page_size() {
+        let page_size = crate::page::Page::size();
+
+        for strategy in STRATEGIES {
+            let mut buffer = PageBuffer::new(strategy, 32).expect("Failed to new(..)");
+
+            buffer
+                .open_mut(&mut |bytes| {
+                    bytes.fill(0xCD);
+                    Ok(())
+                })
+                .expect("Failed to open_mut(..)");
+
+            buffer
+                .resize(3 * page_size + 1)
+                .expect("Failed to resize(..)");
+
+            buffer
+                .open_mut(&mut |bytes| {
+                    assert_eq!(bytes.len(), 3 * page_size + 1);
+                    assert_eq!(bytes[..32], [0xCD; 32]);
+                    assert!(bytes[32..].is_zeroized());
+                    bytes.fill(0xEF);
+                    Ok(())
+                })
+                .expect("Failed to open_mut(..)");
+        }
+    }
+
+    #[test]
+    #[serial(page_buffer)]
+    fn test_new_larger_than_page_size() {
+        let page_size = crate::page::Page::size();
+        let mut buffer = PageBuffer::new(ProtectionStrategy::MemProtectedGuarded, 2 * page_size)
+            .expect("Failed to new(..)");
+
+        buffer
+            .open_mut(&mut |bytes| {
+                assert_eq!(bytes.len(), 2 * page_size);
+                bytes.fill(0x11);
+                Ok(())
+            })
+            .expect("Failed to open_mut(..)");
+    }
+
     // =============================================================================
     // dispose()
     // =============================================================================
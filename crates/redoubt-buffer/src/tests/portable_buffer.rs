@@ -169,6 +169,136 @@ fn test_portable_buffer_open_mut_propagates_callback_error() {
     }
 }
 
+// open_with / open_mut_with
+
+#[test]
+fn test_portable_buffer_open_mut_reopens_after_panic() {
+    let mut portable_buffer = PortableBuffer::create(10);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _ = portable_buffer.open_mut(&mut |bytes| {
+            bytes[0] = 0xA5;
+            panic!("closure panicked");
+        });
+    }));
+
+    assert!(result.is_err());
+
+    let byte = portable_buffer
+        .open_with(|bytes| bytes[0])
+        .expect("Failed to open_with(..)");
+    assert_eq!(byte, 0xA5);
+}
+
+#[test]
+fn test_portable_buffer_open_with_returns_value() {
+    let mut portable_buffer = PortableBuffer::create(10);
+
+    portable_buffer
+        .open_mut(&mut |bytes| {
+            bytes[3] = 0x42;
+            Ok(())
+        })
+        .expect("Failed to open_mut(..)");
+
+    let byte = portable_buffer
+        .open_with(|bytes| bytes[3])
+        .expect("Failed to open_with(..)");
+
+    assert_eq!(byte, 0x42);
+}
+
+#[test]
+fn test_portable_buffer_open_mut_with_returns_value() {
+    let mut portable_buffer = PortableBuffer::create(10);
+
+    let previous = portable_buffer
+        .open_mut_with(|bytes| core::mem::replace(&mut bytes[0], 0x7F))
+        .expect("Failed to open_mut_with(..)");
+    let byte = portable_buffer
+        .open_with(|bytes| bytes[0])
+        .expect("Failed to open_with(..)");
+
+    assert_eq!(previous, 0);
+    assert_eq!(byte, 0x7F);
+}
+
+// clone_contents_into
+
+#[test]
+fn test_portable_buffer_clone_contents_into() {
+    let mut src = PortableBuffer::create(16);
+    let mut dst = PortableBuffer::create(16);
+
+    src.open_mut(&mut |bytes| {
+        fill_bytes_with_pattern(bytes, 0xA5);
+        Ok(())
+    })
+    .expect("Failed to open_mut(..)");
+
+    src.clone_contents_into(&mut dst)
+        .expect("Failed to clone_contents_into(..)");
+
+    let mut expected = [0u8; 16];
+    fill_bytes_with_pattern(&mut expected, 0xA5);
+
+    dst.open(&mut |bytes| {
+        assert_eq!(bytes, expected);
+        Ok(())
+    })
+    .expect("Failed to open(..)");
+}
+
+#[test]
+fn test_portable_buffer_clone_contents_into_size_mismatch() {
+    let mut src = PortableBuffer::create(16);
+    let mut dst = PortableBuffer::create(8);
+
+    src.open_mut(&mut |bytes| {
+        bytes.fill(0xFF);
+        Ok(())
+    })
+    .expect("Failed to open_mut(..)");
+
+    let result = src.clone_contents_into(&mut dst);
+
+    assert!(matches!(
+        result,
+        Err(BufferError::SizeMismatch { src: 16, dst: 8 })
+    ));
+    dst.open(&mut |bytes| {
+        assert!(bytes.is_zeroized());
+        Ok(())
+    })
+    .expect("Failed to open(..)");
+}
+
+// zeroize_now
+
+#[test]
+fn test_portable_buffer_zeroize_now() {
+    let mut portable_buffer = PortableBuffer::create(10);
+
+    portable_buffer
+        .open_mut(&mut |bytes| {
+            fill_bytes_with_pattern(bytes, 1);
+            Ok(())
+        })
+        .expect("Failed to open_mut(..)");
+
+    portable_buffer
+        .zeroize_now()
+        .expect("Failed to zeroize_now()");
+
+    portable_buffer
+        .open(&mut |bytes| {
+            assert!(bytes.is_zeroized());
+            Ok(())
+        })
+        .expect("Failed to open(..)");
+    assert_eq!(portable_buffer.len(), 10);
+}
+
 // len
 
 #[test]
@@ -4,11 +4,11 @@
 
 //! Test utilities for redoubt-buffer.
 
-/// Runs an ignored test as a subprocess and returns its exit code.
 #[cfg(target_os = "linux")]
-pub fn run_test_as_subprocess(test_name: &str) -> Option<i32> {
+fn run_subprocess(test_name: &str) -> std::process::ExitStatus {
     let exe = std::env::current_exe().expect("Failed to current_exe()");
-    let status = std::process::Command::new(exe)
+
+    std::process::Command::new(exe)
         .args([
             "--exact",
             test_name,
@@ -17,9 +17,21 @@ pub fn run_test_as_subprocess(test_name: &str) -> Option<i32> {
             "--nocapture",
         ])
         .status()
-        .expect("Failed to run subprocess");
+        .expect("Failed to run subprocess")
+}
+
+/// Runs an ignored test as a subprocess and returns its exit code.
+#[cfg(target_os = "linux")]
+pub fn run_test_as_subprocess(test_name: &str) -> Option<i32> {
+    run_subprocess(test_name).code()
+}
+
+/// Runs an ignored test as a subprocess and returns the signal that killed it.
+#[cfg(target_os = "linux")]
+pub fn run_test_as_subprocess_signal(test_name: &str) -> Option<i32> {
+    use std::os::unix::process::ExitStatusExt;
 
-    status.code()
+    run_subprocess(test_name).signal()
 }
 
 #[cfg(target_os = "linux")]
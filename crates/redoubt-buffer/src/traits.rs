@@ -2,22 +2,105 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // See LICENSE in the repository root for full license text.
 
+use redoubt_zero::FastZeroizable;
+
 use crate::error::BufferError;
 
 /// Trait for buffer types that provide temporary access to their contents.
 pub trait Buffer: Send + Sync + core::fmt::Debug {
     /// Opens the buffer for read-only access, executing the provided closure.
+    ///
+    /// Implementations must restore protection even if `f` panics, so the
+    /// buffer stays usable after the unwind is caught.
     fn open(
         &mut self,
         f: &mut dyn FnMut(&[u8]) -> Result<(), BufferError>,
     ) -> Result<(), BufferError>;
 
     /// Opens the buffer for mutable access, executing the provided closure.
+    ///
+    /// Implementations must restore protection even if `f` panics, so the
+    /// buffer stays usable after the unwind is caught.
     fn open_mut(
         &mut self,
         f: &mut dyn FnMut(&mut [u8]) -> Result<(), BufferError>,
     ) -> Result<(), BufferError>;
 
+    /// Opens the buffer for read-only access and returns the closure's result.
+    ///
+    /// Protection is restored after the closure runs, even if it panics.
+    fn open_with<R, F>(&mut self, f: F) -> Result<R, BufferError>
+    where
+        Self: Sized,
+        F: FnOnce(&[u8]) -> R,
+    {
+        let mut f = Some(f);
+        let mut result = None;
+
+        self.open(&mut |bytes| {
+            result = f.take().map(|f| f(bytes));
+            Ok(())
+        })?;
+
+        Ok(result.expect("Buffer::open returned Ok without running the closure"))
+    }
+
+    /// Opens the buffer for mutable access and returns the closure's result.
+    ///
+    /// Protection is restored after the closure runs, even if it panics.
+    fn open_mut_with<R, F>(&mut self, f: F) -> Result<R, BufferError>
+    where
+        Self: Sized,
+        F: FnOnce(&mut [u8]) -> R,
+    {
+        let mut f = Some(f);
+        let mut result = None;
+
+        self.open_mut(&mut |bytes| {
+            result = f.take().map(|f| f(bytes));
+            Ok(())
+        })?;
+
+        Ok(result.expect("Buffer::open_mut returned Ok without running the closure"))
+    }
+
+    /// Copies the contents of this buffer into `dst`.
+    ///
+    /// The copy happens inside the access closures of both buffers, so the
+    /// bytes are never held outside either buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BufferError::SizeMismatch`] if the lengths differ, leaving
+    /// `dst` untouched.
+    fn clone_contents_into(&mut self, dst: &mut dyn Buffer) -> Result<(), BufferError> {
+        if self.len() != dst.len() {
+            return Err(BufferError::SizeMismatch {
+                src: self.len(),
+                dst: dst.len(),
+            });
+        }
+
+        self.open(&mut |src| {
+            dst.open_mut(&mut |dst| {
+                dst.copy_from_slice(src);
+                Ok(())
+            })
+        })
+    }
+
+    /// Zeroizes the buffer's contents in place, leaving it usable.
+    ///
+    /// Lets a long-lived buffer be wiped right after use and refilled later
+    /// without giving up its allocation. Protection is lifted and restored
+    /// around the wipe, as in [`Buffer::open_mut`].
+    fn zeroize_now(&mut self) -> Result<(), BufferError> {
+        self.open_mut(&mut |bytes| {
+            bytes.fast_zeroize();
+            Ok(())
+        })
+    }
+
     /// Returns the length of the buffer in bytes.
     fn len(&self) -> usize;
 
@@ -10,7 +10,7 @@ use redoubt_alloc::AllockedVec;
 #[cfg(feature = "zeroize")]
 use redoubt_zero::{FastZeroizable, RedoubtZero, ZeroizeOnDropSentinel};
 
-use crate::error::RedoubtCodecBufferError;
+use crate::error::{EncodeError, RedoubtCodecBufferError};
 
 #[cfg_attr(feature = "zeroize", derive(RedoubtZero))]
 pub struct RedoubtCodecBuffer {
@@ -74,19 +74,47 @@ impl RedoubtCodecBuffer {
         self.allocked_vec.fast_zeroize();
     }
 
+    /// Prepares the buffer for a new encode of `new_size` bytes.
+    ///
+    /// Current contents are zeroized and the cursor rewound. The existing
+    /// allocation is reused when `new_size <= capacity()`; otherwise it is
+    /// replaced via [`realloc_with_capacity`](Self::realloc_with_capacity),
+    /// which zeroizes the old allocation. Lets hot encoders pool buffers
+    /// instead of allocating per operation.
+    #[inline(always)]
+    pub fn reset(&mut self, new_size: usize) {
+        self.clear();
+
+        if new_size > self.capacity() {
+            self.realloc_with_capacity(new_size);
+            return;
+        }
+
+        self.capacity = new_size;
+    }
+
+    /// Returns the size of the underlying allocation.
+    ///
+    /// May exceed [`len`](Self::len) after a [`reset`](Self::reset) to a
+    /// smaller size.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.allocked_vec.capacity()
+    }
+
     #[inline(always)]
     pub fn as_slice(&self) -> &[u8] {
-        unsafe { self.allocked_vec.as_capacity_slice() }
+        unsafe { &self.allocked_vec.as_capacity_slice()[..self.capacity] }
     }
 
     #[inline(always)]
     pub fn as_mut_slice(&mut self) -> &mut [u8] {
-        unsafe { self.allocked_vec.as_capacity_mut_slice() }
+        unsafe { &mut self.allocked_vec.as_capacity_mut_slice()[..self.capacity] }
     }
 
     #[inline(always)]
     pub fn len(&self) -> usize {
-        unsafe { self.allocked_vec.as_capacity_slice().len() }
+        self.capacity
     }
 
     #[inline(always)]
@@ -134,6 +162,76 @@ impl RedoubtCodecBuffer {
         Ok(())
     }
 
+    /// Returns the write cursor: the number of bytes written so far.
+    ///
+    /// Record it before writing a placeholder header to get the offset for
+    /// [`write_len_prefix_at`](Self::write_len_prefix_at).
+    #[inline(always)]
+    pub fn position(&self) -> usize {
+        self.cursor
+    }
+
+    /// Backfills a `u64` little-endian length prefix at `pos`.
+    ///
+    /// Overwrites 8 bytes that were already written (typically a zero
+    /// placeholder reserved before the body), so a frame can be encoded
+    /// without computing the body length up front. The cursor is unchanged.
+    ///
+    /// Fails with [`RedoubtCodecBufferError::OutOfBounds`] unless
+    /// `pos + 8 <= position()`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let header_pos = buf.position();
+    /// buf.write(&mut 0u64)?;
+    /// value.encode_into(&mut buf)?;
+    /// let body_len = buf.position() - header_pos - 8;
+    /// buf.write_len_prefix_at(header_pos, body_len)?;
+    /// ```
+    #[inline(always)]
+    pub fn write_len_prefix_at(
+        &mut self,
+        pos: usize,
+        len: usize,
+    ) -> Result<(), RedoubtCodecBufferError> {
+        let prefix_len = core::mem::size_of::<u64>();
+
+        match pos.checked_add(prefix_len) {
+            Some(end) if end <= self.cursor => {}
+            _ => return Err(RedoubtCodecBufferError::OutOfBounds),
+        }
+
+        let prefix = (len as u64).to_le_bytes();
+
+        unsafe {
+            let ptr = self.allocked_vec.as_mut_ptr().add(pos);
+            core::ptr::copy_nonoverlapping(prefix.as_ptr(), ptr, prefix_len);
+        }
+
+        Ok(())
+    }
+
+    /// Ends the encode lifecycle and seals the buffer against further writes.
+    ///
+    /// This is where any trailer (checksum, magic) would be appended once all
+    /// fields have been written. In the current (no-trailer) mode it is a cheap
+    /// no-op that only moves the buffer into a read-only [`FinalizedCodecBuffer`],
+    /// so forgetting to finalize becomes a type error rather than a missing trailer.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut buf = RedoubtCodecBuffer::with_capacity(8);
+    /// value.encode_into(&mut buf)?;
+    /// let mut finalized = buf.finalize()?;
+    /// let bytes = finalized.export_as_vec();
+    /// ```
+    #[inline(always)]
+    pub fn finalize(self) -> Result<FinalizedCodecBuffer, EncodeError> {
+        Ok(FinalizedCodecBuffer { buffer: self })
+    }
+
     /// Exports the buffer contents as a `Vec<u8>` and zeroizes the internal buffer.
     ///
     /// This method creates a new `Vec` containing a copy of the buffer's data,
@@ -163,3 +261,38 @@ impl RedoubtCodecBuffer {
         vec
     }
 }
+
+/// Read-only view of a [`RedoubtCodecBuffer`] returned by [`RedoubtCodecBuffer::finalize`].
+///
+/// Exposes no write methods. The wrapped buffer is still zeroized on drop.
+pub struct FinalizedCodecBuffer {
+    buffer: RedoubtCodecBuffer,
+}
+
+impl FinalizedCodecBuffer {
+    /// Returns the sealed encoded bytes.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        self.buffer.as_slice()
+    }
+
+    /// Returns the length of the sealed encoding in bytes.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns `true` if the sealed encoding is empty.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Exports the sealed contents as a `Vec<u8>` and zeroizes the internal buffer.
+    ///
+    /// See [`RedoubtCodecBuffer::export_as_vec`].
+    #[inline(always)]
+    pub fn export_as_vec(&mut self) -> Vec<u8> {
+        self.buffer.export_as_vec()
+    }
+}
@@ -13,7 +13,7 @@ use crate::traits::{
     BytesRequired, Decode, DecodeSlice, Encode, EncodeSlice, PreAlloc, TryDecode, TryEncode,
 };
 
-use super::helpers::{header_size, process_header, write_header};
+use super::helpers::{header_size, process_collection_header, write_header};
 
 /// Cleanup function for encode errors. Marked #[cold] to keep it out of the hot path.
 #[cold]
@@ -45,15 +45,11 @@ where
         let mut bytes_required = header_size();
 
         for elem in self.as_slice().iter() {
-            let new_bytes_required = bytes_required.wrapping_add(elem.encode_bytes_required()?);
-
-            if new_bytes_required < bytes_required {
-                return Err(OverflowError {
+            bytes_required = bytes_required
+                .checked_add(elem.encode_bytes_required()?)
+                .ok_or_else(|| OverflowError {
                     reason: "AllockedVec bytes_required overflow".into(),
-                });
-            }
-
-            bytes_required = new_bytes_required;
+                })?;
         }
 
         Ok(bytes_required)
@@ -116,7 +112,7 @@ where
     fn try_decode_from(&mut self, buf: &mut &mut [u8]) -> Result<(), DecodeError> {
         let mut size = Zeroizing::from(&mut 0usize);
 
-        process_header(buf, &mut size)?;
+        process_collection_header::<T>(buf, &mut size)?;
 
         self.prealloc(*size);
 
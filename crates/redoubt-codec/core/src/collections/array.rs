@@ -45,15 +45,11 @@ where
         let mut bytes_required = header_size();
 
         for elem in self.iter() {
-            let new_bytes_required = bytes_required.wrapping_add(elem.encode_bytes_required()?);
-
-            if new_bytes_required < bytes_required {
-                return Err(OverflowError {
+            bytes_required = bytes_required
+                .checked_add(elem.encode_bytes_required()?)
+                .ok_or_else(|| OverflowError {
                     reason: "Array bytes_required overflow".into(),
-                });
-            }
-
-            bytes_required = new_bytes_required;
+                })?;
         }
 
         Ok(bytes_required)
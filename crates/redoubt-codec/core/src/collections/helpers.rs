@@ -11,7 +11,9 @@ use smallvec::SmallVec;
 
 use crate::codec_buffer::RedoubtCodecBuffer;
 use crate::error::{DecodeError, EncodeError, OverflowError, RedoubtCodecBufferError};
-use crate::traits::{BytesRequired, Decode, DecodeBuffer, DecodeZeroize, Encode, EncodeZeroize};
+use crate::traits::{
+    BytesRequired, Decode, DecodeBuffer, DecodeSlice, DecodeZeroize, Encode, EncodeZeroize,
+};
 use crate::zeroizing::Zeroizing;
 
 pub fn header_size() -> usize {
@@ -32,6 +34,62 @@ pub fn write_header(
 
 #[inline(always)]
 pub fn process_header(buf: &mut &mut [u8], output_size: &mut usize) -> Result<(), DecodeError> {
+    let mut body_len = Zeroizing::from(&mut 0usize);
+
+    read_header(buf, output_size, &mut body_len)
+}
+
+/// Like [`process_header`], but also rejects an element count larger than
+/// the body it describes.
+///
+/// Callers preallocate `output_size` elements before decoding them, so an
+/// unchecked count lets a few bytes of input request an arbitrarily large
+/// allocation. Unless `T` encodes to zero bytes, every element takes at least
+/// one byte of the body, so a larger count can only come from a malformed
+/// header. Types with an empty encoding (zero-sized types, structs with only
+/// `#[codec(default)]` fields) are exempt.
+#[inline(always)]
+pub(crate) fn process_collection_header<T>(
+    buf: &mut &mut [u8],
+    output_size: &mut usize,
+) -> Result<(), DecodeError>
+where
+    T: DecodeSlice + Default,
+{
+    let mut body_len = Zeroizing::from(&mut 0usize);
+
+    read_header(buf, output_size, &mut body_len)?;
+
+    if *output_size > *body_len && !encodes_to_nothing::<T>() {
+        return Err(DecodeError::PreconditionViolated);
+    }
+
+    Ok(())
+}
+
+/// Whether `T` encodes to zero bytes: it is zero-sized, or one element
+/// decodes from empty input.
+#[cold]
+#[inline(never)]
+fn encodes_to_nothing<T>() -> bool
+where
+    T: DecodeSlice + Default,
+{
+    if size_of::<T>() == 0 {
+        return true;
+    }
+
+    let mut probe = [T::default()];
+
+    T::decode_slice_from(&mut probe, &mut &mut [][..]).is_ok()
+}
+
+#[inline(always)]
+fn read_header(
+    buf: &mut &mut [u8],
+    output_size: &mut usize,
+    body_len: &mut usize,
+) -> Result<(), DecodeError> {
     let header_size = Zeroizing::from(&mut header_size());
 
     if buf.len() < *header_size {
@@ -53,15 +111,57 @@ pub fn process_header(buf: &mut &mut [u8], output_size: &mut usize) -> Result<()
         return Err(DecodeError::PreconditionViolated);
     }
 
-    let expected_len = Zeroizing::from(&mut (*bytes_required - *header_size));
+    *body_len = *bytes_required - *header_size;
 
-    if buf.len() < *expected_len {
+    if buf.len() < *body_len {
         return Err(DecodeError::PreconditionViolated);
     }
 
     Ok(())
 }
 
+/// Advances `buf` past one encoded collection without decoding it.
+///
+/// Reads the collection header and consumes `bytes_required` bytes in total,
+/// zeroizing every skipped byte (the body may be sensitive). Lets callers
+/// decode only selected fields of a larger encoding.
+///
+/// Fails with [`DecodeError::PreconditionViolated`] if the header is
+/// truncated or `bytes_required` exceeds the remaining input.
+#[inline(always)]
+pub fn skip_collection(buf: &mut &mut [u8]) -> Result<(), DecodeError> {
+    let header_size = Zeroizing::from(&mut header_size());
+
+    if buf.len() < *header_size {
+        return Err(DecodeError::PreconditionViolated);
+    }
+
+    let mut bytes_required = Zeroizing::from(&mut 0usize);
+
+    // Peek instead of read_usize: nothing is consumed unless the whole
+    // collection fits.
+    unsafe {
+        // SAFETY (PRECONDITIONS ARE MET): buf.len() >= header_size, so the
+        // second usize of the header is in bounds
+        core::ptr::copy_nonoverlapping(
+            buf.as_ptr().add(size_of::<usize>()),
+            &mut *bytes_required as *mut usize as *mut u8,
+            size_of::<usize>(),
+        );
+    }
+
+    if *bytes_required < *header_size || *bytes_required > buf.len() {
+        return Err(DecodeError::PreconditionViolated);
+    }
+
+    #[cfg(feature = "zeroize")]
+    redoubt_util::fast_zeroize_slice(&mut buf[..*bytes_required]);
+
+    *buf = &mut core::mem::take(buf)[*bytes_required..];
+
+    Ok(())
+}
+
 // =============================================================================
 // Derive macro helpers
 // =============================================================================
@@ -104,15 +204,11 @@ pub fn bytes_required_sum<'a>(
     let mut total = Zeroizing::from(&mut 0usize);
 
     for elem in iter {
-        let new_total = Zeroizing::from(&mut total.wrapping_add(elem.encode_bytes_required()?));
-
-        if *new_total < *total {
-            return Err(OverflowError {
+        *total = total
+            .checked_add(elem.encode_bytes_required()?)
+            .ok_or_else(|| OverflowError {
                 reason: "bytes_required_sum overflow".into(),
-            });
-        }
-
-        *total = *new_total;
+            })?;
     }
 
     Ok(*total)
@@ -198,3 +294,51 @@ pub fn decode_fields<'a>(
 
     result
 }
+
+/// Zeroizes every field (no-op without the `zeroize` feature).
+#[inline(always)]
+pub fn zeroize_fields<'a>(fields: impl Iterator<Item = &'a mut dyn DecodeZeroize>) {
+    #[cfg(feature = "zeroize")]
+    for field in fields {
+        field.fast_zeroize();
+        compiler_fence(Ordering::SeqCst);
+    }
+
+    #[cfg(not(feature = "zeroize"))]
+    let _ = fields;
+}
+
+/// Zeroizes the remaining input and returns [`DecodeError::UnknownDiscriminant`].
+#[inline(always)]
+pub fn unknown_discriminant(buf: &mut &mut [u8]) -> DecodeError {
+    #[cfg(feature = "zeroize")]
+    redoubt_util::fast_zeroize_slice(buf);
+
+    #[cfg(not(feature = "zeroize"))]
+    let _ = buf;
+
+    DecodeError::UnknownDiscriminant
+}
+
+/// Reads a `u16` version, zeroizing it, and checks it equals `expected`.
+///
+/// On mismatch the remaining input is zeroized and
+/// [`DecodeError::UnsupportedVersion`] is returned.
+#[inline(always)]
+pub fn decode_version(buf: &mut &mut [u8], expected: u16) -> Result<(), DecodeError> {
+    let mut found = Zeroizing::from(&mut 0u16);
+
+    found.decode_from(buf)?;
+
+    if *found != expected {
+        #[cfg(feature = "zeroize")]
+        redoubt_util::fast_zeroize_slice(buf);
+
+        return Err(DecodeError::UnsupportedVersion {
+            found: *found,
+            expected,
+        });
+    }
+
+    Ok(())
+}
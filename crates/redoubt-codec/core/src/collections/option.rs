@@ -46,15 +46,12 @@ where
             None => Ok(header),
             Some(inner) => {
                 let inner_bytes = inner.encode_bytes_required()?;
-                let total = header.wrapping_add(inner_bytes);
 
-                if total < header {
-                    return Err(OverflowError {
+                header
+                    .checked_add(inner_bytes)
+                    .ok_or_else(|| OverflowError {
                         reason: "Option::encode_bytes_required overflow".into(),
-                    });
-                }
-
-                Ok(total)
+                    })
             }
         }
     }
@@ -127,7 +124,7 @@ where
                 *self = Some(inner);
             }
             _ => {
-                return Err(DecodeError::PreconditionViolated);
+                return Err(DecodeError::InvalidTag);
             }
         }
 
@@ -14,7 +14,7 @@ use crate::traits::{
 };
 use crate::zeroizing::Zeroizing;
 
-use super::helpers::{header_size, process_header, write_header};
+use super::helpers::{header_size, process_collection_header, write_header};
 
 /// Cleanup function for encode errors. Marked #[cold] to keep it out of the hot path.
 #[cfg(feature = "zeroize")]
@@ -40,15 +40,9 @@ fn cleanup_decode_error(s: &mut String, buf: &mut &mut [u8]) {
 
 #[inline(always)]
 pub(crate) fn string_bytes_required(len: usize) -> Result<usize, OverflowError> {
-    let bytes_required = header_size().wrapping_add(len);
-
-    if bytes_required < header_size() {
-        return Err(OverflowError {
-            reason: "String bytes_required overflow".into(),
-        });
-    }
-
-    Ok(bytes_required)
+    header_size().checked_add(len).ok_or_else(|| OverflowError {
+        reason: "String bytes_required overflow".into(),
+    })
 }
 
 impl BytesRequired for String {
@@ -104,7 +98,7 @@ impl TryDecode for String {
     fn try_decode_from(&mut self, buf: &mut &mut [u8]) -> Result<(), DecodeError> {
         let mut size = Zeroizing::from(&mut 0usize);
 
-        process_header(buf, &mut size)?;
+        process_collection_header::<u8>(buf, &mut size)?;
 
         self.prealloc(*size);
 
@@ -13,7 +13,7 @@ use crate::traits::{
 };
 use crate::zeroizing::Zeroizing;
 
-use super::helpers::{header_size, process_header, write_header};
+use super::helpers::{header_size, process_collection_header, write_header};
 
 /// Cleanup function for encode errors. Marked #[cold] to keep it out of the hot path.
 #[cfg(feature = "zeroize")]
@@ -47,15 +47,11 @@ where
         let mut bytes_required = header_size();
 
         for elem in self.iter() {
-            let new_bytes_required = bytes_required.wrapping_add(elem.encode_bytes_required()?);
-
-            if new_bytes_required < bytes_required {
-                return Err(OverflowError {
+            bytes_required = bytes_required
+                .checked_add(elem.encode_bytes_required()?)
+                .ok_or_else(|| OverflowError {
                     reason: "Vec::encode_bytes_required overflow".into(),
-                });
-            }
-
-            bytes_required = new_bytes_required;
+                })?;
         }
 
         Ok(bytes_required)
@@ -120,7 +116,7 @@ where
     fn try_decode_from(&mut self, buf: &mut &mut [u8]) -> Result<(), DecodeError> {
         let mut size = Zeroizing::from(&mut 0);
 
-        process_header(buf, &mut size)?;
+        process_collection_header::<T>(buf, &mut size)?;
 
         self.prealloc(*size);
 
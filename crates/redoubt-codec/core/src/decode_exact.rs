@@ -0,0 +1,42 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! Whole-buffer decoding that rejects trailing input.
+
+use redoubt_zero::FastZeroizable;
+
+use crate::error::DecodeError;
+use crate::traits::DecodeZeroize;
+
+/// Decodes a `T` that must occupy all of `buf`.
+///
+/// Fails with [`DecodeError::TrailingBytes`] if bytes remain after the value,
+/// so data appended to an encoding is rejected instead of silently ignored.
+///
+/// The whole of `buf` is zeroized on every path, trailing bytes included. On
+/// error the partially decoded value is zeroized before being dropped.
+pub fn decode_exact<T>(buf: &mut [u8]) -> Result<T, DecodeError>
+where
+    T: DecodeZeroize + Default,
+{
+    let mut value = T::default();
+    let mut cursor = &mut *buf;
+
+    let result = value.decode_from(&mut cursor);
+    let remaining = cursor.len();
+
+    buf.fast_zeroize();
+
+    if let Err(e) = result {
+        value.fast_zeroize();
+        return Err(e);
+    }
+
+    if remaining != 0 {
+        value.fast_zeroize();
+        return Err(DecodeError::TrailingBytes { remaining });
+    }
+
+    Ok(value)
+}
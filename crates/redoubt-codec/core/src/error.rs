@@ -13,6 +13,10 @@ use thiserror::Error;
 pub enum RedoubtCodecBufferError {
     #[error("CapacityExceeded")]
     CapacityExceeded,
+
+    /// A backfill position lies outside the bytes written so far.
+    #[error("OutOfBounds")]
+    OutOfBounds,
 }
 
 #[derive(Debug, Error, Eq, PartialEq)]
@@ -45,6 +49,26 @@ pub enum DecodeError {
     #[error("PreconditionViolated")]
     PreconditionViolated,
 
+    /// The encoded version does not match the one the type declares.
+    #[error("UnsupportedVersion: found {found}, expected {expected}")]
+    UnsupportedVersion { found: u16, expected: u16 },
+
+    /// A `bool` byte is neither `0x00` nor `0x01`.
+    #[error("InvalidBool")]
+    InvalidBool,
+
+    /// An `Option` tag is neither `0` (`None`) nor `1` (`Some`).
+    #[error("InvalidTag")]
+    InvalidTag,
+
+    /// An enum discriminant does not match any variant.
+    #[error("UnknownDiscriminant")]
+    UnknownDiscriminant,
+
+    /// The value decoded without consuming the whole input.
+    #[error("TrailingBytes: {remaining} bytes remaining")]
+    TrailingBytes { remaining: usize },
+
     /// Test-only error for simulating decode failures.
     ///
     /// Available only with `test-utils` feature enabled.
@@ -53,6 +77,29 @@ pub enum DecodeError {
     IntentionalDecodeError,
 }
 
+/// Errors yielded by [`FrameDecoder`](crate::FrameDecoder).
+#[cfg(any(test, feature = "std"))]
+#[derive(Debug, Error)]
+pub enum FrameDecodeError {
+    #[error("Io: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The stream ended in the middle of a frame header or body.
+    #[error("TruncatedFrame")]
+    TruncatedFrame,
+
+    /// The frame length prefix exceeds the configured maximum.
+    #[error("FrameTooLarge")]
+    FrameTooLarge,
+
+    /// The value decoded without consuming the whole frame.
+    #[error("TrailingBytes")]
+    TrailingBytes,
+
+    #[error("DecodeError: {0}")]
+    DecodeError(#[from] DecodeError),
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct OverflowError {
     pub reason: String,
@@ -0,0 +1,163 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! Streaming decoder for length-prefixed frames.
+
+use alloc::vec::Vec;
+
+use std::io::{ErrorKind, Read};
+
+use redoubt_zero::FastZeroizable;
+
+use crate::error::FrameDecodeError;
+use crate::traits::DecodeZeroize;
+
+/// Size of the frame length prefix (`u64`, little-endian).
+pub const FRAME_HEADER_LEN: usize = core::mem::size_of::<u64>();
+
+/// Decodes a stream of length-prefixed frames into values of type `T`.
+///
+/// Each frame is a `u64` little-endian byte length followed by exactly that
+/// many bytes of encoded `T`. For every frame the decoder:
+///
+/// 1. Reads the frame into an internal buffer
+/// 2. Decodes it into a fresh `T::default()`
+/// 3. Zeroizes the frame buffer (on success and on error)
+/// 4. Yields the value
+///
+/// A clean EOF before a frame header ends iteration. A partial header or body
+/// yields [`FrameDecodeError::TruncatedFrame`]. After the first error the
+/// iterator is fused and only returns `None`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use redoubt_codec::FrameDecoder;
+///
+/// for msg in FrameDecoder::<_, Message>::new(socket, 64 * 1024) {
+///     let msg = msg?;
+///     // ...
+/// }
+/// ```
+pub struct FrameDecoder<R, T> {
+    reader: R,
+    max_frame_len: usize,
+    frame: Vec<u8>,
+    done: bool,
+    _marker: core::marker::PhantomData<T>,
+}
+
+impl<R, T> FrameDecoder<R, T>
+where
+    R: Read,
+    T: DecodeZeroize + Default,
+{
+    /// Creates a decoder over `reader`, rejecting frames longer than `max_frame_len` bytes.
+    pub fn new(reader: R, max_frame_len: usize) -> Self {
+        Self {
+            reader,
+            max_frame_len,
+            frame: Vec::new(),
+            done: false,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn __unsafe_get_frame(&self) -> &Vec<u8> {
+        &self.frame
+    }
+
+    /// Reads the frame header.
+    ///
+    /// Returns `Ok(None)` on a clean EOF (no header byte read).
+    fn read_header(&mut self) -> Result<Option<usize>, FrameDecodeError> {
+        let mut header = [0u8; FRAME_HEADER_LEN];
+        let mut filled = 0;
+
+        while filled < FRAME_HEADER_LEN {
+            match self.reader.read(&mut header[filled..]) {
+                Ok(0) if filled == 0 => return Ok(None),
+                Ok(0) => return Err(FrameDecodeError::TruncatedFrame),
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(FrameDecodeError::Io(e)),
+            }
+        }
+
+        let len = u64::from_le_bytes(header);
+
+        match usize::try_from(len) {
+            Ok(len) if len <= self.max_frame_len => Ok(Some(len)),
+            _ => Err(FrameDecodeError::FrameTooLarge),
+        }
+    }
+
+    fn try_next_frame(&mut self) -> Result<Option<T>, FrameDecodeError> {
+        let Some(len) = self.read_header()? else {
+            return Ok(None);
+        };
+
+        // Safe to reallocate: the previous frame was already zeroized
+        self.frame.resize(len, 0);
+        self.reader
+            .read_exact(&mut self.frame)
+            .map_err(|e| match e.kind() {
+                ErrorKind::UnexpectedEof => FrameDecodeError::TruncatedFrame,
+                _ => FrameDecodeError::Io(e),
+            })?;
+
+        let mut value = T::default();
+        let mut buf = self.frame.as_mut_slice();
+
+        if let Err(e) = value.decode_from(&mut buf) {
+            value.fast_zeroize();
+            return Err(e.into());
+        }
+
+        if !buf.is_empty() {
+            value.fast_zeroize();
+            return Err(FrameDecodeError::TrailingBytes);
+        }
+
+        Ok(Some(value))
+    }
+}
+
+impl<R, T> Iterator for FrameDecoder<R, T>
+where
+    R: Read,
+    T: DecodeZeroize + Default,
+{
+    type Item = Result<T, FrameDecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = self.try_next_frame();
+
+        // Wipe the frame buffer (including spare capacity) on every path
+        self.frame.fast_zeroize();
+
+        match result {
+            Ok(Some(value)) => Some(Ok(value)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl<R, T> Drop for FrameDecoder<R, T> {
+    fn drop(&mut self) {
+        self.frame.fast_zeroize();
+    }
+}
@@ -12,15 +12,22 @@
 
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
 #[cfg(test)]
 mod tests;
 
 mod blankets;
 mod codec_buffer;
 mod decode_buffer;
+mod decode_exact;
 mod error;
+#[cfg(any(test, feature = "std"))]
+mod frame_decoder;
 mod primitives;
 mod traits;
+mod tuples;
 mod zeroizing;
 
 pub mod collections;
@@ -28,6 +35,11 @@ pub mod collections;
 #[cfg(any(test, feature = "test-utils"))]
 pub mod support;
 
-pub use codec_buffer::RedoubtCodecBuffer;
+pub use codec_buffer::{FinalizedCodecBuffer, RedoubtCodecBuffer};
+pub use decode_exact::decode_exact;
+#[cfg(any(test, feature = "std"))]
+pub use error::FrameDecodeError;
 pub use error::{DecodeError, EncodeError, OverflowError};
+#[cfg(any(test, feature = "std"))]
+pub use frame_decoder::{FRAME_HEADER_LEN, FrameDecoder};
 pub use traits::{BytesRequired, Decode, DecodeBuffer, DecodeZeroize, Encode, EncodeZeroize};
@@ -8,6 +8,7 @@ use redoubt_zero::FastZeroizable;
 use crate::error::DecodeError;
 
 use super::traits::{DecodeBuffer, TryDecode, TryEncode};
+use super::zeroizing::Zeroizing;
 
 // Native endian - bulk copy for all architectures
 macro_rules! impl_traits_for_primitives {
@@ -99,5 +100,116 @@ macro_rules! impl_traits_for_primitives {
 }
 
 impl_traits_for_primitives!(
-    bool, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64,
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64,
 );
+
+// bool is encoded as a single `0x00` / `0x01` byte. Encoding can copy the
+// in-memory representation as-is, but decoding must go through a `u8` so that
+// any other byte is rejected instead of producing an invalid `bool`.
+
+#[inline(always)]
+fn bool_from_byte(byte: u8) -> Result<bool, DecodeError> {
+    match byte {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(DecodeError::InvalidBool),
+    }
+}
+
+impl crate::traits::BytesRequired for bool {
+    #[inline(always)]
+    fn encode_bytes_required(&self) -> Result<usize, crate::error::OverflowError> {
+        Ok(1)
+    }
+}
+
+impl TryEncode for bool {
+    #[inline(always)]
+    fn try_encode_into(
+        &mut self,
+        buf: &mut crate::codec_buffer::RedoubtCodecBuffer,
+    ) -> Result<(), crate::error::EncodeError> {
+        buf.write(self)?;
+        Ok(())
+    }
+}
+
+impl crate::traits::Encode for bool {
+    #[inline(always)]
+    fn encode_into(
+        &mut self,
+        buf: &mut crate::codec_buffer::RedoubtCodecBuffer,
+    ) -> Result<(), crate::error::EncodeError> {
+        let result = self.try_encode_into(buf);
+
+        #[cfg(feature = "zeroize")]
+        self.fast_zeroize();
+
+        #[cfg(feature = "zeroize")]
+        if result.is_err() {
+            buf.fast_zeroize();
+        }
+
+        result
+    }
+}
+
+/// Caller is responsible for zeroizing slice and buffer on error.
+impl crate::traits::EncodeSlice for bool {
+    #[inline(always)]
+    fn encode_slice_into(
+        slice: &mut [Self],
+        buf: &mut crate::codec_buffer::RedoubtCodecBuffer,
+    ) -> Result<(), crate::error::EncodeError> {
+        buf.write_slice(slice)?;
+        Ok(())
+    }
+}
+
+impl TryDecode for bool {
+    #[inline(always)]
+    fn try_decode_from(&mut self, buf: &mut &mut [u8]) -> Result<(), DecodeError> {
+        let mut byte = Zeroizing::from(&mut 0u8);
+
+        buf.read(&mut *byte)?;
+        *self = bool_from_byte(*byte)?;
+
+        Ok(())
+    }
+}
+
+impl crate::traits::Decode for bool {
+    #[inline(always)]
+    fn decode_from(&mut self, buf: &mut &mut [u8]) -> Result<(), DecodeError> {
+        let result = self.try_decode_from(buf);
+
+        #[cfg(feature = "zeroize")]
+        if result.is_err() {
+            self.fast_zeroize();
+            buf.fast_zeroize();
+        }
+
+        result
+    }
+}
+
+/// Caller is responsible for zeroizing slice and buffer on error.
+impl crate::traits::DecodeSlice for bool {
+    #[inline(always)]
+    fn decode_slice_from(slice: &mut [Self], buf: &mut &mut [u8]) -> Result<(), DecodeError> {
+        for value in slice.iter_mut() {
+            value.try_decode_from(buf)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl crate::traits::PreAlloc for bool {
+    const ZERO_INIT: bool = true;
+
+    #[inline(always)]
+    fn prealloc(&mut self, _size: usize) {
+        // No-op: collection must preallocate memory with zeroes
+    }
+}
@@ -88,6 +88,57 @@ fn test_codec_buffer_clear() {
     assert_eq!(buf.as_slice()[0], 0x42);
 }
 
+#[test]
+fn test_codec_buffer_reset_smaller_reuses_allocation() {
+    let mut buf = RedoubtCodecBuffer::with_capacity(16);
+    buf.as_mut_slice().fill(0xFF);
+    let ptr = buf.as_slice().as_ptr();
+
+    buf.reset(8);
+
+    assert_eq!(buf.as_slice().as_ptr(), ptr);
+    assert_eq!(buf.len(), 8);
+    assert_eq!(buf.capacity(), 16);
+
+    #[cfg(feature = "zeroize")]
+    assert!(buf.as_slice().is_zeroized());
+
+    // Writes are bounded by the new size, not the allocation
+    let mut word = 0u64;
+    buf.write(&mut word).expect("Failed to write(..)");
+    assert!(buf.write(&mut 0u8).is_err());
+}
+
+#[test]
+fn test_codec_buffer_reset_larger_reallocates() {
+    let mut buf = RedoubtCodecBuffer::with_capacity(4);
+    buf.as_mut_slice().fill(0xFF);
+
+    buf.reset(32);
+
+    assert_eq!(buf.len(), 32);
+    assert_eq!(buf.capacity(), 32);
+    assert!(buf.as_slice().is_zeroized());
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn test_codec_buffer_reset_zeroizes_between_uses() {
+    let mut buf = RedoubtCodecBuffer::with_capacity(8);
+
+    let mut first = 0xDEAD_BEEF_u64;
+    buf.write(&mut first).expect("Failed to write(..)");
+    assert!(!buf.as_slice().is_zeroized());
+
+    buf.reset(8);
+    assert!(buf.as_slice().is_zeroized());
+
+    let mut second = 0x0102_u16;
+    buf.write(&mut second).expect("Failed to write(..)");
+    assert_eq!(&buf.as_slice()[..2], &0x0102_u16.to_ne_bytes());
+    assert!(buf.as_slice()[2..].is_zeroized());
+}
+
 // #[test]
 // fn test_codec_buffer_as_slice() {
 //     let capacity = 10;
@@ -173,6 +224,33 @@ fn test_codec_buffer_to_vec() {
     assert!(buf.is_zeroized());
 }
 
+#[test]
+fn test_codec_buffer_finalize_seals_contents() {
+    let mut buf = RedoubtCodecBuffer::with_capacity(4);
+    buf.write_slice(&mut [0xAAu8, 0xBB, 0xCC, 0xDD])
+        .expect("Failed to write_slice()");
+
+    let mut finalized = buf.finalize().expect("Failed to finalize()");
+
+    assert_eq!(finalized.len(), 4);
+    assert!(!finalized.is_empty());
+    assert_eq!(finalized.as_slice(), &[0xAA, 0xBB, 0xCC, 0xDD]);
+
+    let vec = finalized.export_as_vec();
+
+    assert_eq!(vec, vec![0xAA, 0xBB, 0xCC, 0xDD]);
+    assert!(finalized.as_slice().is_zeroized());
+}
+
+#[test]
+fn test_codec_buffer_finalize_empty() {
+    let buf = RedoubtCodecBuffer::default();
+    let finalized = buf.finalize().expect("Failed to finalize()");
+
+    assert!(finalized.is_empty());
+    assert_eq!(finalized.as_slice(), &[] as &[u8]);
+}
+
 /// Test pointer invariants after realloc_with_capacity
 /// This test catches potential UB from dangling pointers after reallocation
 #[test]
@@ -266,3 +344,81 @@ fn test_codec_buffer_realloc_pointer_invariants() {
         }
     }
 }
+
+#[test]
+fn test_codec_buffer_position_tracks_writes() {
+    let mut buf = RedoubtCodecBuffer::with_capacity(12);
+    assert_eq!(buf.position(), 0);
+
+    buf.write(&mut 0u64).expect("Failed to write(..)");
+    assert_eq!(buf.position(), 8);
+
+    buf.write_slice(&mut [1u8, 2, 3, 4])
+        .expect("Failed to write_slice(..)");
+    assert_eq!(buf.position(), 12);
+
+    buf.clear();
+    assert_eq!(buf.position(), 0);
+}
+
+#[test]
+fn test_codec_buffer_backfilled_frame_decodes() {
+    use std::io::Cursor;
+
+    use crate::frame_decoder::{FRAME_HEADER_LEN, FrameDecoder};
+    use crate::traits::{BytesRequired, Encode};
+
+    let mut value = vec![7u32, 8, 9];
+    let body_len = value
+        .encode_bytes_required()
+        .expect("Failed to encode_bytes_required()");
+    let mut buf = RedoubtCodecBuffer::with_capacity(FRAME_HEADER_LEN + body_len);
+
+    // Reserve the length slot, write the body, then backfill
+    let header_pos = buf.position();
+    buf.write(&mut 0u64).expect("Failed to write(..)");
+    value
+        .encode_into(&mut buf)
+        .expect("Failed to encode_into(..)");
+    let written = buf.position() - header_pos - FRAME_HEADER_LEN;
+    buf.write_len_prefix_at(header_pos, written)
+        .expect("Failed to write_len_prefix_at(..)");
+
+    assert_eq!(written, body_len);
+    assert_eq!(buf.position(), FRAME_HEADER_LEN + body_len);
+
+    let frames: Vec<Vec<u32>> =
+        FrameDecoder::<_, Vec<u32>>::new(Cursor::new(buf.export_as_vec()), 64)
+            .collect::<Result<_, _>>()
+            .expect("Failed to decode frames");
+
+    assert_eq!(frames, [vec![7, 8, 9]]);
+}
+
+#[test]
+fn test_codec_buffer_write_len_prefix_at_bounds() {
+    use crate::error::RedoubtCodecBufferError;
+
+    let mut buf = RedoubtCodecBuffer::with_capacity(16);
+    buf.write(&mut 0u64).expect("Failed to write(..)");
+    buf.write_slice(&mut [0u8; 4])
+        .expect("Failed to write_slice(..)");
+
+    // Within the written bytes
+    assert!(buf.write_len_prefix_at(0, 1).is_ok());
+    assert!(buf.write_len_prefix_at(4, 1).is_ok());
+
+    // Past the cursor, even though within capacity
+    assert_eq!(
+        buf.write_len_prefix_at(5, 1),
+        Err(RedoubtCodecBufferError::OutOfBounds)
+    );
+    // Overflowing position
+    assert_eq!(
+        buf.write_len_prefix_at(usize::MAX, 1),
+        Err(RedoubtCodecBufferError::OutOfBounds)
+    );
+    // Cursor unchanged by backfills
+    assert_eq!(buf.position(), 12);
+    assert_eq!(&buf.as_slice()[4..12], &1u64.to_le_bytes());
+}
@@ -7,9 +7,10 @@ use redoubt_zero::ZeroizationProbe;
 
 use crate::codec_buffer::RedoubtCodecBuffer;
 use crate::collections::helpers::{
-    bytes_required_sum, decode_fields, encode_fields, header_size, process_header,
-    to_bytes_required_dyn_ref, to_decode_dyn_mut, to_decode_zeroize_dyn_mut, to_encode_dyn_mut,
-    to_encode_zeroize_dyn_mut, write_header,
+    bytes_required_sum, decode_fields, decode_version, encode_fields, header_size,
+    process_collection_header, process_header, skip_collection, to_bytes_required_dyn_ref,
+    to_decode_dyn_mut, to_decode_zeroize_dyn_mut, to_encode_dyn_mut, to_encode_zeroize_dyn_mut,
+    unknown_discriminant, write_header, zeroize_fields,
 };
 use crate::error::{DecodeError, OverflowError, RedoubtCodecBufferError};
 use crate::support::test_utils::{RedoubtCodecTestBreaker, RedoubtCodecTestBreakerBehaviour};
@@ -145,6 +146,133 @@ fn test_process_header_ok() {
     assert_eq!(output_size, 1);
 }
 
+// process_collection_header
+fn forged_header(size: usize, body: &[u8]) -> Vec<u8> {
+    let mut bytes_required = header_size() + body.len();
+    let mut buf = RedoubtCodecBuffer::with_capacity(bytes_required);
+
+    buf.write(&mut { size }).expect("Failed to write size");
+    buf.write(&mut bytes_required)
+        .expect("Failed to write bytes_required");
+    buf.write_slice(&mut body.to_vec())
+        .expect("Failed to write body");
+
+    buf.export_as_vec()
+}
+
+#[test]
+fn test_process_collection_header_rejects_size_larger_than_body() {
+    let mut bytes = forged_header(1 << 40, &[0xAA; 16]);
+
+    let result = process_collection_header::<u8>(&mut bytes.as_mut_slice(), &mut 0);
+
+    assert!(matches!(result, Err(DecodeError::PreconditionViolated)));
+}
+
+#[test]
+fn test_process_collection_header_accepts_size_up_to_body() {
+    let mut bytes = forged_header(16, &[0xAA; 16]);
+    let mut output_size = 0;
+
+    let result = process_collection_header::<u8>(&mut bytes.as_mut_slice(), &mut output_size);
+
+    assert!(result.is_ok());
+    assert_eq!(output_size, 16);
+}
+
+#[test]
+fn test_process_collection_header_exempts_zero_sized_elements() {
+    let mut bytes = forged_header(1 << 40, &[]);
+    let mut output_size = 0;
+
+    let result = process_collection_header::<[u8; 0]>(&mut bytes.as_mut_slice(), &mut output_size);
+
+    assert!(result.is_ok());
+    assert_eq!(output_size, 1 << 40);
+}
+
+// skip_collection
+
+#[test]
+fn test_skip_collection_decodes_second() {
+    let mut first = vec![0xAAu8; 16];
+    let mut second = vec![1u8, 2, 3];
+    let first_len = first
+        .encode_bytes_required()
+        .expect("Failed to encode_bytes_required()");
+    let second_len = second
+        .encode_bytes_required()
+        .expect("Failed to encode_bytes_required()");
+
+    let mut buf = RedoubtCodecBuffer::with_capacity(first_len + second_len);
+    first
+        .encode_into(&mut buf)
+        .expect("Failed to encode_into(..)");
+    second
+        .encode_into(&mut buf)
+        .expect("Failed to encode_into(..)");
+
+    let mut read_buf = buf.as_mut_slice();
+    skip_collection(&mut read_buf).expect("Failed to skip_collection(..)");
+    assert_eq!(read_buf.len(), second_len);
+
+    let mut decoded: Vec<u8> = Vec::new();
+    decoded
+        .decode_from(&mut read_buf)
+        .expect("Failed to decode_from(..)");
+    assert_eq!(decoded, [1, 2, 3]);
+
+    // Skipped bytes were wiped
+    #[cfg(feature = "zeroize")]
+    assert!(buf.as_slice()[..first_len].is_zeroized());
+}
+
+#[test]
+fn test_skip_collection_buffer_too_small_for_header() {
+    let mut bytes = [0u8; 4];
+    let mut read_buf = bytes.as_mut_slice();
+
+    let result = skip_collection(&mut read_buf);
+
+    assert!(matches!(result, Err(DecodeError::PreconditionViolated)));
+    assert_eq!(read_buf.len(), 4);
+}
+
+#[test]
+fn test_skip_collection_bytes_required_exceeds_remaining() {
+    let mut data = vec![1u8, 2, 3, 4];
+    let len = data
+        .encode_bytes_required()
+        .expect("Failed to encode_bytes_required()");
+
+    let mut buf = RedoubtCodecBuffer::with_capacity(len);
+    data.encode_into(&mut buf)
+        .expect("Failed to encode_into(..)");
+
+    // Drop the last body byte
+    let mut read_buf = &mut buf.as_mut_slice()[..len - 1];
+    let result = skip_collection(&mut read_buf);
+
+    assert!(matches!(result, Err(DecodeError::PreconditionViolated)));
+    assert_eq!(read_buf.len(), len - 1);
+}
+
+#[test]
+fn test_skip_collection_bytes_required_lt_header_size() {
+    let mut buf = RedoubtCodecBuffer::with_capacity(header_size());
+    let mut size: usize = 0;
+    let mut bytes_required: usize = header_size() - 1;
+
+    buf.write(&mut size).expect("Failed to write size");
+    buf.write(&mut bytes_required)
+        .expect("Failed to write bytes_required");
+
+    let mut read_buf = buf.as_mut_slice();
+    let result = skip_collection(&mut read_buf);
+
+    assert!(matches!(result, Err(DecodeError::PreconditionViolated)));
+}
+
 // to_bytes_required_dyn_ref
 
 #[test]
@@ -267,6 +395,38 @@ fn test_bytes_required_sum_overflow() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_bytes_required_sum_overflow_checked_per_field() {
+    // Each size is individually valid and the first two fit together; only the
+    // third addition overflows. A wrapped running total would land back below
+    // usize::MAX and be accepted.
+    let half = usize::MAX / 2;
+    let fields = [
+        RedoubtCodecTestBreaker::new(
+            RedoubtCodecTestBreakerBehaviour::BytesRequiredReturn(half),
+            1,
+        ),
+        RedoubtCodecTestBreaker::new(
+            RedoubtCodecTestBreakerBehaviour::BytesRequiredReturn(half),
+            2,
+        ),
+        RedoubtCodecTestBreaker::new(
+            RedoubtCodecTestBreakerBehaviour::BytesRequiredReturn(half),
+            3,
+        ),
+    ];
+
+    let first_two = bytes_required_sum(fields[..2].iter().map(to_bytes_required_dyn_ref));
+    assert_eq!(first_two, Ok(usize::MAX - 1));
+
+    let result = bytes_required_sum(fields.iter().map(to_bytes_required_dyn_ref));
+
+    assert!(matches!(
+        result,
+        Err(OverflowError { reason }) if reason == "bytes_required_sum overflow"
+    ));
+}
+
 // encode_fields / decode_fields
 
 #[test]
@@ -369,6 +529,79 @@ fn perm_test_decode_fields_propagates_error_at_any_position() {
     });
 }
 
+// zeroize_fields
+
+#[test]
+fn test_zeroize_fields() {
+    let mut tb1 = RedoubtCodecTestBreaker::new(RedoubtCodecTestBreakerBehaviour::None, 100);
+    let mut tb2 = RedoubtCodecTestBreaker::new(RedoubtCodecTestBreakerBehaviour::None, 200);
+
+    let refs: [&mut dyn DecodeZeroize; 2] = [
+        to_decode_zeroize_dyn_mut(&mut tb1),
+        to_decode_zeroize_dyn_mut(&mut tb2),
+    ];
+    zeroize_fields(refs.into_iter());
+
+    #[cfg(feature = "zeroize")]
+    {
+        assert!(tb1.is_zeroized());
+        assert!(tb2.is_zeroized());
+    }
+}
+
+// unknown_discriminant
+
+#[test]
+fn test_unknown_discriminant() {
+    let mut data = [0xAAu8; 8];
+    let error = unknown_discriminant(&mut data.as_mut_slice());
+
+    assert_eq!(error, DecodeError::UnknownDiscriminant);
+
+    #[cfg(feature = "zeroize")]
+    assert!(data.is_zeroized());
+}
+
+// decode_version
+
+#[test]
+fn test_decode_version_ok() {
+    let mut bytes = [0x02, 0x00, 0xFF];
+    let mut slice = bytes.as_mut_slice();
+
+    decode_version(&mut slice, 2).expect("Failed to decode_version(..)");
+
+    assert_eq!(slice, [0xFF]);
+
+    #[cfg(feature = "zeroize")]
+    assert_eq!(bytes[..2], [0, 0]);
+}
+
+#[test]
+fn test_decode_version_mismatch() {
+    let mut bytes = [0x01, 0x00, 0xFF];
+    let result = decode_version(&mut bytes.as_mut_slice(), 2);
+
+    assert_eq!(
+        result,
+        Err(DecodeError::UnsupportedVersion {
+            found: 1,
+            expected: 2
+        })
+    );
+
+    #[cfg(feature = "zeroize")]
+    assert!(bytes.is_zeroized());
+}
+
+#[test]
+fn test_decode_version_truncated() {
+    let mut bytes = [0x01];
+    let result = decode_version(&mut bytes.as_mut_slice(), 1);
+
+    assert!(result.is_err());
+}
+
 // Roundtrip
 
 #[test]
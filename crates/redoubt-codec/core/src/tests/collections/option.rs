@@ -176,7 +176,7 @@ fn test_option_decode_from_propagates_invalid_size_value() {
     let result = opt.decode_from(&mut decode_buf.as_mut_slice());
 
     assert!(result.is_err());
-    assert!(matches!(result, Err(DecodeError::PreconditionViolated)));
+    assert!(matches!(result, Err(DecodeError::InvalidTag)));
 
     #[cfg(feature = "zeroize")]
     // Assert zeroization!
@@ -186,6 +186,34 @@ fn test_option_decode_from_propagates_invalid_size_value() {
     }
 }
 
+#[test]
+fn test_option_decode_from_invalid_tag_keeps_some_zeroized() {
+    let mut opt = Some(RedoubtCodecTestBreaker::new(
+        RedoubtCodecTestBreakerBehaviour::None,
+        42,
+    ));
+
+    let mut buf = RedoubtCodecBuffer::with_capacity(1024);
+    let mut size = usize::MAX;
+    let mut bytes_required = 2 * size_of::<usize>();
+    buf.write(&mut size)
+        .expect("Failed to write size to buffer");
+    buf.write(&mut bytes_required)
+        .expect("Failed to write bytes_required to buffer");
+
+    let mut decode_buf = buf.export_as_vec();
+    let result = opt.decode_from(&mut decode_buf.as_mut_slice());
+
+    assert!(matches!(result, Err(DecodeError::InvalidTag)));
+
+    #[cfg(feature = "zeroize")]
+    // Assert zeroization!
+    {
+        assert!(opt.is_zeroized());
+        assert!(decode_buf.is_zeroized());
+    }
+}
+
 // Note: Unlike Vec, we cannot test `test_option_decode_from_propagates_decode_err` because
 // Option always creates a fresh T::default() when decoding Some(_), so there's no way to
 // inject a RedoubtCodecTestBreaker with ForceDecodeError behaviour that would survive into
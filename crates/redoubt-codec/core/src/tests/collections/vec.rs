@@ -3,12 +3,12 @@
 // See LICENSE in the repository root for full license text.
 
 use redoubt_test_utils::{apply_permutation, index_permutations};
-use redoubt_zero::{FastZeroizable, ZeroizationProbe};
+use redoubt_zero::{FastZeroizable, ZeroizationProbe, ZeroizeMetadata};
 
 use crate::codec_buffer::RedoubtCodecBuffer;
 use crate::error::{DecodeError, EncodeError, OverflowError, RedoubtCodecBufferError};
 use crate::support::test_utils::{RedoubtCodecTestBreaker, RedoubtCodecTestBreakerBehaviour};
-use crate::traits::{BytesRequired, Decode, Encode};
+use crate::traits::{BytesRequired, Decode, DecodeSlice, Encode, EncodeSlice, PreAlloc};
 
 use super::utils::test_collection_varying_capacities;
 
@@ -161,6 +161,121 @@ fn test_vec_decode_propagates_decode_err() {
     }
 }
 
+#[test]
+fn test_vec_decode_rejects_inflated_size_before_prealloc() {
+    let mut vec = vec![0xAAu8; 16];
+    let bytes_required = vec
+        .encode_bytes_required()
+        .expect("Failed to get encode_bytes_required()");
+    let mut buf = RedoubtCodecBuffer::with_capacity(bytes_required);
+
+    vec.encode_into(&mut buf)
+        .expect("Failed to encode_into(..)");
+
+    // Claim 2^40 elements for a 16 byte body
+    let mut decode_buf = buf.export_as_vec();
+    decode_buf[..size_of::<usize>()].copy_from_slice(&(1usize << 40).to_ne_bytes());
+
+    let mut recovered: Vec<u8> = Vec::new();
+    let result = recovered.decode_from(&mut decode_buf.as_mut_slice());
+
+    assert!(matches!(result, Err(DecodeError::PreconditionViolated)));
+    assert_eq!(recovered.capacity(), 0);
+
+    #[cfg(feature = "zeroize")]
+    assert!(decode_buf.is_zeroized());
+}
+
+/// Mirrors a struct whose only field is `#[codec(default)]`: it encodes to
+/// zero bytes but is not zero-sized.
+#[derive(Debug, Default, PartialEq)]
+struct AllDefaultStruct {
+    cache: u64,
+}
+
+impl BytesRequired for AllDefaultStruct {
+    fn encode_bytes_required(&self) -> Result<usize, OverflowError> {
+        Ok(0)
+    }
+}
+
+impl Encode for AllDefaultStruct {
+    fn encode_into(&mut self, _buf: &mut RedoubtCodecBuffer) -> Result<(), EncodeError> {
+        self.fast_zeroize();
+        Ok(())
+    }
+}
+
+impl EncodeSlice for AllDefaultStruct {
+    fn encode_slice_into(
+        slice: &mut [Self],
+        buf: &mut RedoubtCodecBuffer,
+    ) -> Result<(), EncodeError> {
+        for elem in slice.iter_mut() {
+            elem.encode_into(buf)?;
+        }
+        Ok(())
+    }
+}
+
+impl Decode for AllDefaultStruct {
+    fn decode_from(&mut self, _buf: &mut &mut [u8]) -> Result<(), DecodeError> {
+        *self = Self::default();
+        Ok(())
+    }
+}
+
+impl DecodeSlice for AllDefaultStruct {
+    fn decode_slice_from(slice: &mut [Self], buf: &mut &mut [u8]) -> Result<(), DecodeError> {
+        for elem in slice.iter_mut() {
+            elem.decode_from(buf)?;
+        }
+        Ok(())
+    }
+}
+
+impl PreAlloc for AllDefaultStruct {
+    const ZERO_INIT: bool = false;
+
+    fn prealloc(&mut self, _size: usize) {}
+}
+
+impl ZeroizeMetadata for AllDefaultStruct {
+    const CAN_BE_BULK_ZEROIZED: bool = false;
+}
+
+impl FastZeroizable for AllDefaultStruct {
+    fn fast_zeroize(&mut self) {
+        self.cache.fast_zeroize();
+    }
+}
+
+#[test]
+fn test_vec_roundtrip_with_empty_encoding_elements() {
+    let mut vec: Vec<AllDefaultStruct> = (0..3).map(|_| AllDefaultStruct { cache: 7 }).collect();
+    let bytes_required = vec
+        .encode_bytes_required()
+        .expect("Failed to get encode_bytes_required()");
+    let mut buf = RedoubtCodecBuffer::with_capacity(bytes_required);
+
+    vec.encode_into(&mut buf)
+        .expect("Failed to encode_into(..)");
+
+    let mut decode_buf = buf.export_as_vec();
+    let mut recovered: Vec<AllDefaultStruct> = Vec::new();
+
+    recovered
+        .decode_from(&mut decode_buf.as_mut_slice())
+        .expect("Failed to decode_from(..)");
+
+    assert_eq!(recovered.len(), 3);
+    assert!(
+        recovered
+            .iter()
+            .all(|elem| *elem == AllDefaultStruct::default())
+    );
+}
+
 // Roundtrip
 
 #[test]
@@ -0,0 +1,71 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use redoubt_util::is_slice_zeroized;
+
+use crate::codec_buffer::RedoubtCodecBuffer;
+use crate::decode_exact::decode_exact;
+use crate::error::{DecodeBufferError, DecodeError};
+use crate::traits::{BytesRequired, Encode};
+
+fn encode<T: Encode + BytesRequired>(value: &mut T) -> Vec<u8> {
+    let bytes_required = value
+        .encode_bytes_required()
+        .expect("Failed to encode_bytes_required()");
+    let mut buf = RedoubtCodecBuffer::with_capacity(bytes_required);
+
+    value
+        .encode_into(&mut buf)
+        .expect("Failed to encode_into(..)");
+
+    buf.as_slice().to_vec()
+}
+
+// =============================================================================
+// decode_exact()
+// =============================================================================
+
+#[test]
+fn test_decode_exact_consumes_whole_buffer() {
+    let mut bytes = encode(&mut vec![1u32, 2, 3]);
+
+    let value: Vec<u32> = decode_exact(&mut bytes).expect("Failed to decode_exact(..)");
+
+    assert_eq!(value, [1, 2, 3]);
+    assert!(is_slice_zeroized(&bytes));
+}
+
+#[test]
+fn test_decode_exact_rejects_trailing_bytes() {
+    let mut bytes = encode(&mut 0xdead_beef_u64);
+    bytes.extend_from_slice(&[0xaa, 0xbb, 0xcc]);
+
+    let result = decode_exact::<u64>(&mut bytes);
+
+    assert_eq!(result, Err(DecodeError::TrailingBytes { remaining: 3 }));
+    assert!(is_slice_zeroized(&bytes));
+}
+
+#[test]
+fn test_decode_exact_zeroizes_buffer_on_decode_error() {
+    let mut bytes = encode(&mut 0xdead_beef_u64);
+    bytes.truncate(4);
+
+    let result = decode_exact::<u64>(&mut bytes);
+
+    assert_eq!(
+        result,
+        Err(DecodeError::DecodeBufferError(
+            DecodeBufferError::OutOfBounds
+        ))
+    );
+    assert!(is_slice_zeroized(&bytes));
+}
+
+#[test]
+fn test_trailing_bytes_display() {
+    let error = DecodeError::TrailingBytes { remaining: 7 };
+
+    assert_eq!(format!("{}", error), "TrailingBytes: 7 bytes remaining");
+}
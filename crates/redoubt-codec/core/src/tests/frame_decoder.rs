@@ -0,0 +1,172 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use std::io::Cursor;
+
+use redoubt_util::is_vec_fully_zeroized;
+
+use crate::codec_buffer::RedoubtCodecBuffer;
+use crate::error::{DecodeBufferError, DecodeError, FrameDecodeError};
+use crate::frame_decoder::FrameDecoder;
+use crate::traits::{BytesRequired, Encode};
+
+fn push_frame<T: Encode + BytesRequired>(stream: &mut Vec<u8>, value: &mut T) {
+    let bytes_required = value
+        .encode_bytes_required()
+        .expect("Failed to encode_bytes_required()");
+    let mut buf = RedoubtCodecBuffer::with_capacity(bytes_required);
+
+    value
+        .encode_into(&mut buf)
+        .expect("Failed to encode_into(..)");
+
+    stream.extend_from_slice(&(bytes_required as u64).to_le_bytes());
+    stream.extend_from_slice(buf.as_slice());
+}
+
+// =============================================================================
+// next()
+// =============================================================================
+
+#[test]
+fn test_frame_decoder_decodes_all_frames() {
+    let mut stream = Vec::new();
+    push_frame(&mut stream, &mut 1u64);
+    push_frame(&mut stream, &mut 2u64);
+    push_frame(&mut stream, &mut 3u64);
+
+    let values: Vec<u64> = FrameDecoder::<_, u64>::new(Cursor::new(stream), 64)
+        .collect::<Result<_, _>>()
+        .expect("Failed to decode frames");
+
+    assert_eq!(values, [1, 2, 3]);
+}
+
+#[test]
+fn test_frame_decoder_decodes_variable_length_frames() {
+    let mut stream = Vec::new();
+    push_frame(&mut stream, &mut vec![0xAAu8; 3]);
+    push_frame(&mut stream, &mut vec![0xBBu8; 17]);
+
+    let mut decoder = FrameDecoder::<_, Vec<u8>>::new(Cursor::new(stream), 64);
+
+    let first = decoder
+        .next()
+        .expect("Failed to get first frame")
+        .expect("Failed to decode first frame");
+    assert_eq!(first, [0xAA; 3]);
+
+    let second = decoder
+        .next()
+        .expect("Failed to get second frame")
+        .expect("Failed to decode second frame");
+    assert_eq!(second, [0xBB; 17]);
+
+    assert!(decoder.next().is_none());
+}
+
+#[test]
+fn test_frame_decoder_empty_stream() {
+    let mut decoder = FrameDecoder::<_, u64>::new(Cursor::new(Vec::new()), 64);
+
+    assert!(decoder.next().is_none());
+}
+
+#[test]
+fn test_frame_decoder_zeroizes_frame_buffer() {
+    let mut stream = Vec::new();
+    push_frame(&mut stream, &mut vec![0xCCu8; 32]);
+
+    let mut decoder = FrameDecoder::<_, Vec<u8>>::new(Cursor::new(stream), 64);
+
+    let value = decoder
+        .next()
+        .expect("Failed to get frame")
+        .expect("Failed to decode frame");
+    assert_eq!(value, [0xCC; 32]);
+
+    assert!(!decoder.__unsafe_get_frame().is_empty());
+    assert!(is_vec_fully_zeroized(decoder.__unsafe_get_frame()));
+}
+
+// =============================================================================
+// Errors
+// =============================================================================
+
+#[test]
+fn test_frame_decoder_truncated_header() {
+    let stream = vec![8u8, 0, 0];
+
+    let mut decoder = FrameDecoder::<_, u64>::new(Cursor::new(stream), 64);
+
+    assert!(matches!(
+        decoder.next(),
+        Some(Err(FrameDecodeError::TruncatedFrame))
+    ));
+    // Fused after the first error
+    assert!(decoder.next().is_none());
+}
+
+#[test]
+fn test_frame_decoder_truncated_body() {
+    let mut stream = Vec::new();
+    push_frame(&mut stream, &mut 1u64);
+    push_frame(&mut stream, &mut 2u64);
+    stream.truncate(stream.len() - 1);
+
+    let mut decoder = FrameDecoder::<_, u64>::new(Cursor::new(stream), 64);
+
+    assert!(matches!(decoder.next(), Some(Ok(1))));
+    assert!(matches!(
+        decoder.next(),
+        Some(Err(FrameDecodeError::TruncatedFrame))
+    ));
+    assert!(is_vec_fully_zeroized(decoder.__unsafe_get_frame()));
+    assert!(decoder.next().is_none());
+}
+
+#[test]
+fn test_frame_decoder_frame_too_large() {
+    let mut stream = Vec::new();
+    push_frame(&mut stream, &mut vec![0u8; 65]);
+
+    let mut decoder = FrameDecoder::<_, Vec<u8>>::new(Cursor::new(stream), 64);
+
+    assert!(matches!(
+        decoder.next(),
+        Some(Err(FrameDecodeError::FrameTooLarge))
+    ));
+}
+
+#[test]
+fn test_frame_decoder_trailing_bytes() {
+    let mut stream = Vec::new();
+    stream.extend_from_slice(&9u64.to_le_bytes());
+    stream.extend_from_slice(&[0xDD; 9]);
+
+    let mut decoder = FrameDecoder::<_, u64>::new(Cursor::new(stream), 64);
+
+    assert!(matches!(
+        decoder.next(),
+        Some(Err(FrameDecodeError::TrailingBytes))
+    ));
+    assert!(is_vec_fully_zeroized(decoder.__unsafe_get_frame()));
+}
+
+#[test]
+fn test_frame_decoder_propagates_decode_error() {
+    let mut stream = Vec::new();
+    stream.extend_from_slice(&4u64.to_le_bytes());
+    stream.extend_from_slice(&[0xEE; 4]);
+
+    let mut decoder = FrameDecoder::<_, u64>::new(Cursor::new(stream), 64);
+
+    assert!(matches!(
+        decoder.next(),
+        Some(Err(FrameDecodeError::DecodeError(
+            DecodeError::DecodeBufferError(DecodeBufferError::OutOfBounds)
+        )))
+    ));
+    assert!(is_vec_fully_zeroized(decoder.__unsafe_get_frame()));
+}
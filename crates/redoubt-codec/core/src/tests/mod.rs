@@ -9,7 +9,10 @@ mod blankets;
 mod codec_buffer;
 mod collections;
 mod decode_buffer;
+mod decode_exact;
 mod error;
+mod frame_decoder;
 mod primitives;
 mod support;
+mod tuples;
 mod zeroizing;
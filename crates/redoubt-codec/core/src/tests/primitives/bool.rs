@@ -2,8 +2,12 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // See LICENSE in the repository root for full license text.
 
+#[cfg(feature = "zeroize")]
+use redoubt_zero::ZeroizationProbe;
+
+use crate::codec_buffer::RedoubtCodecBuffer;
 use crate::error::{DecodeBufferError, DecodeError};
-use crate::traits::Decode;
+use crate::traits::{BytesRequired, Decode, Encode};
 
 use super::utils::test_all_pairs;
 
@@ -29,3 +33,84 @@ fn test_bool_decode_from_empty_buffer() {
         ))
     ));
 }
+
+// encode_into
+
+#[test]
+fn test_bool_encodes_as_single_byte() {
+    for (value, byte) in [(true, 0x01u8), (false, 0x00u8)] {
+        let mut value = value;
+        assert_eq!(value.encode_bytes_required(), Ok(1));
+
+        let mut buf = RedoubtCodecBuffer::with_capacity(1);
+        value
+            .encode_into(&mut buf)
+            .expect("Failed to encode_into(..)");
+
+        assert_eq!(buf.as_slice(), [byte]);
+
+        #[cfg(feature = "zeroize")]
+        assert!(!value);
+    }
+}
+
+// decode_from
+
+#[test]
+fn test_bool_decode_from_zeroizes_consumed_byte() {
+    for (byte, expected) in [(0x01u8, true), (0x00u8, false)] {
+        let mut value = !expected;
+        let mut bytes = [byte, 0xAA];
+        let mut slice = bytes.as_mut_slice();
+
+        value
+            .decode_from(&mut slice)
+            .expect("Failed to decode_from(..)");
+
+        assert_eq!(value, expected);
+        assert_eq!(slice, [0xAA]);
+
+        #[cfg(feature = "zeroize")]
+        assert_eq!(bytes, [0x00, 0xAA]);
+    }
+}
+
+#[test]
+fn test_bool_decode_from_invalid_byte() {
+    let mut value = true;
+    let mut bytes = [0x02u8, 0xAA];
+    let result = value.decode_from(&mut bytes.as_mut_slice());
+
+    assert!(matches!(result, Err(DecodeError::InvalidBool)));
+
+    #[cfg(feature = "zeroize")]
+    // Assert zeroization!
+    {
+        assert!(!value);
+        assert!(bytes.is_zeroized());
+    }
+}
+
+#[test]
+fn test_bool_vec_decode_from_invalid_byte() {
+    let mut original = vec![true, false, true];
+    let bytes_required = original
+        .encode_bytes_required()
+        .expect("Failed to get encode_bytes_required()");
+    let mut buf = RedoubtCodecBuffer::with_capacity(bytes_required);
+    original
+        .encode_into(&mut buf)
+        .expect("Failed to encode_into(..)");
+
+    let mut decode_buf = buf.export_as_vec();
+    let last = decode_buf.len() - 1;
+    decode_buf[last] = 0xFF;
+
+    let mut decoded: Vec<bool> = Vec::new();
+    let result = decoded.decode_from(&mut decode_buf.as_mut_slice());
+
+    assert!(matches!(result, Err(DecodeError::InvalidBool)));
+
+    #[cfg(feature = "zeroize")]
+    assert!(decode_buf.is_zeroized());
+}
@@ -0,0 +1,132 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use redoubt_alloc::RedoubtVec;
+#[cfg(feature = "zeroize")]
+use redoubt_zero::ZeroizationProbe;
+
+use crate::codec_buffer::RedoubtCodecBuffer;
+use crate::error::{DecodeError, EncodeError};
+use crate::support::test_utils::{RedoubtCodecTestBreaker, RedoubtCodecTestBreakerBehaviour};
+use crate::traits::{BytesRequired, Decode, Encode};
+
+#[test]
+fn test_tuple_u32_redoubt_vec_roundtrip() {
+    let mut vec = RedoubtVec::<u8>::new();
+    vec.extend_from_mut_slice(&mut [1, 2, 3, 4]);
+    let mut pair = (0xdead_beef_u32, vec);
+
+    let bytes_required = pair
+        .encode_bytes_required()
+        .expect("Failed to get encode_bytes_required()");
+    assert_eq!(
+        bytes_required,
+        4 + pair
+            .1
+            .encode_bytes_required()
+            .expect("Failed to get encode_bytes_required()")
+    );
+
+    let mut buf = RedoubtCodecBuffer::with_capacity(bytes_required);
+    pair.encode_into(&mut buf)
+        .expect("Failed to encode_into(..)");
+
+    let mut decode_buf = buf.export_as_vec();
+    let mut recovered = (0u32, RedoubtVec::<u8>::new());
+    recovered
+        .decode_from(&mut decode_buf.as_mut_slice())
+        .expect("Failed to decode_from(..)");
+
+    assert_eq!(recovered.0, 0xdead_beef);
+    assert_eq!(recovered.1.as_slice(), [1, 2, 3, 4]);
+
+    #[cfg(feature = "zeroize")]
+    // Assert zeroization!
+    {
+        assert!(buf.is_zeroized());
+        assert!(decode_buf.is_zeroized());
+        assert!(pair.0.is_zeroized());
+        assert!(pair.1.is_zeroized());
+    }
+}
+
+#[test]
+fn test_tuple_triple_roundtrip() {
+    let mut triple = (1u8, 2u8, 0x0102_0304_0506_0708_u64);
+
+    let bytes_required = triple
+        .encode_bytes_required()
+        .expect("Failed to get encode_bytes_required()");
+    assert_eq!(bytes_required, 10);
+
+    let mut buf = RedoubtCodecBuffer::with_capacity(bytes_required);
+    triple
+        .encode_into(&mut buf)
+        .expect("Failed to encode_into(..)");
+
+    let mut decode_buf = buf.export_as_vec();
+    let mut recovered = (0u8, 0u8, 0u64);
+    recovered
+        .decode_from(&mut decode_buf.as_mut_slice())
+        .expect("Failed to decode_from(..)");
+
+    assert_eq!(recovered, (1, 2, 0x0102_0304_0506_0708));
+
+    #[cfg(feature = "zeroize")]
+    assert!(decode_buf.is_zeroized());
+}
+
+#[test]
+fn test_tuple_encode_error_zeroizes_every_element() {
+    let mut tuple = (
+        RedoubtCodecTestBreaker::new(RedoubtCodecTestBreakerBehaviour::None, 1),
+        RedoubtCodecTestBreaker::new(RedoubtCodecTestBreakerBehaviour::ForceEncodeError, 2),
+        RedoubtCodecTestBreaker::new(RedoubtCodecTestBreakerBehaviour::None, 3),
+    );
+    let mut buf = RedoubtCodecBuffer::with_capacity(1024);
+
+    let result = tuple.encode_into(&mut buf);
+
+    assert!(matches!(result, Err(EncodeError::IntentionalEncodeError)));
+
+    #[cfg(feature = "zeroize")]
+    {
+        assert!(buf.is_zeroized());
+        assert!(tuple.0.is_zeroized());
+        assert!(tuple.1.is_zeroized());
+        assert!(tuple.2.is_zeroized());
+    }
+}
+
+#[test]
+fn test_tuple_decode_error_zeroizes_every_element() {
+    let mut tuple = (
+        RedoubtCodecTestBreaker::new(RedoubtCodecTestBreakerBehaviour::None, 1),
+        RedoubtCodecTestBreaker::new(RedoubtCodecTestBreakerBehaviour::None, 2),
+    );
+    let bytes_required = tuple
+        .encode_bytes_required()
+        .expect("Failed to get encode_bytes_required()");
+    let mut buf = RedoubtCodecBuffer::with_capacity(bytes_required);
+    tuple
+        .encode_into(&mut buf)
+        .expect("Failed to encode_into(..)");
+
+    let mut decode_buf = buf.export_as_vec();
+    let mut recovered = (
+        RedoubtCodecTestBreaker::new(RedoubtCodecTestBreakerBehaviour::None, 0),
+        RedoubtCodecTestBreaker::new(RedoubtCodecTestBreakerBehaviour::ForceDecodeError, 0),
+    );
+
+    let result = recovered.decode_from(&mut decode_buf.as_mut_slice());
+
+    assert!(matches!(result, Err(DecodeError::IntentionalDecodeError)));
+
+    #[cfg(feature = "zeroize")]
+    {
+        assert!(decode_buf.is_zeroized());
+        assert!(recovered.0.is_zeroized());
+        assert!(recovered.1.is_zeroized());
+    }
+}
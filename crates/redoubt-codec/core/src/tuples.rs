@@ -0,0 +1,61 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! Implementations for tuples up to arity 6.
+//!
+//! A tuple is encoded like a struct with the same fields: each element in
+//! order, with no header. On error every element (and the buffer) is zeroized,
+//! exactly as for derived structs.
+
+use crate::codec_buffer::RedoubtCodecBuffer;
+use crate::collections::helpers::{
+    bytes_required_sum, decode_fields, encode_fields, to_bytes_required_dyn_ref,
+    to_decode_zeroize_dyn_mut, to_encode_zeroize_dyn_mut,
+};
+use crate::error::{DecodeError, EncodeError, OverflowError};
+use crate::traits::{BytesRequired, Decode, DecodeZeroize, Encode, EncodeZeroize};
+
+macro_rules! impl_traits_for_tuples {
+    ($( $len:literal => ($($name:ident $idx:tt),+) ),* $(,)?) => {
+        $(
+            impl<$($name: BytesRequired),+> BytesRequired for ($($name,)+) {
+                #[inline(always)]
+                fn encode_bytes_required(&self) -> Result<usize, OverflowError> {
+                    let fields: [&dyn BytesRequired; $len] = [
+                        $( to_bytes_required_dyn_ref(&self.$idx) ),+
+                    ];
+                    bytes_required_sum(fields.into_iter())
+                }
+            }
+
+            impl<$($name: EncodeZeroize),+> Encode for ($($name,)+) {
+                #[inline(always)]
+                fn encode_into(&mut self, buf: &mut RedoubtCodecBuffer) -> Result<(), EncodeError> {
+                    let fields: [&mut dyn EncodeZeroize; $len] = [
+                        $( to_encode_zeroize_dyn_mut(&mut self.$idx) ),+
+                    ];
+                    encode_fields(fields.into_iter(), buf)
+                }
+            }
+
+            impl<$($name: DecodeZeroize),+> Decode for ($($name,)+) {
+                #[inline(always)]
+                fn decode_from(&mut self, buf: &mut &mut [u8]) -> Result<(), DecodeError> {
+                    let fields: [&mut dyn DecodeZeroize; $len] = [
+                        $( to_decode_zeroize_dyn_mut(&mut self.$idx) ),+
+                    ];
+                    decode_fields(fields.into_iter(), buf)
+                }
+            }
+        )*
+    };
+}
+
+impl_traits_for_tuples! {
+    2 => (A 0, B 1),
+    3 => (A 0, B 1, C 2),
+    4 => (A 0, B 1, C 2, D 3),
+    5 => (A 0, B 1, C 2, D 3, E 4),
+    6 => (A 0, B 1, C 2, D 3, E 4, F 5),
+}
@@ -23,14 +23,40 @@ mod tests;
 use proc_macro::TokenStream;
 use proc_macro_crate::{FoundCrate, crate_name};
 use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::format_ident;
 use quote::quote;
-use syn::{Attribute, Data, DeriveInput, Fields, Ident, Index, LitStr, Meta, parse_macro_input};
+use syn::{
+    Attribute, Data, DataEnum, DeriveInput, Fields, Ident, Index, LitInt, LitStr, Meta,
+    parse_macro_input,
+};
 
-/// Derives `BytesRequired`, `Encode`, and `Decode` for a struct.
+/// Derives `BytesRequired`, `Encode`, and `Decode` for a struct or enum.
+///
+/// # Enums
+///
+/// An enum is encoded as a `u32` little-endian discriminant (the variant
+/// index, in declaration order) followed by the fields of the active variant.
+/// Explicit discriminants (`A = 5`) are rejected.
+///
+/// When decoding into a value holding a different variant, the current
+/// variant's fields are zeroized and the new variant is built from
+/// `Default::default()` fields before being decoded in place, so every field
+/// of every variant must implement `Default`. Unknown discriminants fail with
+/// `DecodeError::UnknownDiscriminant`.
 ///
 /// # Attributes
 ///
 /// - `#[codec(default)]` on a field: Skip encoding/decoding, use `Default::default()`
+/// - `#[codec(skip_with = "path::to::fn")]` on a struct field: Skip encoding/decoding,
+///   then call `path::to::fn(&mut self)` once the other fields have been decoded, so
+///   the field can be rebuilt from them (e.g. a checksum).
+/// - `#[codec(pos = N)]` on a struct field: Encode/decode fields in ascending `pos`
+///   instead of declaration order, so fields can be reordered in source without
+///   changing the wire layout. A field without `pos` uses its declaration index;
+///   positions must be unique.
+/// - `#[codec(version = N)]` on a struct: Prefix the encoding with `N` as a `u16`
+///   little-endian. Decoding fails with `DecodeError::UnsupportedVersion` if the
+///   encoded version differs, leaving a single point to migrate persisted data.
 #[proc_macro_derive(RedoubtCodec, attributes(codec))]
 pub fn derive_redoubt_codec(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -81,34 +107,154 @@ fn has_codec_default(attrs: &[Attribute]) -> bool {
     })
 }
 
-fn expand(input: DeriveInput) -> Result<TokenStream2, TokenStream2> {
-    let struct_name = &input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+/// Parses the field-level `#[codec(skip_with = "path")]` attribute.
+fn codec_skip_with(attrs: &[Attribute]) -> syn::Result<Option<syn::Path>> {
+    let mut skip_with = None;
+
+    for attr in attrs.iter().filter(|attr| {
+        matches!(&attr.meta, Meta::List(meta_list)
+            if meta_list.path.is_ident("codec")
+            && meta_list.tokens.to_string().contains("skip_with"))
+    }) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip_with") {
+                let lit: LitStr = meta.value()?.parse()?;
+                skip_with = Some(lit.parse()?);
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(skip_with)
+}
+
+/// Parses the field-level `#[codec(pos = N)]` attribute.
+fn codec_pos(attrs: &[Attribute]) -> syn::Result<Option<(usize, LitInt)>> {
+    let mut pos = None;
+
+    for attr in attrs.iter().filter(|attr| {
+        matches!(&attr.meta, Meta::List(meta_list)
+            if meta_list.path.is_ident("codec")
+            && meta_list.tokens.to_string().contains("pos"))
+    }) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("pos") {
+                let lit: LitInt = meta.value()?.parse()?;
+                pos = Some((lit.base10_parse()?, lit));
+            } else if meta.input.peek(syn::Token![=]) {
+                // Other keys are handled by their own parsers
+                let _: syn::Expr = meta.value()?.parse()?;
+            }
 
+            Ok(())
+        })?;
+    }
+
+    Ok(pos)
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2, TokenStream2> {
     let root =
         find_root_with_candidates(&["redoubt-codec-core", "redoubt-codec", "redoubt::codec"]);
+    let version = codec_version(&input.attrs).map_err(|e| e.to_compile_error())?;
+
+    match &input.data {
+        Data::Struct(data) => expand_struct(&input, &data.fields, &root, version),
+        Data::Enum(_) if version.is_some() => Err(syn::Error::new_spanned(
+            &input.ident,
+            "#[codec(version = N)] is only supported on structs.",
+        )
+        .to_compile_error()),
+        Data::Enum(data) => expand_enum(&input, data, &root),
+        Data::Union(_) => Err(syn::Error::new_spanned(
+            &input.ident,
+            "RedoubtCodec can only be derived for structs and enums.",
+        )
+        .to_compile_error()),
+    }
+}
+
+/// Parses the type-level `#[codec(version = N)]` attribute into a `u16` literal.
+fn codec_version(attrs: &[Attribute]) -> syn::Result<Option<LitInt>> {
+    let mut version = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("codec")) {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("version") {
+                return Err(meta.error("unsupported codec attribute, expected `version = N`"));
+            }
+
+            let lit: LitInt = meta.value()?.parse()?;
+            let value: u16 = lit.base10_parse()?;
+            version = Some(LitInt::new(&format!("{}u16", value), lit.span()));
+
+            Ok(())
+        })?;
+    }
+
+    Ok(version)
+}
+
+fn expand_struct(
+    input: &DeriveInput,
+    fields: &Fields,
+    root: &TokenStream2,
+    version: Option<LitInt>,
+) -> Result<TokenStream2, TokenStream2> {
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
     // Get fields
-    let fields: Vec<(usize, &syn::Field)> = match &input.data {
-        Data::Struct(data) => match &data.fields {
-            Fields::Named(named) => named.named.iter().enumerate().collect(),
-            Fields::Unnamed(unnamed) => unnamed.unnamed.iter().enumerate().collect(),
-            Fields::Unit => vec![],
-        },
-        _ => {
-            return Err(syn::Error::new_spanned(
-                &input.ident,
-                "RedoubtCodec can only be derived for structs.",
-            )
-            .to_compile_error());
-        }
+    let fields: Vec<(usize, &syn::Field)> = match fields {
+        Fields::Named(named) => named.named.iter().enumerate().collect(),
+        Fields::Unnamed(unnamed) => unnamed.unnamed.iter().enumerate().collect(),
+        Fields::Unit => vec![],
     };
 
-    // Generate field references (filter out fields with #[codec(default)])
-    let (immut_refs, mut_refs): (Vec<TokenStream2>, Vec<TokenStream2>) = fields
+    // Fields rebuilt after decoding with #[codec(skip_with = "..")]
+    let mut skip_with = Vec::new();
+    for (i, f) in &fields {
+        if let Some(path) = codec_skip_with(&f.attrs).map_err(|e| e.to_compile_error())? {
+            skip_with.push((*i, path));
+        }
+    }
+
+    // Encoded fields (filter out fields with #[codec(default)] or skip_with),
+    // keyed by #[codec(pos = N)] or their declaration index
+    let mut encoded: Vec<(usize, usize, &syn::Field)> = Vec::new();
+    for (i, f) in &fields {
+        let pos = codec_pos(&f.attrs).map_err(|e| e.to_compile_error())?;
+        let skipped =
+            has_codec_default(&f.attrs) || skip_with.iter().any(|(skipped, _)| skipped == i);
+
+        if skipped {
+            if let Some((_, lit)) = pos {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "#[codec(pos = N)] has no effect on a field that is not encoded.",
+                )
+                .to_compile_error());
+            }
+            continue;
+        }
+
+        let key = pos.as_ref().map_or(*i, |(key, _)| *key);
+        if encoded.iter().any(|(k, _, _)| *k == key) {
+            return Err(
+                syn::Error::new_spanned(f, format!("duplicate codec position {}", key))
+                    .to_compile_error(),
+            );
+        }
+
+        encoded.push((key, *i, f));
+    }
+    encoded.sort_by_key(|(key, _, _)| *key);
+
+    // Generate field references in wire order
+    let (immut_refs, mut_refs): (Vec<TokenStream2>, Vec<TokenStream2>) = encoded
         .iter()
-        .filter(|(_, f)| !has_codec_default(&f.attrs))
-        .map(|(i, f)| {
+        .map(|(_, i, f)| {
             if let Some(ident) = &f.ident {
                 (quote! { &self.#ident }, quote! { &mut self.#ident })
             } else {
@@ -121,6 +267,28 @@ fn expand(input: DeriveInput) -> Result<TokenStream2, TokenStream2> {
     let len = immut_refs.len();
     let len_lit = syn::LitInt::new(&len.to_string(), Span::call_site());
 
+    let skip_with_paths: Vec<&syn::Path> = skip_with.iter().map(|(_, path)| path).collect();
+    let decode_tail = if skip_with_paths.is_empty() {
+        quote! { #root::collections::helpers::decode_fields(fields.into_iter(), buf) }
+    } else {
+        quote! {
+            #root::collections::helpers::decode_fields(fields.into_iter(), buf)?;
+            #( #skip_with_paths(self); )*
+            Ok(())
+        }
+    };
+
+    if let Some(version) = version {
+        return Ok(expand_versioned_struct(
+            input,
+            root,
+            &version,
+            &immut_refs,
+            &mut_refs,
+            &decode_tail,
+        ));
+    }
+
     let output = quote! {
         impl #impl_generics #root::BytesRequired for #struct_name #ty_generics #where_clause {
             fn encode_bytes_required(&self) -> Result<usize, #root::OverflowError> {
@@ -145,10 +313,297 @@ fn expand(input: DeriveInput) -> Result<TokenStream2, TokenStream2> {
                 let fields: [&mut dyn #root::DecodeZeroize; #len_lit] = [
                     #( #root::collections::helpers::to_decode_zeroize_dyn_mut(#mut_refs) ),*
                 ];
-                #root::collections::helpers::decode_fields(fields.into_iter(), buf)
+                #decode_tail
             }
         }
     };
 
     Ok(output)
 }
+
+/// Binding pattern, default constructor and encoded bindings of one enum variant.
+struct VariantParts {
+    /// Pattern binding the encoded fields, e.g. `Self::A { x: __field_x, .. }` or
+    /// `Self::B(__field0, _)`.
+    pattern: TokenStream2,
+    /// Pattern matching the variant without binding, e.g. `Self::A { .. }`.
+    wildcard: TokenStream2,
+    /// Expression building the variant from `Default::default()` fields.
+    default_ctor: TokenStream2,
+    /// Bindings of the encoded fields (excludes `#[codec(default)]` ones).
+    bindings: Vec<Ident>,
+}
+
+fn variant_parts(variant: &syn::Variant) -> VariantParts {
+    let name = &variant.ident;
+    let default = quote! { ::core::default::Default::default() };
+
+    match &variant.fields {
+        Fields::Named(named) => {
+            let all: Vec<&Ident> = named
+                .named
+                .iter()
+                .filter_map(|f| f.ident.as_ref())
+                .collect();
+            let fields: Vec<&Ident> = named
+                .named
+                .iter()
+                .filter(|f| !has_codec_default(&f.attrs))
+                .filter_map(|f| f.ident.as_ref())
+                .collect();
+            // Prefixed so field names cannot shadow the generated locals
+            let bindings: Vec<Ident> = fields
+                .iter()
+                .map(|ident| format_ident!("__field_{}", ident))
+                .collect();
+
+            VariantParts {
+                pattern: quote! { Self::#name { #( #fields: #bindings, )* .. } },
+                wildcard: quote! { Self::#name { .. } },
+                default_ctor: quote! { Self::#name { #( #all: #default ),* } },
+                bindings,
+            }
+        }
+        Fields::Unnamed(unnamed) => {
+            let mut bindings = Vec::new();
+            let slots: Vec<TokenStream2> = unnamed
+                .unnamed
+                .iter()
+                .enumerate()
+                .map(|(i, f)| {
+                    if has_codec_default(&f.attrs) {
+                        quote! { _ }
+                    } else {
+                        let binding = format_ident!("__field{}", i);
+                        let slot = quote! { #binding };
+                        bindings.push(binding);
+                        slot
+                    }
+                })
+                .collect();
+            let defaults = unnamed.unnamed.iter().map(|_| &default);
+
+            VariantParts {
+                pattern: quote! { Self::#name( #( #slots ),* ) },
+                wildcard: quote! { Self::#name(..) },
+                default_ctor: quote! { Self::#name( #( #defaults ),* ) },
+                bindings,
+            }
+        }
+        Fields::Unit => VariantParts {
+            pattern: quote! { Self::#name },
+            wildcard: quote! { Self::#name },
+            default_ctor: quote! { Self::#name },
+            bindings: vec![],
+        },
+    }
+}
+
+fn expand_enum(
+    input: &DeriveInput,
+    data: &DataEnum,
+    root: &TokenStream2,
+) -> Result<TokenStream2, TokenStream2> {
+    let enum_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    if data.variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "RedoubtCodec cannot be derived for enums without variants.",
+        )
+        .to_compile_error());
+    }
+
+    if let Some((_, expr)) = data.variants.iter().find_map(|v| v.discriminant.as_ref()) {
+        return Err(syn::Error::new_spanned(
+            expr,
+            "RedoubtCodec does not support explicit discriminants: the encoded discriminant is the variant index.",
+        )
+        .to_compile_error());
+    }
+
+    for field in data.variants.iter().flat_map(|v| v.fields.iter()) {
+        if codec_pos(&field.attrs)
+            .map_err(|e| e.to_compile_error())?
+            .is_some()
+        {
+            return Err(syn::Error::new_spanned(
+                field,
+                "#[codec(pos = N)] is only supported on struct fields.",
+            )
+            .to_compile_error());
+        }
+
+        if codec_skip_with(&field.attrs)
+            .map_err(|e| e.to_compile_error())?
+            .is_some()
+        {
+            return Err(syn::Error::new_spanned(
+                field,
+                "#[codec(skip_with = \"..\")] is only supported on struct fields.",
+            )
+            .to_compile_error());
+        }
+    }
+
+    let parts: Vec<VariantParts> = data.variants.iter().map(variant_parts).collect();
+    let discriminants: Vec<LitInt> = (0..parts.len())
+        .map(|i| LitInt::new(&format!("{}u32", i), Span::call_site()))
+        .collect();
+    let patterns: Vec<&TokenStream2> = parts.iter().map(|p| &p.pattern).collect();
+    let wildcards: Vec<&TokenStream2> = parts.iter().map(|p| &p.wildcard).collect();
+    let default_ctors: Vec<&TokenStream2> = parts.iter().map(|p| &p.default_ctor).collect();
+    let bindings: Vec<&Vec<Ident>> = parts.iter().map(|p| &p.bindings).collect();
+    let lens: Vec<LitInt> = parts
+        .iter()
+        .map(|p| LitInt::new(&(p.bindings.len() + 1).to_string(), Span::call_site()))
+        .collect();
+    let field_lens: Vec<LitInt> = parts
+        .iter()
+        .map(|p| LitInt::new(&p.bindings.len().to_string(), Span::call_site()))
+        .collect();
+
+    let output = quote! {
+        impl #impl_generics #root::BytesRequired for #enum_name #ty_generics #where_clause {
+            fn encode_bytes_required(&self) -> Result<usize, #root::OverflowError> {
+                match self {
+                    #(
+                        #patterns => {
+                            let discriminant: u32 = #discriminants;
+                            let fields: [&dyn #root::BytesRequired; #lens] = [
+                                #root::collections::helpers::to_bytes_required_dyn_ref(&discriminant),
+                                #( #root::collections::helpers::to_bytes_required_dyn_ref(#bindings) ),*
+                            ];
+                            #root::collections::helpers::bytes_required_sum(fields.into_iter())
+                        }
+                    )*
+                }
+            }
+        }
+
+        impl #impl_generics #root::Encode for #enum_name #ty_generics #where_clause {
+            fn encode_into(&mut self, buf: &mut #root::RedoubtCodecBuffer) -> Result<(), #root::EncodeError> {
+                match self {
+                    #(
+                        #patterns => {
+                            let mut discriminant: u32 = #discriminants;
+                            let fields: [&mut dyn #root::EncodeZeroize; #lens] = [
+                                #root::collections::helpers::to_encode_zeroize_dyn_mut(&mut discriminant),
+                                #( #root::collections::helpers::to_encode_zeroize_dyn_mut(#bindings) ),*
+                            ];
+                            #root::collections::helpers::encode_fields(fields.into_iter(), buf)
+                        }
+                    )*
+                }
+            }
+        }
+
+        impl #impl_generics #root::Decode for #enum_name #ty_generics #where_clause {
+            fn decode_from(&mut self, buf: &mut &mut [u8]) -> Result<(), #root::DecodeError> {
+                let zeroize_active = |value: &mut Self| match value {
+                    #(
+                        #patterns => {
+                            let fields: [&mut dyn #root::DecodeZeroize; #field_lens] = [
+                                #( #root::collections::helpers::to_decode_zeroize_dyn_mut(#bindings) ),*
+                            ];
+                            #root::collections::helpers::zeroize_fields(fields.into_iter());
+                        }
+                    )*
+                };
+
+                let mut discriminant = 0u32;
+                #root::collections::helpers::decode_fields(
+                    ::core::iter::once(#root::collections::helpers::to_decode_zeroize_dyn_mut(&mut discriminant)),
+                    buf,
+                )?;
+
+                let result = match discriminant {
+                    #(
+                        #discriminants => {
+                            if !matches!(self, #wildcards) {
+                                zeroize_active(self);
+                                *self = #default_ctors;
+                            }
+
+                            #[allow(unreachable_patterns)]
+                            match self {
+                                #patterns => {
+                                    let fields: [&mut dyn #root::DecodeZeroize; #field_lens] = [
+                                        #( #root::collections::helpers::to_decode_zeroize_dyn_mut(#bindings) ),*
+                                    ];
+                                    #root::collections::helpers::decode_fields(fields.into_iter(), buf)
+                                }
+                                _ => Err(#root::DecodeError::PreconditionViolated),
+                            }
+                        }
+                    )*
+                    _ => Err(#root::collections::helpers::unknown_discriminant(buf)),
+                };
+
+                #root::collections::helpers::zeroize_fields(::core::iter::once(
+                    #root::collections::helpers::to_decode_zeroize_dyn_mut(&mut discriminant),
+                ));
+
+                result
+            }
+        }
+    };
+
+    Ok(output)
+}
+
+/// Like the plain struct expansion, with a `u16` version encoded before the fields.
+fn expand_versioned_struct(
+    input: &DeriveInput,
+    root: &TokenStream2,
+    version: &LitInt,
+    immut_refs: &[TokenStream2],
+    mut_refs: &[TokenStream2],
+    decode_tail: &TokenStream2,
+) -> TokenStream2 {
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let len_lit = LitInt::new(&mut_refs.len().to_string(), Span::call_site());
+    let versioned_len_lit = LitInt::new(&(mut_refs.len() + 1).to_string(), Span::call_site());
+
+    quote! {
+        impl #impl_generics #root::BytesRequired for #struct_name #ty_generics #where_clause {
+            fn encode_bytes_required(&self) -> Result<usize, #root::OverflowError> {
+                let version: u16 = #version;
+                let fields: [&dyn #root::BytesRequired; #versioned_len_lit] = [
+                    #root::collections::helpers::to_bytes_required_dyn_ref(&version),
+                    #( #root::collections::helpers::to_bytes_required_dyn_ref(#immut_refs) ),*
+                ];
+                #root::collections::helpers::bytes_required_sum(fields.into_iter())
+            }
+        }
+
+        impl #impl_generics #root::Encode for #struct_name #ty_generics #where_clause {
+            fn encode_into(&mut self, buf: &mut #root::RedoubtCodecBuffer) -> Result<(), #root::EncodeError> {
+                let mut version: u16 = #version;
+                let fields: [&mut dyn #root::EncodeZeroize; #versioned_len_lit] = [
+                    #root::collections::helpers::to_encode_zeroize_dyn_mut(&mut version),
+                    #( #root::collections::helpers::to_encode_zeroize_dyn_mut(#mut_refs) ),*
+                ];
+                #root::collections::helpers::encode_fields(fields.into_iter(), buf)
+            }
+        }
+
+        impl #impl_generics #root::Decode for #struct_name #ty_generics #where_clause {
+            fn decode_from(&mut self, buf: &mut &mut [u8]) -> Result<(), #root::DecodeError> {
+                let fields: [&mut dyn #root::DecodeZeroize; #len_lit] = [
+                    #( #root::collections::helpers::to_decode_zeroize_dyn_mut(#mut_refs) ),*
+                ];
+
+                if let Err(e) = #root::collections::helpers::decode_version(buf, #version) {
+                    #root::collections::helpers::zeroize_fields(fields.into_iter());
+                    return Err(e);
+                }
+
+                #decode_tail
+            }
+        }
+    }
+}
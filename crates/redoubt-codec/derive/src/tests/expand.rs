@@ -133,11 +133,26 @@ fn snapshot_struct_with_generics_ok() {
 }
 
 #[test]
-fn snapshot_enum_fails() {
+fn snapshot_enum_ok() {
     let derive_input = parse_quote! {
         #[derive(RedoubtCodec)]
         enum Choice {
-            A,
+            Named { alpha: Vec<u8>, #[codec(default)] beta: u64 },
+            Tuple(u32, #[codec(default)] u8),
+            Unit,
+        }
+    };
+
+    let token_stream = expand(derive_input).expect("expand failed");
+    insta::assert_snapshot!(pretty(token_stream));
+}
+
+#[test]
+fn test_enum_with_explicit_discriminant_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtCodec)]
+        enum Choice {
+            A = 1,
             B,
         }
     };
@@ -146,6 +161,222 @@ fn snapshot_enum_fails() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_enum_without_variants_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtCodec)]
+        enum Never {}
+    };
+
+    let result = expand(derive_input);
+    assert!(result.is_err());
+}
+
+// #[codec(skip_with = "..")]
+
+#[test]
+fn snapshot_named_struct_with_skip_with() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtCodec)]
+        struct Data {
+            pub alpha: Vec<u8>,
+            #[codec(skip_with = "Data::recompute_checksum")]
+            pub checksum: u64,
+        }
+    };
+
+    let token_stream = expand(derive_input).expect("expand failed");
+    insta::assert_snapshot!(pretty(token_stream));
+}
+
+#[test]
+fn test_skip_with_non_string_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtCodec)]
+        struct Data {
+            #[codec(skip_with = 42)]
+            pub checksum: u64,
+        }
+    };
+
+    assert!(expand(derive_input).is_err());
+}
+
+#[test]
+fn test_skip_with_on_enum_field_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtCodec)]
+        enum Choice {
+            A {
+                #[codec(skip_with = "recompute")]
+                checksum: u64,
+            },
+        }
+    };
+
+    assert!(expand(derive_input).is_err());
+}
+
+// #[codec(pos = N)]
+
+#[test]
+fn snapshot_named_struct_with_pos() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtCodec)]
+        struct Data {
+            #[codec(pos = 2)]
+            pub gamma: u64,
+            pub alpha: Vec<u8>,
+            #[codec(pos = 0)]
+            pub beta: u32,
+        }
+    };
+
+    let token_stream = expand(derive_input).expect("expand failed");
+    insta::assert_snapshot!(pretty(token_stream));
+}
+
+#[test]
+fn test_pos_duplicate_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtCodec)]
+        struct Data {
+            pub alpha: u64,
+            #[codec(pos = 0)]
+            pub beta: u64,
+        }
+    };
+
+    assert!(expand(derive_input).is_err());
+}
+
+#[test]
+fn test_pos_non_integer_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtCodec)]
+        struct Data {
+            #[codec(pos = "first")]
+            pub alpha: u64,
+        }
+    };
+
+    assert!(expand(derive_input).is_err());
+}
+
+#[test]
+fn test_pos_on_default_field_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtCodec)]
+        struct Data {
+            pub alpha: u64,
+            #[codec(default, pos = 5)]
+            pub beta: u64,
+        }
+    };
+
+    assert!(expand(derive_input).is_err());
+}
+
+#[test]
+fn test_pos_on_enum_field_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtCodec)]
+        enum Choice {
+            A {
+                #[codec(pos = 1)]
+                value: u64,
+            },
+        }
+    };
+
+    assert!(expand(derive_input).is_err());
+}
+
+#[test]
+fn test_pos_ignores_skip_with_path_containing_pos() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtCodec)]
+        struct Data {
+            pub alpha: Vec<u8>,
+            #[codec(skip_with = "Data::reposition")]
+            pub checksum: u64,
+        }
+    };
+
+    assert!(expand(derive_input).is_ok());
+}
+
+// #[codec(version = N)]
+
+#[test]
+fn snapshot_named_struct_with_version() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtCodec)]
+        #[codec(version = 3)]
+        struct Data {
+            pub alpha: Vec<u8>,
+            #[codec(default)]
+            pub beta: u64,
+        }
+    };
+
+    let token_stream = expand(derive_input).expect("expand failed");
+    insta::assert_snapshot!(pretty(token_stream));
+}
+
+#[test]
+fn test_version_out_of_range_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtCodec)]
+        #[codec(version = 70000)]
+        struct Data {
+            pub alpha: Vec<u8>,
+        }
+    };
+
+    assert!(expand(derive_input).is_err());
+}
+
+#[test]
+fn test_unknown_type_level_codec_attr_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtCodec)]
+        #[codec(varint)]
+        struct Data {
+            pub alpha: Vec<u8>,
+        }
+    };
+
+    assert!(expand(derive_input).is_err());
+}
+
+#[test]
+fn test_version_on_enum_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtCodec)]
+        #[codec(version = 1)]
+        enum Choice {
+            A,
+        }
+    };
+
+    assert!(expand(derive_input).is_err());
+}
+
+#[test]
+fn test_union_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtCodec)]
+        union Bits {
+            a: u32,
+            b: f32,
+        }
+    };
+
+    let result = expand(derive_input);
+    assert!(result.is_err());
+}
+
 // #[codec(default)]
 
 #[test]
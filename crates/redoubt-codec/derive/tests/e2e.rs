@@ -199,4 +199,467 @@ mod tests {
             assert!(decode_buf.is_zeroized());
         }
     }
+
+    #[derive(RedoubtCodec, PartialEq, Debug, Clone)]
+    enum Secret {
+        Key { id: u32, bytes: Vec<u8> },
+        Pair(u64, Vec<u8>),
+        Empty,
+    }
+
+    fn roundtrip_into(original: &Secret, recovered: &mut Secret) {
+        let mut original_clone = original.clone();
+
+        let bytes_required = original
+            .encode_bytes_required()
+            .expect("Failed to get encode_bytes_required()");
+        let mut buf = RedoubtCodecBuffer::with_capacity(bytes_required);
+
+        original_clone
+            .encode_into(&mut buf)
+            .expect("Failed to encode_into(..)");
+
+        let mut decode_buf = buf.export_as_vec();
+        assert_eq!(decode_buf.len(), bytes_required);
+
+        recovered
+            .decode_from(&mut decode_buf.as_mut_slice())
+            .expect("Failed to decode_from(..)");
+
+        assert_eq!(recovered, original);
+
+        #[cfg(feature = "zeroize")]
+        {
+            assert!(buf.is_zeroized());
+            assert!(decode_buf.is_zeroized());
+        }
+    }
+
+    #[test]
+    fn test_derive_enum_roundtrip_each_variant() {
+        let variants = [
+            Secret::Key {
+                id: 7,
+                bytes: vec![1, 2, 3],
+            },
+            Secret::Pair(0xdeadbeef, vec![4, 5]),
+            Secret::Empty,
+        ];
+
+        for original in &variants {
+            let mut recovered = original.clone();
+            roundtrip_into(original, &mut recovered);
+        }
+    }
+
+    #[test]
+    fn test_derive_enum_decode_switches_variant() {
+        let original = Secret::Pair(42, vec![9; 16]);
+        let mut recovered = Secret::Key {
+            id: 1,
+            bytes: vec![0xAA; 8],
+        };
+
+        roundtrip_into(&original, &mut recovered);
+    }
+
+    #[test]
+    fn test_derive_enum_discriminant_is_variant_index() {
+        let mut value = Secret::Empty;
+        let mut buf = RedoubtCodecBuffer::with_capacity(4);
+
+        value
+            .encode_into(&mut buf)
+            .expect("Failed to encode_into(..)");
+
+        assert_eq!(buf.export_as_vec(), 2u32.to_le_bytes());
+    }
+
+    #[test]
+    fn test_derive_enum_unknown_discriminant() {
+        let mut decode_buf = vec![3u8, 0, 0, 0, 0xFF, 0xFF];
+        let mut recovered = Secret::Empty;
+
+        let result = recovered.decode_from(&mut decode_buf.as_mut_slice());
+
+        assert_eq!(
+            result,
+            Err(redoubt_codec_core::DecodeError::UnknownDiscriminant)
+        );
+
+        #[cfg(feature = "zeroize")]
+        assert!(decode_buf.is_zeroized());
+    }
+
+    // Field names matching locals of the generated code must still compile
+    #[derive(RedoubtCodec, PartialEq, Debug, Clone)]
+    enum Shadowing {
+        Frame {
+            buf: Vec<u8>,
+            fields: u32,
+            discriminant: u32,
+            value: u64,
+        },
+    }
+
+    #[test]
+    fn test_derive_enum_fields_named_like_generated_locals() {
+        let original = Shadowing::Frame {
+            buf: vec![1, 2, 3],
+            fields: 4,
+            discriminant: 5,
+            value: 6,
+        };
+        let mut original_clone = original.clone();
+
+        let bytes_required = original
+            .encode_bytes_required()
+            .expect("Failed to get encode_bytes_required()");
+        let mut buf = RedoubtCodecBuffer::with_capacity(bytes_required);
+
+        original_clone
+            .encode_into(&mut buf)
+            .expect("Failed to encode_into(..)");
+
+        let mut recovered = Shadowing::Frame {
+            buf: vec![],
+            fields: 0,
+            discriminant: 0,
+            value: 0,
+        };
+        let mut decode_buf = buf.export_as_vec();
+
+        recovered
+            .decode_from(&mut decode_buf.as_mut_slice())
+            .expect("Failed to decode_from(..)");
+
+        assert_eq!(recovered, original);
+    }
+
+    mod v1 {
+        use super::*;
+
+        #[derive(RedoubtCodec, Default, PartialEq, Debug, Clone)]
+        #[codec(version = 1)]
+        pub struct Credentials {
+            pub user: Vec<u8>,
+            pub secret: u64,
+        }
+    }
+
+    mod v2 {
+        use super::*;
+
+        #[derive(RedoubtCodec, Default, PartialEq, Debug, Clone)]
+        #[codec(version = 2)]
+        pub struct Credentials {
+            pub user: Vec<u8>,
+            pub secret: u64,
+        }
+    }
+
+    fn encode_v1(value: &v1::Credentials) -> Vec<u8> {
+        let bytes_required = value
+            .encode_bytes_required()
+            .expect("Failed to get encode_bytes_required()");
+        let mut buf = RedoubtCodecBuffer::with_capacity(bytes_required);
+
+        value
+            .clone()
+            .encode_into(&mut buf)
+            .expect("Failed to encode_into(..)");
+
+        buf.export_as_vec()
+    }
+
+    #[test]
+    fn test_derive_versioned_struct_roundtrip() {
+        let original = v1::Credentials {
+            user: vec![b'a'; 5],
+            secret: 0xfeed,
+        };
+
+        let mut decode_buf = encode_v1(&original);
+        assert_eq!(
+            decode_buf.len(),
+            2 + original.user.encode_bytes_required().unwrap() + 8
+        );
+        assert_eq!(decode_buf[..2], 1u16.to_le_bytes());
+
+        let mut recovered = v1::Credentials::default();
+        recovered
+            .decode_from(&mut decode_buf.as_mut_slice())
+            .expect("Failed to decode_from(..)");
+
+        assert_eq!(recovered, original);
+
+        // Version bytes are consumed and zeroized along with the rest
+        #[cfg(feature = "zeroize")]
+        assert!(decode_buf.is_zeroized());
+    }
+
+    #[test]
+    fn test_derive_versioned_struct_rejects_other_version() {
+        let original = v1::Credentials {
+            user: vec![b'b'; 3],
+            secret: 7,
+        };
+
+        let mut decode_buf = encode_v1(&original);
+        let mut recovered = v2::Credentials {
+            user: vec![0xAA; 4],
+            secret: 1,
+        };
+
+        let result = recovered.decode_from(&mut decode_buf.as_mut_slice());
+
+        assert_eq!(
+            result,
+            Err(redoubt_codec_core::DecodeError::UnsupportedVersion {
+                found: 1,
+                expected: 2
+            })
+        );
+
+        #[cfg(feature = "zeroize")]
+        {
+            assert!(decode_buf.is_zeroized());
+            assert!(recovered.user.is_zeroized());
+            assert_eq!(recovered.secret, 0);
+        }
+    }
+
+    mod nested {
+        use redoubt_alloc::RedoubtVec;
+        use redoubt_zero::RedoubtZero;
+
+        use super::*;
+
+        #[derive(RedoubtCodec, RedoubtZero, Default, PartialEq, Debug)]
+        pub struct Leaf {
+            pub id: u32,
+            pub bytes: RedoubtVec<u8>,
+        }
+
+        #[derive(RedoubtCodec, RedoubtZero, Default, PartialEq, Debug)]
+        pub struct Middle {
+            pub leaf: Leaf,
+            pub tags: Vec<u16>,
+        }
+
+        #[derive(RedoubtCodec, RedoubtZero, Default, PartialEq, Debug)]
+        pub struct Root {
+            pub version: u8,
+            pub middle: Middle,
+            pub trailer: u64,
+        }
+
+        pub fn sample() -> Root {
+            Root {
+                version: 3,
+                middle: Middle {
+                    leaf: Leaf {
+                        id: 0xdeadbeef,
+                        bytes: RedoubtVec::from_mut_slice(&mut [0xAB; 37]),
+                    },
+                    tags: vec![1, 2, 3],
+                },
+                trailer: u64::MAX,
+            }
+        }
+    }
+
+    #[test]
+    fn test_derive_three_level_nested_struct_roundtrip() {
+        let mut original = nested::sample();
+
+        let leaf_bytes = original
+            .middle
+            .leaf
+            .encode_bytes_required()
+            .expect("Failed to get encode_bytes_required()");
+        let middle_bytes = original
+            .middle
+            .encode_bytes_required()
+            .expect("Failed to get encode_bytes_required()");
+        let bytes_required = original
+            .encode_bytes_required()
+            .expect("Failed to get encode_bytes_required()");
+
+        // Derived structs add no header of their own: each level is exactly
+        // the sum of its fields.
+        assert_eq!(
+            leaf_bytes,
+            4 + original.middle.leaf.bytes.encode_bytes_required().unwrap()
+        );
+        assert_eq!(
+            middle_bytes,
+            leaf_bytes + original.middle.tags.encode_bytes_required().unwrap()
+        );
+        assert_eq!(bytes_required, 1 + middle_bytes + 8);
+
+        // Encode
+        let mut buf = RedoubtCodecBuffer::with_capacity(bytes_required);
+        original
+            .encode_into(&mut buf)
+            .expect("Failed to encode_into(..)");
+
+        assert_eq!(buf.as_slice().len(), bytes_required);
+
+        // Assert zeroization!
+        #[cfg(feature = "zeroize")]
+        {
+            assert_eq!(original.version, 0);
+            assert_eq!(original.trailer, 0);
+            assert_eq!(original.middle.leaf.id, 0);
+            assert!(original.middle.leaf.bytes.is_zeroized());
+            assert!(original.middle.tags.is_zeroized());
+        }
+
+        // Decode
+        let mut decode_buf = buf.export_as_vec();
+        let mut recovered = nested::Root::default();
+        recovered
+            .decode_from(&mut decode_buf.as_mut_slice())
+            .expect("Failed to decode_from(..)");
+
+        assert_eq!(recovered, nested::sample());
+
+        #[cfg(feature = "zeroize")]
+        assert!(decode_buf.is_zeroized());
+    }
+
+    #[derive(RedoubtCodec, Default, PartialEq, Debug, Clone)]
+    struct Checksummed {
+        pub payload: Vec<u8>,
+        #[codec(skip_with = "Checksummed::recompute_checksum")]
+        pub checksum: u64,
+    }
+
+    impl Checksummed {
+        fn recompute_checksum(&mut self) {
+            self.checksum = self.payload.iter().map(|b| u64::from(*b)).sum();
+        }
+    }
+
+    #[test]
+    fn test_derive_skip_with_recomputes_field_after_decode() {
+        let mut original = Checksummed {
+            payload: vec![1, 2, 3, 4],
+            checksum: 0,
+        };
+        original.recompute_checksum();
+        let expected = original.clone();
+
+        let bytes_required = original
+            .encode_bytes_required()
+            .expect("Failed to get encode_bytes_required()");
+
+        // The skipped field is not on the wire
+        assert_eq!(
+            bytes_required,
+            original.payload.encode_bytes_required().unwrap()
+        );
+
+        let mut buf = RedoubtCodecBuffer::with_capacity(bytes_required);
+        original
+            .encode_into(&mut buf)
+            .expect("Failed to encode_into(..)");
+
+        let mut decode_buf = buf.export_as_vec();
+        let mut recovered = Checksummed::default();
+        recovered
+            .decode_from(&mut decode_buf.as_mut_slice())
+            .expect("Failed to decode_from(..)");
+
+        assert_eq!(recovered.checksum, 10);
+        assert_eq!(recovered, expected);
+    }
+
+    #[test]
+    fn test_derive_skip_with_not_called_on_decode_error() {
+        let mut recovered = Checksummed {
+            payload: vec![],
+            checksum: 0xAA,
+        };
+        let mut truncated = [0u8; 3];
+        let result = recovered.decode_from(&mut truncated.as_mut_slice());
+
+        assert!(result.is_err());
+        assert_eq!(recovered.checksum, 0xAA);
+    }
+
+    mod declared {
+        use super::*;
+
+        #[derive(RedoubtCodec, Default, PartialEq, Debug, Clone)]
+        pub struct Record {
+            pub id: u32,
+            pub key: Vec<u8>,
+            pub counter: u64,
+        }
+    }
+
+    mod reordered {
+        use super::*;
+
+        #[derive(RedoubtCodec, Default, PartialEq, Debug, Clone)]
+        pub struct Record {
+            #[codec(pos = 2)]
+            pub counter: u64,
+            #[codec(pos = 0)]
+            pub id: u32,
+            #[codec(pos = 1)]
+            pub key: Vec<u8>,
+        }
+    }
+
+    #[test]
+    fn test_derive_pos_keeps_wire_layout() {
+        let mut declared = declared::Record {
+            id: 7,
+            key: vec![0xAB; 4],
+            counter: 0xfeed,
+        };
+        let mut reordered = reordered::Record {
+            counter: 0xfeed,
+            id: 7,
+            key: vec![0xAB; 4],
+        };
+
+        let mut declared_buf = RedoubtCodecBuffer::with_capacity(
+            declared
+                .encode_bytes_required()
+                .expect("Failed to get encode_bytes_required()"),
+        );
+        declared
+            .encode_into(&mut declared_buf)
+            .expect("Failed to encode_into(..)");
+
+        let mut reordered_buf = RedoubtCodecBuffer::with_capacity(
+            reordered
+                .encode_bytes_required()
+                .expect("Failed to get encode_bytes_required()"),
+        );
+        reordered
+            .encode_into(&mut reordered_buf)
+            .expect("Failed to encode_into(..)");
+
+        let mut bytes = declared_buf.export_as_vec();
+        assert_eq!(bytes, reordered_buf.export_as_vec());
+
+        let mut recovered = reordered::Record::default();
+        recovered
+            .decode_from(&mut bytes.as_mut_slice())
+            .expect("Failed to decode_from(..)");
+
+        assert_eq!(
+            recovered,
+            reordered::Record {
+                counter: 0xfeed,
+                id: 7,
+                key: vec![0xAB; 4],
+            }
+        );
+    }
 }
@@ -5,8 +5,10 @@
 //! redoubt_guard - Process-level memory protection via prctl and setrlimit
 //!
 //! Provides a one-time initialization of `PR_SET_DUMPABLE` and `RLIMIT_CORE`
-//! to prevent core dumps and ptrace attachment. Uses a spin lock to ensure
-//! only one thread performs the initialization.
+//! to prevent core dumps and ptrace attachment. On Windows, the same
+//! initialization makes a best-effort `SetProcessMitigationPolicy` call
+//! instead. Uses a spin lock to ensure only one thread performs the
+//! initialization.
 //!
 //! ## License
 //!
@@ -34,6 +36,38 @@ pub struct GuardStatus {
     /// Redundant core dump prevention (limits size to 0 bytes).
     /// Does NOT block ptrace. More difficult to revert than prctl.
     pub rlimit_succeeded: bool,
+
+    /// Whether `SetProcessMitigationPolicy(ProcessExtensionPointDisablePolicy)`
+    /// succeeded (Windows only).
+    ///
+    /// Blocks legacy extension points (AppInit DLLs, Winsock LSPs, global
+    /// window hooks) commonly used to inject code into the process. Cannot be
+    /// reverted for the lifetime of the process.
+    pub mitigation_succeeded: bool,
+}
+
+impl GuardStatus {
+    /// Returns true if at least one protection is active.
+    pub fn is_protected(&self) -> bool {
+        self.prctl_succeeded || self.rlimit_succeeded || self.mitigation_succeeded
+    }
+}
+
+impl core::fmt::Display for GuardStatus {
+    /// Renders a summary such as `prctl: ok, rlimit: failed, mitigation: failed`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fn outcome(succeeded: bool) -> &'static str {
+            if succeeded { "ok" } else { "failed" }
+        }
+
+        write!(
+            f,
+            "prctl: {}, rlimit: {}, mitigation: {}",
+            outcome(self.prctl_succeeded),
+            outcome(self.rlimit_succeeded),
+            outcome(self.mitigation_succeeded)
+        )
+    }
 }
 
 /// Initialization state: not yet attempted
@@ -46,12 +80,15 @@ const STATE_DONE: u8 = 2;
 static INIT_STATE: AtomicU8 = AtomicU8::new(STATE_UNINIT);
 static PRCTL_SUCCEEDED: AtomicU8 = AtomicU8::new(0);
 static RLIMIT_SUCCEEDED: AtomicU8 = AtomicU8::new(0);
+static MITIGATION_SUCCEEDED: AtomicU8 = AtomicU8::new(0);
 
 /// Returns the status of process-level memory protections.
 ///
-/// **Side effect on first call:** Attempts to initialize both:
+/// **Side effect on first call:** Attempts to initialize:
 /// - `prctl(PR_SET_DUMPABLE, 0)` - prevents core dumps and ptrace attachment
 /// - `setrlimit(RLIMIT_CORE, 0)` - limits core dump size to 0 bytes
+/// - `SetProcessMitigationPolicy(ProcessExtensionPointDisablePolicy)` (Windows) -
+///   blocks legacy DLL injection vectors
 ///
 /// Subsequent calls return the cached result immediately without side effects.
 ///
@@ -64,6 +101,9 @@ static RLIMIT_SUCCEEDED: AtomicU8 = AtomicU8::new(0);
 /// use redoubt_guard::guard_status;
 ///
 /// let status = guard_status();
+/// if !status.is_protected() {
+///     println!("no process-level protection: {status}");
+/// }
 /// if status.prctl_succeeded {
 ///     println!("prctl protection active");
 /// }
@@ -74,17 +114,119 @@ static RLIMIT_SUCCEEDED: AtomicU8 = AtomicU8::new(0);
 #[inline]
 pub fn guard_status() -> GuardStatus {
     // Fast path: already initialized
-    if INIT_STATE.load(Ordering::Acquire) == STATE_DONE {
-        return GuardStatus {
-            prctl_succeeded: PRCTL_SUCCEEDED.load(Ordering::Relaxed) != 0,
-            rlimit_succeeded: RLIMIT_SUCCEEDED.load(Ordering::Relaxed) != 0,
-        };
+    if let Some(status) = guard_status_peek() {
+        return status;
     }
 
     init_slow();
     guard_status()
 }
 
+/// Returns the cached guard status without triggering initialization.
+///
+/// Returns `None` if [`guard_status`] has not completed its first call yet,
+/// and `Some` with the cached result otherwise. Never performs syscalls, so
+/// it is safe to call from health checks that must not change process state.
+///
+/// # Example
+///
+/// ```
+/// use redoubt_guard::{guard_status, guard_status_peek};
+///
+/// let _ = guard_status();
+/// assert!(guard_status_peek().is_some());
+/// ```
+#[inline]
+pub fn guard_status_peek() -> Option<GuardStatus> {
+    if INIT_STATE.load(Ordering::Acquire) != STATE_DONE {
+        return None;
+    }
+
+    Some(GuardStatus {
+        prctl_succeeded: PRCTL_SUCCEEDED.load(Ordering::Relaxed) != 0,
+        rlimit_succeeded: RLIMIT_SUCCEEDED.load(Ordering::Relaxed) != 0,
+        mitigation_succeeded: MITIGATION_SUCCEEDED.load(Ordering::Relaxed) != 0,
+    })
+}
+
+/// Sets the process dumpable flag via `prctl(PR_SET_DUMPABLE)`.
+///
+/// Returns `true` if the call succeeded. Always returns `false` on non-Linux
+/// platforms.
+///
+/// Unlike [`guard_status`], this is not a one-time operation: it can be called
+/// at any point, before or after initialization. On success,
+/// [`GuardStatus::prctl_succeeded`] is updated to reflect the new state.
+/// If called before the first [`guard_status`], that initialization still runs
+/// and (with the `guard` feature) marks the process non-dumpable again.
+///
+/// # Warning
+///
+/// `set_dumpable(true)` weakens process protection: it re-enables core dumps
+/// and ptrace attachment by same-user processes. Only use it for controlled
+/// teardown, once sensitive data has been zeroized.
+pub fn set_dumpable(enabled: bool) -> bool {
+    let succeeded = prctl_set_dumpable(enabled);
+
+    if succeeded {
+        PRCTL_SUCCEEDED.store(!enabled as u8, Ordering::Relaxed);
+    }
+
+    succeeded
+}
+
+#[cfg(target_os = "linux")]
+fn prctl_set_dumpable(enabled: bool) -> bool {
+    unsafe { libc::prctl(libc::PR_SET_DUMPABLE, enabled as libc::c_int, 0, 0, 0) == 0 }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn prctl_set_dumpable(_enabled: bool) -> bool {
+    // prctl is Linux-only
+    false
+}
+
+/// Excludes a memory region from core dumps via `madvise(MADV_DONTDUMP)`.
+///
+/// A finer-grained alternative to `PR_SET_DUMPABLE` for a specific sensitive
+/// mapping. Returns `true` if the call succeeded. Always returns `false` on
+/// non-Linux platforms.
+///
+/// # Alignment
+///
+/// `ptr` must be page-aligned and the region must belong to a mapping (e.g.
+/// one returned by `mmap`), otherwise the kernel rejects the call and this
+/// returns `false`. `len` is rounded up to a whole number of pages, so the
+/// advice covers every page the region touches.
+pub fn exclude_from_core_dump(ptr: *mut u8, len: usize) -> bool {
+    madvise_dump(ptr, len, false)
+}
+
+/// Re-includes a memory region in core dumps via `madvise(MADV_DODUMP)`.
+///
+/// Reverts [`exclude_from_core_dump`]. Same alignment requirements apply.
+/// Always returns `false` on non-Linux platforms.
+pub fn include_in_core_dump(ptr: *mut u8, len: usize) -> bool {
+    madvise_dump(ptr, len, true)
+}
+
+#[cfg(target_os = "linux")]
+fn madvise_dump(ptr: *mut u8, len: usize, dump: bool) -> bool {
+    let advice = if dump {
+        libc::MADV_DODUMP
+    } else {
+        libc::MADV_DONTDUMP
+    };
+
+    unsafe { libc::madvise(ptr as *mut libc::c_void, len, advice) == 0 }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn madvise_dump(_ptr: *mut u8, _len: usize, _dump: bool) -> bool {
+    // MADV_DONTDUMP/MADV_DODUMP are Linux-only
+    false
+}
+
 #[cold]
 #[inline(never)]
 fn init_slow() {
@@ -100,9 +242,11 @@ fn init_slow() {
             {
                 let prctl_ok = prctl_set_not_dumpable();
                 let rlimit_ok = setrlimit_core_zero();
+                let mitigation_ok = disable_extension_points();
 
                 PRCTL_SUCCEEDED.store(prctl_ok as u8, Ordering::Relaxed);
                 RLIMIT_SUCCEEDED.store(rlimit_ok as u8, Ordering::Relaxed);
+                MITIGATION_SUCCEEDED.store(mitigation_ok as u8, Ordering::Relaxed);
 
                 // Delay STATE_DONE to allow other threads to enter init_slow()
                 // and hit the spin loop for coverage. Without this, initialization
@@ -117,6 +261,7 @@ fn init_slow() {
             {
                 PRCTL_SUCCEEDED.store(0, Ordering::Relaxed);
                 RLIMIT_SUCCEEDED.store(0, Ordering::Relaxed);
+                MITIGATION_SUCCEEDED.store(0, Ordering::Relaxed);
 
                 INIT_STATE.store(STATE_DONE, Ordering::Release);
             }
@@ -130,16 +275,9 @@ fn init_slow() {
     }
 }
 
-#[cfg(all(target_os = "linux", feature = "guard"))]
+#[cfg(feature = "guard")]
 fn prctl_set_not_dumpable() -> bool {
-    // PR_SET_DUMPABLE = 4, 0 = not dumpable
-    unsafe { libc::prctl(libc::PR_SET_DUMPABLE, 0, 0, 0, 0) == 0 }
-}
-
-#[cfg(all(not(target_os = "linux"), feature = "guard"))]
-fn prctl_set_not_dumpable() -> bool {
-    // prctl is Linux-only
-    false
+    prctl_set_dumpable(false)
 }
 
 #[cfg(all(target_os = "linux", feature = "guard"))]
@@ -156,3 +294,33 @@ fn setrlimit_core_zero() -> bool {
     // setrlimit RLIMIT_CORE is Linux-specific
     false
 }
+
+#[cfg(all(windows, feature = "guard"))]
+fn disable_extension_points() -> bool {
+    use windows_sys::Win32::System::SystemServices::{
+        PROCESS_MITIGATION_EXTENSION_POINT_DISABLE_POLICY,
+        PROCESS_MITIGATION_EXTENSION_POINT_DISABLE_POLICY_0,
+    };
+    use windows_sys::Win32::System::Threading::{
+        ProcessExtensionPointDisablePolicy, SetProcessMitigationPolicy,
+    };
+
+    // Bit 0: DisableExtensionPoints
+    let policy = PROCESS_MITIGATION_EXTENSION_POINT_DISABLE_POLICY {
+        Anonymous: PROCESS_MITIGATION_EXTENSION_POINT_DISABLE_POLICY_0 { Flags: 1 },
+    };
+
+    unsafe {
+        SetProcessMitigationPolicy(
+            ProcessExtensionPointDisablePolicy,
+            &policy as *const _ as *const core::ffi::c_void,
+            core::mem::size_of_val(&policy),
+        ) != 0
+    }
+}
+
+#[cfg(all(not(windows), feature = "guard"))]
+fn disable_extension_points() -> bool {
+    // Process mitigation policies are Windows-specific
+    false
+}
@@ -12,32 +12,103 @@ fn test_guard_status_is_idempotent() {
     let _ = crate::guard_status();
 }
 
+#[test]
+fn test_guard_status_is_protected_and_display() {
+    let cases = [
+        (
+            false,
+            false,
+            false,
+            false,
+            "prctl: failed, rlimit: failed, mitigation: failed",
+        ),
+        (
+            true,
+            false,
+            false,
+            true,
+            "prctl: ok, rlimit: failed, mitigation: failed",
+        ),
+        (
+            false,
+            true,
+            false,
+            true,
+            "prctl: failed, rlimit: ok, mitigation: failed",
+        ),
+        (
+            false,
+            false,
+            true,
+            true,
+            "prctl: failed, rlimit: failed, mitigation: ok",
+        ),
+        (
+            true,
+            true,
+            true,
+            true,
+            "prctl: ok, rlimit: ok, mitigation: ok",
+        ),
+    ];
+
+    for (prctl_succeeded, rlimit_succeeded, mitigation_succeeded, protected, display) in cases {
+        let status = crate::GuardStatus {
+            prctl_succeeded,
+            rlimit_succeeded,
+            mitigation_succeeded,
+        };
+
+        assert_eq!(status.is_protected(), protected);
+        assert_eq!(format!("{status}"), display);
+    }
+}
+
+#[cfg(windows)]
+#[test]
+fn test_guard_status_is_cached_on_windows() {
+    let first = crate::guard_status();
+
+    assert_eq!(crate::guard_status(), first);
+    assert_eq!(crate::guard_status_peek(), Some(first));
+
+    // prctl and setrlimit are Linux-only
+    assert!(!first.prctl_succeeded);
+    assert!(!first.rlimit_succeeded);
+    assert_eq!(first.mitigation_succeeded, cfg!(feature = "guard"));
+}
+
+#[cfg(target_os = "linux")]
+fn reset_state() {
+    use core::sync::atomic::Ordering;
+    crate::INIT_STATE.store(crate::STATE_UNINIT, Ordering::SeqCst);
+    crate::PRCTL_SUCCEEDED.store(0, Ordering::SeqCst);
+    crate::RLIMIT_SUCCEEDED.store(0, Ordering::SeqCst);
+    crate::MITIGATION_SUCCEEDED.store(0, Ordering::SeqCst);
+}
+
+/// Runs an ignored test as a subprocess and returns its exit code.
+#[cfg(target_os = "linux")]
+fn run_test_as_subprocess(test_name: &str) -> Option<i32> {
+    let exe = std::env::current_exe().expect("Failed to get current exe");
+    let status = std::process::Command::new(exe)
+        .args([
+            "--exact",
+            test_name,
+            "--ignored",
+            "--test-threads=1",
+            "--nocapture",
+        ])
+        .status()
+        .expect("Failed to run subprocess");
+    status.code()
+}
+
 #[cfg(all(target_os = "linux", feature = "guard"))]
 mod linux {
     use serial_test::serial;
 
-    fn reset_state() {
-        use core::sync::atomic::Ordering;
-        crate::INIT_STATE.store(crate::STATE_UNINIT, Ordering::SeqCst);
-        crate::PRCTL_SUCCEEDED.store(0, Ordering::SeqCst);
-        crate::RLIMIT_SUCCEEDED.store(0, Ordering::SeqCst);
-    }
-
-    /// Runs an ignored test as a subprocess and returns its exit code.
-    fn run_test_as_subprocess(test_name: &str) -> Option<i32> {
-        let exe = std::env::current_exe().expect("Failed to get current exe");
-        let status = std::process::Command::new(exe)
-            .args([
-                "--exact",
-                test_name,
-                "--ignored",
-                "--test-threads=1",
-                "--nocapture",
-            ])
-            .status()
-            .expect("Failed to run subprocess");
-        status.code()
-    }
+    use super::{reset_state, run_test_as_subprocess};
 
     // Subprocess test: prctl blocked by seccomp
     #[test]
@@ -136,6 +207,117 @@ mod linux {
     }
 }
 
+#[cfg(target_os = "linux")]
+mod peek {
+    use serial_test::serial;
+
+    use super::{reset_state, run_test_as_subprocess};
+
+    #[test]
+    #[ignore]
+    fn subprocess_test_peek_before_and_after_init() {
+        reset_state();
+
+        assert_eq!(crate::guard_status_peek(), None);
+        // Peeking must not initialize
+        assert_eq!(crate::guard_status_peek(), None);
+
+        let status = crate::guard_status();
+
+        assert_eq!(crate::guard_status_peek(), Some(status));
+
+        std::process::exit(0);
+    }
+
+    #[test]
+    #[serial(seccomp)]
+    fn test_peek_before_and_after_init() {
+        let exit_code =
+            run_test_as_subprocess("tests::peek::subprocess_test_peek_before_and_after_init");
+        assert_eq!(exit_code, Some(0), "Subprocess should exit with 0");
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod dumpable {
+    use serial_test::serial;
+
+    fn get_dumpable() -> i32 {
+        unsafe { libc::prctl(libc::PR_GET_DUMPABLE, 0, 0, 0, 0) }
+    }
+
+    #[test]
+    #[serial(seccomp)]
+    fn test_set_dumpable_toggles_flag() {
+        let original = get_dumpable();
+        let _ = crate::guard_status();
+
+        assert!(crate::set_dumpable(false), "Failed to set_dumpable(false)");
+        assert_eq!(get_dumpable(), 0);
+        assert!(crate::guard_status().prctl_succeeded);
+
+        assert!(crate::set_dumpable(true), "Failed to set_dumpable(true)");
+        assert_eq!(get_dumpable(), 1);
+        assert!(!crate::guard_status().prctl_succeeded);
+
+        // Restore the flag for the rest of the test process
+        assert!(crate::set_dumpable(original == 1));
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod core_dump {
+    struct Mapping {
+        ptr: *mut u8,
+        len: usize,
+    }
+
+    impl Mapping {
+        fn new() -> Self {
+            let len = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+            let ptr = unsafe {
+                libc::mmap(
+                    core::ptr::null_mut(),
+                    len,
+                    libc::PROT_READ | libc::PROT_WRITE,
+                    libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+                    -1,
+                    0,
+                )
+            };
+            assert_ne!(ptr, libc::MAP_FAILED, "Failed to mmap(..)");
+
+            Self {
+                ptr: ptr as *mut u8,
+                len,
+            }
+        }
+    }
+
+    impl Drop for Mapping {
+        fn drop(&mut self) {
+            unsafe { libc::munmap(self.ptr as *mut libc::c_void, self.len) };
+        }
+    }
+
+    #[test]
+    fn test_exclude_from_core_dump_page_aligned() {
+        let mapping = Mapping::new();
+
+        assert!(crate::exclude_from_core_dump(mapping.ptr, mapping.len));
+        assert!(crate::include_in_core_dump(mapping.ptr, mapping.len));
+    }
+
+    #[test]
+    fn test_exclude_from_core_dump_unaligned_fails() {
+        let mapping = Mapping::new();
+        let unaligned = unsafe { mapping.ptr.add(1) };
+
+        assert!(!crate::exclude_from_core_dump(unaligned, mapping.len - 1));
+        assert!(!crate::include_in_core_dump(unaligned, mapping.len - 1));
+    }
+}
+
 #[cfg(not(target_os = "linux"))]
 mod non_linux {
     #[test]
@@ -148,4 +330,15 @@ mod non_linux {
             "rlimit not available on non-Linux"
         );
     }
+
+    #[test]
+    fn test_core_dump_advice_unavailable() {
+        let mut page = [0u8; 64];
+
+        assert!(!crate::exclude_from_core_dump(
+            page.as_mut_ptr(),
+            page.len()
+        ));
+        assert!(!crate::include_in_core_dump(page.as_mut_ptr(), page.len()));
+    }
 }
@@ -10,4 +10,12 @@ pub enum HkdfError {
     /// Requested output length exceeds maximum (255 * HashLen)
     #[error("requested output length exceeds maximum (255 * HashLen)")]
     OutputTooLong,
+
+    /// HKDF-Expand-Label label exceeds 255 bytes once prefixed with "tls13 "
+    #[error("label exceeds maximum length")]
+    LabelTooLong,
+
+    /// HKDF-Expand-Label context exceeds 255 bytes
+    #[error("context exceeds maximum length")]
+    ContextTooLong,
 }
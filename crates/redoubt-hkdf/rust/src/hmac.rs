@@ -94,3 +94,86 @@ impl HmacSha256State {
         self.inner_hash.fast_zeroize();
     }
 }
+
+/// Multi-part HMAC-SHA256 (RFC 2104).
+///
+/// Authenticates segmented input without concatenating it first. The inner
+/// SHA-256 state is keyed with `K ⊕ ipad` on construction; `K ⊕ opad` is kept
+/// until [`finalize`](Self::finalize). All state is zeroized on drop.
+///
+/// # Example
+///
+/// ```rust
+/// use redoubt_hkdf_rust::HmacSha256;
+///
+/// let mut mac = HmacSha256::new(b"key");
+/// mac.update(b"hello ");
+/// mac.update(b"world");
+///
+/// let mut tag = [0u8; 32];
+/// mac.finalize(&mut tag);
+/// ```
+#[derive(RedoubtZero)]
+#[fast_zeroize(drop)]
+pub struct HmacSha256 {
+    /// K ⊕ opad (0x5c repeated)
+    k_opad: [u8; BLOCK_LEN],
+    /// SHA256 state for inner hash, already keyed with K ⊕ ipad
+    sha_inner: Sha256State,
+    /// SHA256 state for outer hash computation
+    sha_outer: Sha256State,
+    /// Inner hash result: SHA256(K ⊕ ipad || message)
+    inner_hash: [u8; HASH_LEN],
+
+    __sentinel: ZeroizeOnDropSentinel,
+}
+
+impl HmacSha256 {
+    /// Creates a MAC keyed with `key`. Keys longer than 64 bytes are hashed first.
+    pub fn new(key: &[u8]) -> Self {
+        let mut mac = Self {
+            k_opad: [0u8; BLOCK_LEN],
+            sha_inner: Sha256State::new(),
+            sha_outer: Sha256State::new(),
+            inner_hash: [0u8; HASH_LEN],
+            __sentinel: ZeroizeOnDropSentinel::default(),
+        };
+
+        // Stage the effective key in k_opad, then derive K ⊕ ipad in place,
+        // absorb it and flip it to K ⊕ opad (0x36 ^ 0x5c = 0x6a)
+        if key.len() > BLOCK_LEN {
+            mac.sha_outer.hash(key, &mut mac.inner_hash);
+            mac.k_opad[..HASH_LEN].copy_from_slice(&mac.inner_hash);
+            mac.inner_hash.fast_zeroize();
+        } else {
+            mac.k_opad[..key.len()].copy_from_slice(key);
+        }
+
+        for byte in mac.k_opad.iter_mut() {
+            *byte ^= 0x36;
+        }
+
+        mac.sha_inner.update(&mac.k_opad);
+
+        for byte in mac.k_opad.iter_mut() {
+            *byte ^= 0x36 ^ 0x5c;
+        }
+
+        mac
+    }
+
+    /// Absorbs the next segment of the message.
+    pub fn update(&mut self, data: &[u8]) {
+        self.sha_inner.update(data);
+    }
+
+    /// Writes the tag to `out`, consuming (and zeroizing) the MAC.
+    pub fn finalize(mut self, out: &mut [u8; HASH_LEN]) {
+        self.sha_inner.finalize(&mut self.inner_hash);
+
+        self.sha_outer.reset();
+        self.sha_outer.update(&self.k_opad);
+        self.sha_outer.update(&self.inner_hash);
+        self.sha_outer.finalize(out);
+    }
+}
@@ -26,3 +26,4 @@ mod sha256;
 mod word32;
 
 pub use backend::RustBackend;
+pub use hmac::HmacSha256;
@@ -0,0 +1,97 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! Tests for the multi-part HmacSha256 API.
+
+use redoubt_hkdf_core::HkdfApi;
+use redoubt_zero::{AssertZeroizeOnDrop, FastZeroizable, ZeroizationProbe};
+
+use crate::{HmacSha256, RustBackend};
+
+fn one_shot(key: &[u8], data: &[u8]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    RustBackend.api_hmac_sha256(key, data, &mut out);
+    out
+}
+
+fn chunked(key: &[u8], data: &[u8], chunk_size: usize) -> [u8; 32] {
+    let mut mac = HmacSha256::new(key);
+
+    for chunk in data.chunks(chunk_size) {
+        mac.update(chunk);
+    }
+
+    let mut out = [0u8; 32];
+    mac.finalize(&mut out);
+    out
+}
+
+// =============================================================================
+// update() / finalize()
+// =============================================================================
+
+#[test]
+fn test_hmac_chunked_matches_one_shot() {
+    let data: [u8; 1000] = core::array::from_fn(|i| (i * 7) as u8);
+
+    for key_len in [0, 1, 32, 64, 65, 131] {
+        let key = [0xA5u8; 131];
+        let key = &key[..key_len];
+        let expected = one_shot(key, &data);
+
+        for chunk_size in [1, 3, 63, 64, 65, 200, 1000] {
+            assert_eq!(chunked(key, &data, chunk_size), expected);
+        }
+    }
+}
+
+#[test]
+fn test_hmac_empty_message() {
+    let mac = HmacSha256::new(b"key");
+
+    let mut out = [0u8; 32];
+    mac.finalize(&mut out);
+
+    assert_eq!(out, one_shot(b"key", &[]));
+}
+
+#[test]
+fn test_hmac_rfc4231_test_case_2() {
+    let mut mac = HmacSha256::new(b"Jefe");
+    mac.update(b"what do ya want ");
+    mac.update(b"for nothing?");
+
+    let mut out = [0u8; 32];
+    mac.finalize(&mut out);
+
+    assert_eq!(
+        out,
+        [
+            0x5b, 0xdc, 0xc1, 0x46, 0xbf, 0x60, 0x75, 0x4e, 0x6a, 0x04, 0x24, 0x26, 0x08, 0x95,
+            0x75, 0xc7, 0x5a, 0x00, 0x3f, 0x08, 0x9d, 0x27, 0x39, 0x83, 0x9d, 0xec, 0x58, 0xb9,
+            0x64, 0xec, 0x38, 0x43,
+        ]
+    );
+}
+
+// =============================================================================
+// Zeroization
+// =============================================================================
+
+#[test]
+fn test_hmac_zeroize() {
+    let mut mac = HmacSha256::new(b"key");
+    mac.update(b"data");
+
+    mac.fast_zeroize();
+
+    assert!(mac.is_zeroized());
+}
+
+#[test]
+fn test_hmac_assert_zeroize_on_drop() {
+    let mac = HmacSha256::new(b"key");
+
+    mac.assert_zeroize_on_drop();
+}
@@ -3,6 +3,7 @@
 // See LICENSE in the repository root for full license text.
 
 mod backend;
+mod hmac;
 mod sha256_state;
 mod word32;
 mod wycheproof;
@@ -0,0 +1,101 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! HKDF-Expand-Label per RFC 8446 Section 7.1
+
+use redoubt_zero::FastZeroizable;
+
+use crate::{HASH_LEN, HkdfError, MAX_OUTPUT_LEN, hmac_sha256};
+
+const LABEL_PREFIX: &[u8] = b"tls13 ";
+
+/// Maximum label length in bytes, excluding the `"tls13 "` prefix.
+pub const MAX_LABEL_LEN: usize = 255 - LABEL_PREFIX.len();
+
+/// Maximum context length in bytes.
+pub const MAX_CONTEXT_LEN: usize = 255;
+
+/// Largest encoded `HkdfLabel`: length (2) || label (1 + 255) || context (1 + 255)
+const MAX_INFO_LEN: usize = 2 + 1 + 255 + 1 + MAX_CONTEXT_LEN;
+
+/// TLS 1.3 HKDF-Expand-Label (RFC 8446 Section 7.1).
+///
+/// Expands `prk` into `out` using the encoded `HkdfLabel` as the HKDF info:
+///
+/// ```text
+/// struct {
+///     uint16 length = out.len();
+///     opaque label<7..255> = "tls13 " + label;
+///     opaque context<0..255> = context;
+/// } HkdfLabel;
+/// ```
+///
+/// The expand message buffer and every `T(i)` block are zeroized before
+/// returning.
+///
+/// # Errors
+///
+/// - [`HkdfError::LabelTooLong`] if `label` is longer than [`MAX_LABEL_LEN`]
+/// - [`HkdfError::ContextTooLong`] if `context` is longer than [`MAX_CONTEXT_LEN`]
+/// - [`HkdfError::OutputTooLong`] if `out` is longer than [`MAX_OUTPUT_LEN`]
+pub fn hkdf_expand_label(
+    prk: &[u8; HASH_LEN],
+    label: &str,
+    context: &[u8],
+    out: &mut [u8],
+) -> Result<(), HkdfError> {
+    if label.len() > MAX_LABEL_LEN {
+        return Err(HkdfError::LabelTooLong);
+    }
+
+    if context.len() > MAX_CONTEXT_LEN {
+        return Err(HkdfError::ContextTooLong);
+    }
+
+    if out.len() > MAX_OUTPUT_LEN {
+        return Err(HkdfError::OutputTooLong);
+    }
+
+    // Expand message: T(i-1) || HkdfLabel || counter
+    let mut msg = [0u8; HASH_LEN + MAX_INFO_LEN + 1];
+    let mut t = [0u8; HASH_LEN];
+
+    let info_len = {
+        let info = &mut msg[HASH_LEN..];
+        let label_len = LABEL_PREFIX.len() + label.len();
+        let mut pos = 0;
+
+        // Bounds checked above: out.len() <= MAX_OUTPUT_LEN < u16::MAX, label_len <= 255
+        info[pos..pos + 2].copy_from_slice(&(out.len() as u16).to_be_bytes());
+        pos += 2;
+        info[pos] = label_len as u8;
+        pos += 1;
+        info[pos..pos + LABEL_PREFIX.len()].copy_from_slice(LABEL_PREFIX);
+        pos += LABEL_PREFIX.len();
+        info[pos..pos + label.len()].copy_from_slice(label.as_bytes());
+        pos += label.len();
+        info[pos] = context.len() as u8;
+        pos += 1;
+        info[pos..pos + context.len()].copy_from_slice(context);
+        pos + context.len()
+    };
+
+    let counter_pos = HASH_LEN + info_len;
+
+    for (i, chunk) in out.chunks_mut(HASH_LEN).enumerate() {
+        // T(0) is empty, so the first block skips the T(i-1) prefix
+        let start = if i == 0 { HASH_LEN } else { 0 };
+
+        msg[counter_pos] = (i + 1) as u8;
+        hmac_sha256(prk, &msg[start..=counter_pos], &mut t);
+
+        chunk.copy_from_slice(&t[..chunk.len()]);
+        msg[..HASH_LEN].copy_from_slice(&t);
+    }
+
+    t.fast_zeroize();
+    msg.fast_zeroize();
+
+    Ok(())
+}
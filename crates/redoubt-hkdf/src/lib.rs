@@ -19,11 +19,29 @@
 #[cfg(test)]
 mod tests;
 
+mod expand_label;
+
+pub use expand_label::{MAX_CONTEXT_LEN, MAX_LABEL_LEN, hkdf_expand_label};
 pub use redoubt_hkdf_core::{HkdfApi, HkdfError};
+/// Multi-part HMAC-SHA256. Always backed by the pure Rust implementation.
+pub use redoubt_hkdf_rust::HmacSha256;
+
+/// SHA-256 digest length in bytes.
+pub const HASH_LEN: usize = 32;
+
+/// SHA-256 block length in bytes.
+pub const BLOCK_LEN: usize = 64;
+
+/// Maximum HKDF-Expand output length (255 * [`HASH_LEN`]).
+pub const MAX_OUTPUT_LEN: usize = 255 * HASH_LEN;
 
 /// HKDF-SHA256 key derivation (RFC 5869).
 ///
 /// Automatically selects the best backend for the current platform.
+///
+/// # Errors
+///
+/// Returns [`HkdfError::OutputTooLong`] if `okm` is longer than [`MAX_OUTPUT_LEN`].
 pub fn hkdf(salt: &[u8], ikm: &[u8], info: &[u8], okm: &mut [u8]) -> Result<(), HkdfError> {
     #[cfg(all(
         feature = "asm",
@@ -51,3 +69,36 @@ pub fn hkdf(salt: &[u8], ikm: &[u8], info: &[u8], okm: &mut [u8]) -> Result<(),
         redoubt_hkdf_rust::RustBackend.api_hkdf(salt, ikm, info, okm)
     }
 }
+
+/// HMAC-SHA256 (RFC 2104) over a single `message`.
+///
+/// Automatically selects the best backend for the current platform. Keys
+/// longer than [`BLOCK_LEN`] are hashed first. The padded key blocks and the
+/// inner hash are zeroized before returning.
+pub fn hmac_sha256(key: &[u8], message: &[u8], out: &mut [u8; HASH_LEN]) {
+    #[cfg(all(
+        feature = "asm",
+        target_arch = "x86_64",
+        any(target_os = "linux", target_os = "macos")
+    ))]
+    {
+        redoubt_hkdf_x86::X86Backend.api_hmac_sha256(key, message, out)
+    }
+
+    #[cfg(all(feature = "asm", target_arch = "aarch64"))]
+    {
+        redoubt_hkdf_arm::ArmBackend.api_hmac_sha256(key, message, out)
+    }
+
+    #[cfg(not(any(
+        all(
+            feature = "asm",
+            target_arch = "x86_64",
+            any(target_os = "linux", target_os = "macos")
+        ),
+        all(feature = "asm", target_arch = "aarch64")
+    )))]
+    {
+        redoubt_hkdf_rust::RustBackend.api_hmac_sha256(key, message, out)
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use crate::{
+    HASH_LEN, HkdfError, MAX_CONTEXT_LEN, MAX_LABEL_LEN, MAX_OUTPUT_LEN, hkdf_expand_label,
+};
+
+// RFC 8448 Section 3 (Simple 1-RTT Handshake)
+const EARLY_SECRET: [u8; HASH_LEN] = [
+    0x33, 0xad, 0x0a, 0x1c, 0x60, 0x7e, 0xc0, 0x3b, 0x09, 0xe6, 0xcd, 0x98, 0x93, 0x68, 0x0c, 0xe2,
+    0x10, 0xad, 0xf3, 0x00, 0xaa, 0x1f, 0x26, 0x60, 0xe1, 0xb2, 0x2e, 0x10, 0xf1, 0x70, 0xf9, 0x2a,
+];
+
+const SERVER_HANDSHAKE_TRAFFIC_SECRET: [u8; HASH_LEN] = [
+    0xb6, 0x7b, 0x7d, 0x69, 0x0c, 0xc1, 0x6c, 0x4e, 0x75, 0xe5, 0x42, 0x13, 0xcb, 0x2d, 0x37, 0xb4,
+    0xe9, 0xc9, 0x12, 0xbc, 0xde, 0xd9, 0x10, 0x5d, 0x42, 0xbe, 0xfd, 0x59, 0xd3, 0x91, 0xad, 0x38,
+];
+
+// =============================================================================
+// hkdf_expand_label() - RFC 8448 vectors
+// =============================================================================
+
+#[test]
+fn test_hkdf_expand_label_derived_secret() {
+    // Derive-Secret(early_secret, "derived", "") uses the empty transcript hash
+    let empty_hash = [
+        0xe3, 0xb0, 0xc4, 0x42, 0x98, 0xfc, 0x1c, 0x14, 0x9a, 0xfb, 0xf4, 0xc8, 0x99, 0x6f, 0xb9,
+        0x24, 0x27, 0xae, 0x41, 0xe4, 0x64, 0x9b, 0x93, 0x4c, 0xa4, 0x95, 0x99, 0x1b, 0x78, 0x52,
+        0xb8, 0x55,
+    ];
+    let expected = [
+        0x6f, 0x26, 0x15, 0xa1, 0x08, 0xc7, 0x02, 0xc5, 0x67, 0x8f, 0x54, 0xfc, 0x9d, 0xba, 0xb6,
+        0x97, 0x16, 0xc0, 0x76, 0x18, 0x9c, 0x48, 0x25, 0x0c, 0xeb, 0xea, 0xc3, 0x57, 0x6c, 0x36,
+        0x11, 0xba,
+    ];
+
+    let mut out = [0u8; HASH_LEN];
+    hkdf_expand_label(&EARLY_SECRET, "derived", &empty_hash, &mut out)
+        .expect("Failed to hkdf_expand_label(..)");
+
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn test_hkdf_expand_label_traffic_key_and_iv() {
+    let expected_key = [
+        0x3f, 0xce, 0x51, 0x60, 0x09, 0xc2, 0x17, 0x27, 0xd0, 0xf2, 0xe4, 0xe8, 0x6e, 0xe4, 0x03,
+        0xbc,
+    ];
+    let expected_iv = [
+        0x5d, 0x31, 0x3e, 0xb2, 0x67, 0x12, 0x76, 0xee, 0x13, 0x00, 0x0b, 0x30,
+    ];
+
+    let mut key = [0u8; 16];
+    hkdf_expand_label(&SERVER_HANDSHAKE_TRAFFIC_SECRET, "key", &[], &mut key)
+        .expect("Failed to hkdf_expand_label(..)");
+
+    let mut iv = [0u8; 12];
+    hkdf_expand_label(&SERVER_HANDSHAKE_TRAFFIC_SECRET, "iv", &[], &mut iv)
+        .expect("Failed to hkdf_expand_label(..)");
+
+    assert_eq!(key, expected_key);
+    assert_eq!(iv, expected_iv);
+}
+
+// =============================================================================
+// hkdf_expand_label() - limits
+// =============================================================================
+
+#[test]
+fn test_hkdf_expand_label_max_label_and_context_multi_block() {
+    let mut label = [b'x'; MAX_LABEL_LEN];
+    label[..10].copy_from_slice(b"max label ");
+    let label = core::str::from_utf8(&label).expect("Failed to from_utf8(..)");
+    let context: [u8; MAX_CONTEXT_LEN] = core::array::from_fn(|i| i as u8);
+    let expected = [
+        0xcf, 0xab, 0x05, 0x32, 0x47, 0xc5, 0x14, 0x24, 0x9f, 0x4e, 0xa6, 0x1f, 0xb0, 0x8f, 0x9c,
+        0x7b, 0x37, 0xed, 0x61, 0xa8, 0x47, 0x89, 0x3d, 0x4c, 0xc5, 0xe2, 0x40, 0xfb, 0x9d, 0x3b,
+        0x62, 0xd1, 0x83, 0x9c, 0x38, 0x1c, 0x1b, 0xf0, 0xd1, 0x14, 0xe4, 0x34, 0x6a, 0xd0, 0xb4,
+        0x2c, 0xf1, 0x48, 0xc5, 0x16, 0x10, 0x2c, 0xe5, 0xd1, 0xc8, 0x25, 0x41, 0x17, 0xd7, 0x13,
+        0x34, 0xd0, 0xe5, 0xc5, 0x90, 0xbe, 0x84, 0x36, 0x2c, 0x99, 0xfa, 0xbb, 0xed, 0x9a, 0x00,
+        0xbb, 0x98, 0x8e, 0xf6, 0x92, 0x8d, 0x64, 0x5e, 0x42, 0x79, 0xf5, 0x08, 0xc1, 0xca, 0xbc,
+        0x87, 0xfb, 0x79, 0xf3, 0xfb, 0x58, 0x99, 0x75, 0x11, 0xe4,
+    ];
+
+    let mut out = [0u8; 100];
+    hkdf_expand_label(&EARLY_SECRET, label, &context, &mut out)
+        .expect("Failed to hkdf_expand_label(..)");
+
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn test_hkdf_expand_label_rejects_long_label() {
+    let label = [b'x'; MAX_LABEL_LEN + 1];
+    let label = core::str::from_utf8(&label).expect("Failed to from_utf8(..)");
+
+    let mut out = [0u8; HASH_LEN];
+    let result = hkdf_expand_label(&EARLY_SECRET, label, &[], &mut out);
+
+    assert_eq!(result, Err(HkdfError::LabelTooLong));
+}
+
+#[test]
+fn test_hkdf_expand_label_rejects_long_context() {
+    let context = [0u8; MAX_CONTEXT_LEN + 1];
+
+    let mut out = [0u8; HASH_LEN];
+    let result = hkdf_expand_label(&EARLY_SECRET, "key", &context, &mut out);
+
+    assert_eq!(result, Err(HkdfError::ContextTooLong));
+}
+
+#[test]
+fn test_hkdf_expand_label_rejects_long_output() {
+    let mut out = [0u8; MAX_OUTPUT_LEN + 1];
+    let result = hkdf_expand_label(&EARLY_SECRET, "key", &[], &mut out);
+
+    assert_eq!(result, Err(HkdfError::OutputTooLong));
+}
@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // See LICENSE in the repository root for full license text.
 
-//! Facade happy path tests.
+//! Facade tests.
+
+mod expand_label;
 
 #[test]
 fn test_hkdf_happy_path() {
@@ -20,6 +22,191 @@ fn test_hkdf_happy_path() {
     assert_ne!(okm, [0u8; 42]);
 }
 
+// =============================================================================
+// RFC 5869 Appendix A (HKDF-SHA256)
+// =============================================================================
+
+#[test]
+fn test_hkdf_rfc5869_test_case_1() {
+    let ikm = [0x0bu8; 22];
+    let salt: [u8; 13] = core::array::from_fn(|i| i as u8);
+    let info: [u8; 10] = core::array::from_fn(|i| 0xf0 + i as u8);
+    let expected = [
+        0x3c, 0xb2, 0x5f, 0x25, 0xfa, 0xac, 0xd5, 0x7a, 0x90, 0x43, 0x4f, 0x64, 0xd0, 0x36, 0x2f,
+        0x2a, 0x2d, 0x2d, 0x0a, 0x90, 0xcf, 0x1a, 0x5a, 0x4c, 0x5d, 0xb0, 0x2d, 0x56, 0xec, 0xc4,
+        0xc5, 0xbf, 0x34, 0x00, 0x72, 0x08, 0xd5, 0xb8, 0x87, 0x18, 0x58, 0x65,
+    ];
+
+    let mut okm = [0u8; 42];
+    crate::hkdf(&salt, &ikm, &info, &mut okm).expect("Failed to hkdf(..)");
+
+    assert_eq!(okm, expected);
+}
+
+#[test]
+fn test_hkdf_rfc5869_test_case_2() {
+    let ikm: [u8; 80] = core::array::from_fn(|i| i as u8);
+    let salt: [u8; 80] = core::array::from_fn(|i| 0x60 + i as u8);
+    let info: [u8; 80] = core::array::from_fn(|i| 0xb0 + i as u8);
+    let expected = [
+        0xb1, 0x1e, 0x39, 0x8d, 0xc8, 0x03, 0x27, 0xa1, 0xc8, 0xe7, 0xf7, 0x8c, 0x59, 0x6a, 0x49,
+        0x34, 0x4f, 0x01, 0x2e, 0xda, 0x2d, 0x4e, 0xfa, 0xd8, 0xa0, 0x50, 0xcc, 0x4c, 0x19, 0xaf,
+        0xa9, 0x7c, 0x59, 0x04, 0x5a, 0x99, 0xca, 0xc7, 0x82, 0x72, 0x71, 0xcb, 0x41, 0xc6, 0x5e,
+        0x59, 0x0e, 0x09, 0xda, 0x32, 0x75, 0x60, 0x0c, 0x2f, 0x09, 0xb8, 0x36, 0x77, 0x93, 0xa9,
+        0xac, 0xa3, 0xdb, 0x71, 0xcc, 0x30, 0xc5, 0x81, 0x79, 0xec, 0x3e, 0x87, 0xc1, 0x4c, 0x01,
+        0xd5, 0xc1, 0xf3, 0x43, 0x4f, 0x1d, 0x87,
+    ];
+
+    let mut okm = [0u8; 82];
+    crate::hkdf(&salt, &ikm, &info, &mut okm).expect("Failed to hkdf(..)");
+
+    assert_eq!(okm, expected);
+}
+
+#[test]
+fn test_hkdf_rfc5869_test_case_3() {
+    let ikm = [0x0bu8; 22];
+    let expected = [
+        0x8d, 0xa4, 0xe7, 0x75, 0xa5, 0x63, 0xc1, 0x8f, 0x71, 0x5f, 0x80, 0x2a, 0x06, 0x3c, 0x5a,
+        0x31, 0xb8, 0xa1, 0x1f, 0x5c, 0x5e, 0xe1, 0x87, 0x9e, 0xc3, 0x45, 0x4e, 0x5f, 0x3c, 0x73,
+        0x8d, 0x2d, 0x9d, 0x20, 0x13, 0x95, 0xfa, 0xa4, 0xb6, 0x1a, 0x96, 0xc8,
+    ];
+
+    let mut okm = [0u8; 42];
+    crate::hkdf(&[], &ikm, &[], &mut okm).expect("Failed to hkdf(..)");
+
+    assert_eq!(okm, expected);
+}
+
+// =============================================================================
+// hmac_sha256() - RFC 4231 Section 4
+// =============================================================================
+
+#[test]
+fn test_hmac_sha256_rfc4231_test_case_1() {
+    let key = [0x0bu8; 20];
+    let expected = [
+        0xb0, 0x34, 0x4c, 0x61, 0xd8, 0xdb, 0x38, 0x53, 0x5c, 0xa8, 0xaf, 0xce, 0xaf, 0x0b, 0xf1,
+        0x2b, 0x88, 0x1d, 0xc2, 0x00, 0xc9, 0x83, 0x3d, 0xa7, 0x26, 0xe9, 0x37, 0x6c, 0x2e, 0x32,
+        0xcf, 0xf7,
+    ];
+
+    let mut out = [0u8; crate::HASH_LEN];
+    crate::hmac_sha256(&key, b"Hi There", &mut out);
+
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn test_hmac_sha256_rfc4231_test_case_2() {
+    let key = *b"Jefe";
+    let expected = [
+        0x5b, 0xdc, 0xc1, 0x46, 0xbf, 0x60, 0x75, 0x4e, 0x6a, 0x04, 0x24, 0x26, 0x08, 0x95, 0x75,
+        0xc7, 0x5a, 0x00, 0x3f, 0x08, 0x9d, 0x27, 0x39, 0x83, 0x9d, 0xec, 0x58, 0xb9, 0x64, 0xec,
+        0x38, 0x43,
+    ];
+
+    let mut out = [0u8; crate::HASH_LEN];
+    crate::hmac_sha256(&key, b"what do ya want for nothing?", &mut out);
+
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn test_hmac_sha256_rfc4231_test_case_3() {
+    let key = [0xaau8; 20];
+    let expected = [
+        0x77, 0x3e, 0xa9, 0x1e, 0x36, 0x80, 0x0e, 0x46, 0x85, 0x4d, 0xb8, 0xeb, 0xd0, 0x91, 0x81,
+        0xa7, 0x29, 0x59, 0x09, 0x8b, 0x3e, 0xf8, 0xc1, 0x22, 0xd9, 0x63, 0x55, 0x14, 0xce, 0xd5,
+        0x65, 0xfe,
+    ];
+
+    let mut out = [0u8; crate::HASH_LEN];
+    crate::hmac_sha256(&key, &[0xddu8; 50], &mut out);
+
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn test_hmac_sha256_rfc4231_test_case_4() {
+    let key = core::array::from_fn::<u8, 25, _>(|i| i as u8 + 1);
+    let expected = [
+        0x82, 0x55, 0x8a, 0x38, 0x9a, 0x44, 0x3c, 0x0e, 0xa4, 0xcc, 0x81, 0x98, 0x99, 0xf2, 0x08,
+        0x3a, 0x85, 0xf0, 0xfa, 0xa3, 0xe5, 0x78, 0xf8, 0x07, 0x7a, 0x2e, 0x3f, 0xf4, 0x67, 0x29,
+        0x66, 0x5b,
+    ];
+
+    let mut out = [0u8; crate::HASH_LEN];
+    crate::hmac_sha256(&key, &[0xcdu8; 50], &mut out);
+
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn test_hmac_sha256_rfc4231_test_case_6() {
+    let key = [0xaau8; 131];
+    let expected = [
+        0x60, 0xe4, 0x31, 0x59, 0x1e, 0xe0, 0xb6, 0x7f, 0x0d, 0x8a, 0x26, 0xaa, 0xcb, 0xf5, 0xb7,
+        0x7f, 0x8e, 0x0b, 0xc6, 0x21, 0x37, 0x28, 0xc5, 0x14, 0x05, 0x46, 0x04, 0x0f, 0x0e, 0xe3,
+        0x7f, 0x54,
+    ];
+
+    let mut out = [0u8; crate::HASH_LEN];
+    crate::hmac_sha256(
+        &key,
+        b"Test Using Larger Than Block-Size Key - Hash Key First",
+        &mut out,
+    );
+
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn test_hmac_sha256_rfc4231_test_case_7() {
+    let key = [0xaau8; 131];
+    let expected = [
+        0x9b, 0x09, 0xff, 0xa7, 0x1b, 0x94, 0x2f, 0xcb, 0x27, 0x63, 0x5f, 0xbc, 0xd5, 0xb0, 0xe9,
+        0x44, 0xbf, 0xdc, 0x63, 0x64, 0x4f, 0x07, 0x13, 0x93, 0x8a, 0x7f, 0x51, 0x53, 0x5c, 0x3a,
+        0x35, 0xe2,
+    ];
+
+    let mut out = [0u8; crate::HASH_LEN];
+    crate::hmac_sha256(&key, b"This is a test using a larger than block-size key and a larger than block-size data. The key needs to be hashed before being used by the HMAC algorithm.", &mut out);
+
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn test_hmac_sha256_streaming_matches_one_shot() {
+    let key = [0x5au8; 40];
+    let message: [u8; 300] = core::array::from_fn(|i| i as u8);
+
+    let mut expected = [0u8; crate::HASH_LEN];
+    crate::hmac_sha256(&key, &message, &mut expected);
+
+    let mut mac = crate::HmacSha256::new(&key);
+    for chunk in message.chunks(37) {
+        mac.update(chunk);
+    }
+
+    let mut out = [0u8; crate::HASH_LEN];
+    mac.finalize(&mut out);
+
+    assert_eq!(out, expected);
+}
+
+// =============================================================================
+// Limits
+// =============================================================================
+
+#[test]
+fn test_hkdf_max_output_len() {
+    let mut okm = [0u8; crate::MAX_OUTPUT_LEN];
+    let result = crate::hkdf(b"salt", b"ikm", b"info", &mut okm);
+
+    assert!(result.is_ok());
+}
+
 #[test]
 fn test_hkdf_empty_okm() {
     let result = crate::hkdf(b"salt", b"ikm", b"info", &mut []);
@@ -29,7 +216,7 @@ fn test_hkdf_empty_okm() {
 
 #[test]
 fn test_hkdf_output_too_long() {
-    let mut okm = [0u8; 255 * 32 + 1];
+    let mut okm = [0u8; crate::MAX_OUTPUT_LEN + 1];
     let result = crate::hkdf(b"salt", b"ikm", b"info", &mut okm);
 
     assert!(result.is_err());
@@ -0,0 +1,127 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! Buffered entropy source backed by a reseeding ChaCha20 keystream.
+
+use core::cell::RefCell;
+
+use redoubt_zero::FastZeroizable;
+
+use crate::chacha20::{ChaCha20Keystream, KEY_SIZE};
+use crate::error::EntropyError;
+use crate::traits::EntropySource;
+
+/// Default number of bytes served between automatic reseeds (1 MiB).
+pub const DEFAULT_RESEED_INTERVAL: usize = 1024 * 1024;
+
+struct BufferedState {
+    keystream: ChaCha20Keystream,
+    /// Bytes served since the last reseed
+    served: usize,
+}
+
+/// Entropy source that serves bytes from a ChaCha20 keystream keyed by `E`.
+///
+/// Trades one `E::fill_bytes` call (typically a syscall) per `reseed_interval`
+/// bytes for a ChaCha20 block per 64 bytes, which pays off for hot paths that
+/// request many small buffers.
+///
+/// - The first `fill_bytes` seeds the keystream with 32 bytes from `E`
+/// - After `reseed_interval` bytes the keystream is rekeyed from `E` again
+/// - [`reseed`](Self::reseed) forces a rekey at any time
+///
+/// The buffered keystream is zeroized on reseed and on drop, and every byte is
+/// wiped from the buffer as soon as it is served.
+///
+/// # Example
+///
+/// ```rust
+/// use redoubt_rand::{BufferedEntropySource, EntropySource, SystemEntropySource};
+///
+/// let entropy = BufferedEntropySource::new(SystemEntropySource {});
+///
+/// let mut key = [0u8; 32];
+/// entropy.fill_bytes(&mut key).expect("Failed to fill_bytes(..)");
+/// ```
+pub struct BufferedEntropySource<E: EntropySource> {
+    seed_source: E,
+    reseed_interval: usize,
+    state: RefCell<BufferedState>,
+}
+
+impl<E: EntropySource> BufferedEntropySource<E> {
+    /// Creates a buffered source reseeding every [`DEFAULT_RESEED_INTERVAL`] bytes.
+    pub fn new(seed_source: E) -> Self {
+        Self::with_reseed_interval(seed_source, DEFAULT_RESEED_INTERVAL)
+    }
+
+    /// Creates a buffered source reseeding every `reseed_interval` bytes.
+    ///
+    /// The keystream is seeded lazily on first use. A `reseed_interval` of `0`
+    /// is treated as `1`.
+    pub fn with_reseed_interval(seed_source: E, reseed_interval: usize) -> Self {
+        let reseed_interval = reseed_interval.max(1);
+
+        Self {
+            seed_source,
+            reseed_interval,
+            state: RefCell::new(BufferedState {
+                keystream: ChaCha20Keystream::new(&[0u8; KEY_SIZE]),
+                // Forces seeding on the first fill_bytes
+                served: reseed_interval,
+            }),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn seed_source_for_test(&self) -> &E {
+        &self.seed_source
+    }
+
+    /// Rekeys the keystream from the seed source, discarding buffered bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EntropyError::EntropyNotAvailable`] if the seed source fails.
+    pub fn reseed(&self) -> Result<(), EntropyError> {
+        Self::reseed_state(&self.seed_source, &mut self.state.borrow_mut())
+    }
+
+    fn reseed_state(seed_source: &E, state: &mut BufferedState) -> Result<(), EntropyError> {
+        let mut seed = [0u8; KEY_SIZE];
+        let result = seed_source.fill_bytes(&mut seed);
+
+        if result.is_ok() {
+            state.keystream.rekey(&seed);
+            state.served = 0;
+        }
+
+        seed.fast_zeroize();
+
+        result
+    }
+}
+
+impl<E: EntropySource> EntropySource for BufferedEntropySource<E> {
+    fn fill_bytes(&self, dest: &mut [u8]) -> Result<(), EntropyError> {
+        let mut state = self.state.borrow_mut();
+        let mut offset = 0;
+
+        while offset < dest.len() {
+            if state.served >= self.reseed_interval
+                && let Err(e) = Self::reseed_state(&self.seed_source, &mut state)
+            {
+                dest.fast_zeroize();
+                return Err(e);
+            }
+
+            let n = core::cmp::min(self.reseed_interval - state.served, dest.len() - offset);
+            state.keystream.fill(&mut dest[offset..offset + n]);
+            state.served += n;
+            offset += n;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! ChaCha20 keystream generator (RFC 8439 block function).
+//!
+//! Uses the original 64-bit counter / 64-bit nonce layout with an all-zero
+//! nonce, so a single 32-byte key yields 2^64 blocks of keystream.
+//!
+//! All sensitive state is zeroized on drop using RedoubtZero.
+
+use redoubt_zero::{FastZeroizable, RedoubtZero, ZeroizeOnDropSentinel};
+
+/// ChaCha20 key size in bytes.
+pub(crate) const KEY_SIZE: usize = 32;
+
+/// ChaCha20 block size in bytes.
+pub(crate) const BLOCK_SIZE: usize = 64;
+
+const SIGMA: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+
+/// Buffered ChaCha20 keystream.
+#[derive(RedoubtZero)]
+#[fast_zeroize(drop)]
+pub(crate) struct ChaCha20Keystream {
+    key: [u32; 8],
+    counter: u64,
+    initial: [u32; 16],
+    working: [u32; 16],
+    block: [u8; BLOCK_SIZE],
+    /// Bytes of `block` already served
+    pos: usize,
+    __sentinel: ZeroizeOnDropSentinel,
+}
+
+impl ChaCha20Keystream {
+    /// Creates a keystream keyed with `key`, starting at block 0.
+    pub(crate) fn new(key: &[u8; KEY_SIZE]) -> Self {
+        let mut keystream = Self {
+            key: [0; 8],
+            counter: 0,
+            initial: [0; 16],
+            working: [0; 16],
+            block: [0; BLOCK_SIZE],
+            pos: BLOCK_SIZE,
+            __sentinel: ZeroizeOnDropSentinel::default(),
+        };
+
+        keystream.rekey(key);
+        keystream
+    }
+
+    /// Replaces the key, wiping any buffered keystream and restarting at block 0.
+    pub(crate) fn rekey(&mut self, key: &[u8; KEY_SIZE]) {
+        for (word, chunk) in self.key.iter_mut().zip(key.chunks_exact(4)) {
+            *word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+
+        self.counter = 0;
+        self.block.fast_zeroize();
+        self.pos = BLOCK_SIZE;
+    }
+
+    /// Fills `dest` with the next `dest.len()` keystream bytes.
+    pub(crate) fn fill(&mut self, dest: &mut [u8]) {
+        let mut offset = 0;
+
+        while offset < dest.len() {
+            if self.pos == BLOCK_SIZE {
+                self.next_block();
+            }
+
+            let n = core::cmp::min(BLOCK_SIZE - self.pos, dest.len() - offset);
+            dest[offset..offset + n].copy_from_slice(&self.block[self.pos..self.pos + n]);
+
+            // Served keystream must not linger in the buffer
+            self.block[self.pos..self.pos + n].fast_zeroize();
+            self.pos += n;
+            offset += n;
+        }
+    }
+
+    #[inline(always)]
+    fn quarter_round(&mut self, a: usize, b: usize, c: usize, d: usize) {
+        let w = &mut self.working;
+
+        w[a] = w[a].wrapping_add(w[b]);
+        w[d] = (w[d] ^ w[a]).rotate_left(16);
+        w[c] = w[c].wrapping_add(w[d]);
+        w[b] = (w[b] ^ w[c]).rotate_left(12);
+        w[a] = w[a].wrapping_add(w[b]);
+        w[d] = (w[d] ^ w[a]).rotate_left(8);
+        w[c] = w[c].wrapping_add(w[d]);
+        w[b] = (w[b] ^ w[c]).rotate_left(7);
+    }
+
+    fn next_block(&mut self) {
+        self.initial[..4].copy_from_slice(&SIGMA);
+        self.initial[4..12].copy_from_slice(&self.key);
+        self.initial[12] = self.counter as u32;
+        self.initial[13] = (self.counter >> 32) as u32;
+        self.initial[14] = 0;
+        self.initial[15] = 0;
+
+        self.working.copy_from_slice(&self.initial);
+
+        for _ in 0..10 {
+            self.quarter_round(0, 4, 8, 12);
+            self.quarter_round(1, 5, 9, 13);
+            self.quarter_round(2, 6, 10, 14);
+            self.quarter_round(3, 7, 11, 15);
+
+            self.quarter_round(0, 5, 10, 15);
+            self.quarter_round(1, 6, 11, 12);
+            self.quarter_round(2, 7, 8, 13);
+            self.quarter_round(3, 4, 9, 14);
+        }
+
+        for i in 0..16 {
+            let word = self.working[i].wrapping_add(self.initial[i]);
+            self.block[i * 4..i * 4 + 4].copy_from_slice(&word.to_le_bytes());
+        }
+
+        self.initial.fast_zeroize();
+        self.working.fast_zeroize();
+
+        self.counter = self.counter.wrapping_add(1);
+        self.pos = 0;
+    }
+}
@@ -10,4 +10,8 @@ pub enum EntropyError {
     /// System entropy source is unavailable or failed to generate random data.
     #[error("EntropyNotAvailable")]
     EntropyNotAvailable,
+
+    /// A generated nonce repeated one still held in the uniqueness history.
+    #[error("NonceCollision")]
+    NonceCollision,
 }
@@ -13,6 +13,7 @@
 //!
 //! - [`SystemEntropySource`]: OS-level CSPRNG (via `getrandom`)
 //! - [`NonceSessionGenerator`]: Session-based nonce generator with configurable size
+//! - [`BufferedEntropySource`]: Reseeding ChaCha20 keystream over another entropy source
 //!
 //! ## Traits
 //!
@@ -63,6 +64,8 @@ extern crate alloc;
 #[cfg(test)]
 mod tests;
 
+mod buffered;
+mod chacha20;
 mod error;
 mod generate_random_key;
 mod session;
@@ -73,6 +76,7 @@ mod traits;
 pub mod support;
 pub mod u64_seed;
 
+pub use buffered::{BufferedEntropySource, DEFAULT_RESEED_INTERVAL};
 pub use error::EntropyError;
 pub use generate_random_key::generate_random_key;
 pub use session::NonceSessionGenerator;
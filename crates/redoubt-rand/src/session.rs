@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // See LICENSE in the repository root for full license text.
 
+use alloc::vec::Vec;
 use core::mem::size_of;
 
 use crate::error::EntropyError;
@@ -38,10 +39,53 @@ pub(crate) type Counter = u32;
 ///
 /// let nonce = generator.generate_nonce()?;
 /// ```
+///
+/// # Uniqueness tracking
+///
+/// Generators built with [`with_uniqueness_tracking`](Self::with_uniqueness_tracking)
+/// also remember the last `capacity` nonces and fail with
+/// [`EntropyError::NonceCollision`] instead of returning a repeat.
 pub struct NonceSessionGenerator<E: EntropySource, const NONCE_SIZE: usize> {
     entropy: E,
     counter: Counter,
     initialized: bool,
+    history: Option<NonceHistory<NONCE_SIZE>>,
+}
+
+/// Bounded ring buffer of recently issued nonces.
+struct NonceHistory<const NONCE_SIZE: usize> {
+    nonces: Vec<[u8; NONCE_SIZE]>,
+    capacity: usize,
+    next: usize,
+}
+
+impl<const NONCE_SIZE: usize> NonceHistory<NONCE_SIZE> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            nonces: Vec::with_capacity(capacity),
+            capacity,
+            next: 0,
+        }
+    }
+
+    fn contains(&self, nonce: &[u8; NONCE_SIZE]) -> bool {
+        self.nonces.iter().any(|seen| seen == nonce)
+    }
+
+    /// Records `nonce`, evicting the oldest entry once full.
+    fn push(&mut self, nonce: [u8; NONCE_SIZE]) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.nonces.len() < self.capacity {
+            self.nonces.push(nonce);
+        } else {
+            self.nonces[self.next] = nonce;
+        }
+
+        self.next = (self.next + 1) % self.capacity;
+    }
 }
 
 impl<E: EntropySource, const NONCE_SIZE: usize> NonceSessionGenerator<E, NONCE_SIZE> {
@@ -58,6 +102,33 @@ impl<E: EntropySource, const NONCE_SIZE: usize> NonceSessionGenerator<E, NONCE_S
             entropy,
             counter: 0,
             initialized: false,
+            history: None,
+        }
+    }
+
+    /// Creates a nonce session generator that detects nonce reuse.
+    ///
+    /// Every issued nonce is remembered in a ring buffer of `capacity`
+    /// entries. A newly generated nonce matching any of them is rejected
+    /// with [`EntropyError::NonceCollision`].
+    ///
+    /// # Cost
+    ///
+    /// - Memory: `capacity * NONCE_SIZE` bytes, reserved up front
+    /// - Time: each [`generate_nonce`](NonceGenerator::generate_nonce) scans the whole history
+    ///
+    /// Detection is best-effort: only the last `capacity` nonces are checked,
+    /// so a repeat of an evicted nonce goes unnoticed. A `capacity` of `0`
+    /// disables tracking.
+    ///
+    /// # Arguments
+    ///
+    /// * `entropy` - Entropy source for generating random nonce suffixes
+    /// * `capacity` - Number of recent nonces to remember
+    pub fn with_uniqueness_tracking(entropy: E, capacity: usize) -> Self {
+        Self {
+            history: Some(NonceHistory::new(capacity)),
+            ..Self::new(entropy)
         }
     }
 
@@ -65,7 +136,7 @@ impl<E: EntropySource, const NONCE_SIZE: usize> NonceSessionGenerator<E, NONCE_S
         if !self.initialized {
             let mut counter_bytes = [0u8; size_of::<Counter>()];
 
-            self.entropy.fill_bytes(&mut counter_bytes)?;
+            self.entropy.fill_bytes_exact(&mut counter_bytes)?;
             self.counter = Counter::from_le_bytes(counter_bytes);
 
             self.initialized = true;
@@ -84,19 +155,26 @@ impl<E: EntropySource, const NONCE_SIZE: usize> NonceSessionGenerator<E, NONCE_S
 impl<E: EntropySource, const NONCE_SIZE: usize> NonceGenerator<NONCE_SIZE>
     for NonceSessionGenerator<E, NONCE_SIZE>
 {
-    fn generate_nonce(&mut self) -> Result<[u8; NONCE_SIZE], EntropyError> {
+    fn generate_nonce_into(&mut self, nonce: &mut [u8; NONCE_SIZE]) -> Result<(), EntropyError> {
         self.maybe_initialize()?;
 
-        let mut nonce = [0u8; NONCE_SIZE];
         // First part: counter
         nonce[..size_of::<Counter>()].copy_from_slice(&self.counter.to_le_bytes());
 
         // Second part: fill remaining bytes with random
         self.entropy
-            .fill_bytes(&mut nonce[size_of::<Counter>()..])?;
+            .fill_bytes_exact(&mut nonce[size_of::<Counter>()..])?;
+
+        if let Some(history) = &mut self.history {
+            if history.contains(nonce) {
+                return Err(EntropyError::NonceCollision);
+            }
+
+            history.push(*nonce);
+        }
 
         self.counter = self.counter.wrapping_add(1);
 
-        Ok(nonce)
+        Ok(())
     }
 }
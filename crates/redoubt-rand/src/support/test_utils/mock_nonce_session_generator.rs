@@ -41,9 +41,9 @@ impl<E: EntropySource, const NONCE_SIZE: usize> MockNonceSessionGenerator<E, NON
 impl<E: EntropySource, const NONCE_SIZE: usize> NonceGenerator<NONCE_SIZE>
     for MockNonceSessionGenerator<E, NONCE_SIZE>
 {
-    fn generate_nonce(&mut self) -> Result<[u8; NONCE_SIZE], EntropyError> {
+    fn generate_nonce_into(&mut self, out: &mut [u8; NONCE_SIZE]) -> Result<(), EntropyError> {
         match self.behaviour {
-            MockNonceSessionGeneratorBehaviour::None => self.inner.generate_nonce(),
+            MockNonceSessionGeneratorBehaviour::None => self.inner.generate_nonce_into(out),
             MockNonceSessionGeneratorBehaviour::FailAlways => {
                 Err(EntropyError::EntropyNotAvailable)
             }
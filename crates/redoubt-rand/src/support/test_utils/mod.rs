@@ -4,12 +4,15 @@
 
 //! Test utilities for mocking entropy sources and nonce generators.
 //!
-//! Provides mock implementations with configurable behavior for testing.
+//! Provides mock implementations with configurable behavior for testing,
+//! and a seeded deterministic entropy source for reproducible runs.
 
 mod mock_entropy_source;
 mod mock_nonce_session_generator;
+mod seeded_entropy_source;
 
 pub use mock_entropy_source::{MockEntropySource, MockEntropySourceBehaviour};
 pub use mock_nonce_session_generator::{
     MockNonceSessionGenerator, MockNonceSessionGeneratorBehaviour,
 };
+pub use seeded_entropy_source::SeededEntropySource;
@@ -0,0 +1,48 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use core::cell::RefCell;
+
+use crate::chacha20::{ChaCha20Keystream, KEY_SIZE};
+use crate::error::EntropyError;
+use crate::traits::EntropySource;
+
+/// Deterministic entropy source for reproducible tests.
+///
+/// Expands a 32-byte seed through a ChaCha20 keystream: two instances built
+/// from the same seed yield the same byte stream, regardless of how reads are
+/// split across `fill_bytes` calls.
+///
+/// **Not** a CSPRNG substitute outside tests: anyone holding the seed can
+/// predict every byte.
+///
+/// # Example
+///
+/// ```rust
+/// use redoubt_rand::{NonceGenerator, NonceSessionGenerator};
+/// use redoubt_rand::test_utils::SeededEntropySource;
+///
+/// let mut generator = NonceSessionGenerator::<_, 24>::new(SeededEntropySource::new([7u8; 32]));
+/// let nonce = generator.generate_nonce().expect("Failed to generate_nonce()");
+/// ```
+pub struct SeededEntropySource {
+    keystream: RefCell<ChaCha20Keystream>,
+}
+
+impl SeededEntropySource {
+    /// Creates an entropy source that replays the keystream of `seed`.
+    pub fn new(seed: [u8; KEY_SIZE]) -> Self {
+        Self {
+            keystream: RefCell::new(ChaCha20Keystream::new(&seed)),
+        }
+    }
+}
+
+impl EntropySource for SeededEntropySource {
+    fn fill_bytes(&self, dest: &mut [u8]) -> Result<(), EntropyError> {
+        self.keystream.borrow_mut().fill(dest);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use crate::buffered::BufferedEntropySource;
+use crate::chacha20::ChaCha20Keystream;
+use crate::error::EntropyError;
+use crate::support::test_utils::{
+    MockEntropySource, MockEntropySourceBehaviour, SeededEntropySource,
+};
+use crate::system::SystemEntropySource;
+use crate::traits::EntropySource;
+
+// =============================================================================
+// fill_bytes()
+// =============================================================================
+
+#[test]
+fn test_buffered_seeds_lazily() {
+    let source =
+        BufferedEntropySource::new(MockEntropySource::new(MockEntropySourceBehaviour::None));
+
+    assert_eq!(source.seed_source_for_test().call_count(), 0);
+
+    let mut out = [0u8; 16];
+    source
+        .fill_bytes(&mut out)
+        .expect("Failed to fill_bytes(..)");
+
+    assert_eq!(source.seed_source_for_test().call_count(), 1);
+}
+
+#[test]
+fn test_buffered_reseeds_after_threshold() {
+    let source = BufferedEntropySource::with_reseed_interval(
+        MockEntropySource::new(MockEntropySourceBehaviour::None),
+        100,
+    );
+
+    let mut out = [0u8; 250];
+    source
+        .fill_bytes(&mut out)
+        .expect("Failed to fill_bytes(..)");
+
+    // Initial seed + reseeds at 100 and 200 bytes
+    assert_eq!(source.seed_source_for_test().call_count(), 3);
+
+    let mut out = [0u8; 50];
+    source
+        .fill_bytes(&mut out)
+        .expect("Failed to fill_bytes(..)");
+
+    assert_eq!(source.seed_source_for_test().call_count(), 3);
+
+    let mut out = [0u8; 1];
+    source
+        .fill_bytes(&mut out)
+        .expect("Failed to fill_bytes(..)");
+
+    assert_eq!(source.seed_source_for_test().call_count(), 4);
+}
+
+#[test]
+fn test_buffered_rekeys_from_seed_source_at_threshold() {
+    let seed_stream = SeededEntropySource::new([0x05u8; 32]);
+    let mut seed_0 = [0u8; 32];
+    let mut seed_1 = [0u8; 32];
+    seed_stream
+        .fill_bytes(&mut seed_0)
+        .expect("Failed to fill_bytes(..)");
+    seed_stream
+        .fill_bytes(&mut seed_1)
+        .expect("Failed to fill_bytes(..)");
+
+    let mut expected = [0u8; 160];
+    ChaCha20Keystream::new(&seed_0).fill(&mut expected[..100]);
+    ChaCha20Keystream::new(&seed_1).fill(&mut expected[100..]);
+
+    let source =
+        BufferedEntropySource::with_reseed_interval(SeededEntropySource::new([0x05u8; 32]), 100);
+    let mut out = [0u8; 160];
+    for chunk in out.chunks_mut(33) {
+        source.fill_bytes(chunk).expect("Failed to fill_bytes(..)");
+    }
+
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn test_buffered_zero_interval_reseeds_every_byte() {
+    let source = BufferedEntropySource::with_reseed_interval(
+        MockEntropySource::new(MockEntropySourceBehaviour::None),
+        0,
+    );
+
+    let mut out = [0u8; 4];
+    source
+        .fill_bytes(&mut out)
+        .expect("Failed to fill_bytes(..)");
+
+    assert_eq!(source.seed_source_for_test().call_count(), 4);
+}
+
+#[test]
+fn test_buffered_distribution_sanity() {
+    let source = BufferedEntropySource::with_reseed_interval(SystemEntropySource {}, 4096);
+    let mut out = vec![0u8; 64 * 1024];
+    source
+        .fill_bytes(&mut out)
+        .expect("Failed to fill_bytes(..)");
+
+    // Each byte value is expected 256 times (std dev ~16)
+    let mut counts = [0usize; 256];
+    for byte in &out {
+        counts[*byte as usize] += 1;
+    }
+    assert!(counts.iter().all(|c| (128..=384).contains(c)));
+
+    // Expected 262144 set bits (std dev ~362)
+    let ones: u32 = out.iter().map(|b| b.count_ones()).sum();
+    assert!((256_000..=268_288).contains(&ones));
+}
+
+#[test]
+fn test_buffered_propagates_seed_error_and_zeroizes_dest() {
+    let source = BufferedEntropySource::with_reseed_interval(
+        MockEntropySource::new(MockEntropySourceBehaviour::FailAtNthFillBytes(2)),
+        8,
+    );
+
+    let mut out = [0xAAu8; 16];
+    let result = source.fill_bytes(&mut out);
+
+    assert!(matches!(result, Err(EntropyError::EntropyNotAvailable)));
+    assert!(out.iter().all(|b| *b == 0));
+}
+
+// =============================================================================
+// reseed()
+// =============================================================================
+
+#[test]
+fn test_buffered_explicit_reseed() {
+    let source =
+        BufferedEntropySource::with_reseed_interval(SeededEntropySource::new([0x06u8; 32]), 1024);
+    let reference =
+        BufferedEntropySource::with_reseed_interval(SeededEntropySource::new([0x06u8; 32]), 1024);
+
+    let mut out = [0u8; 32];
+    let mut expected = [0u8; 32];
+    source
+        .fill_bytes(&mut out)
+        .expect("Failed to fill_bytes(..)");
+    reference
+        .fill_bytes(&mut expected)
+        .expect("Failed to fill_bytes(..)");
+    assert_eq!(out, expected);
+
+    source.reseed().expect("Failed to reseed()");
+
+    source
+        .fill_bytes(&mut out)
+        .expect("Failed to fill_bytes(..)");
+    reference
+        .fill_bytes(&mut expected)
+        .expect("Failed to fill_bytes(..)");
+    assert_ne!(out, expected);
+}
+
+#[test]
+fn test_buffered_reseed_propagates_error() {
+    let source = BufferedEntropySource::new(MockEntropySource::new(
+        MockEntropySourceBehaviour::FailAlways,
+    ));
+
+    let result = source.reseed();
+
+    assert!(matches!(result, Err(EntropyError::EntropyNotAvailable)));
+}
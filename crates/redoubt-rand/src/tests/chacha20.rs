@@ -0,0 +1,93 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use redoubt_zero::{AssertZeroizeOnDrop, FastZeroizable, ZeroizationProbe};
+
+use crate::chacha20::{BLOCK_SIZE, ChaCha20Keystream};
+
+// RFC 8439 Appendix A.1, test vectors #1 and #2 (all-zero key and nonce)
+const BLOCK_0: [u8; BLOCK_SIZE] = [
+    0x76, 0xb8, 0xe0, 0xad, 0xa0, 0xf1, 0x3d, 0x90, 0x40, 0x5d, 0x6a, 0xe5, 0x53, 0x86, 0xbd, 0x28,
+    0xbd, 0xd2, 0x19, 0xb8, 0xa0, 0x8d, 0xed, 0x1a, 0xa8, 0x36, 0xef, 0xcc, 0x8b, 0x77, 0x0d, 0xc7,
+    0xda, 0x41, 0x59, 0x7c, 0x51, 0x57, 0x48, 0x8d, 0x77, 0x24, 0xe0, 0x3f, 0xb8, 0xd8, 0x4a, 0x37,
+    0x6a, 0x43, 0xb8, 0xf4, 0x15, 0x18, 0xa1, 0x1c, 0xc3, 0x87, 0xb6, 0x69, 0xb2, 0xee, 0x65, 0x86,
+];
+
+const BLOCK_1: [u8; BLOCK_SIZE] = [
+    0x9f, 0x07, 0xe7, 0xbe, 0x55, 0x51, 0x38, 0x7a, 0x98, 0xba, 0x97, 0x7c, 0x73, 0x2d, 0x08, 0x0d,
+    0xcb, 0x0f, 0x29, 0xa0, 0x48, 0xe3, 0x65, 0x69, 0x12, 0xc6, 0x53, 0x3e, 0x32, 0xee, 0x7a, 0xed,
+    0x29, 0xb7, 0x21, 0x76, 0x9c, 0xe6, 0x4e, 0x43, 0xd5, 0x71, 0x33, 0xb0, 0x74, 0xd8, 0x39, 0xd5,
+    0x31, 0xed, 0x1f, 0x28, 0x51, 0x0a, 0xfb, 0x45, 0xac, 0xe1, 0x0a, 0x1f, 0x4b, 0x79, 0x4d, 0x6f,
+];
+
+// =============================================================================
+// fill()
+// =============================================================================
+
+#[test]
+fn test_keystream_rfc8439_vectors() {
+    let mut keystream = ChaCha20Keystream::new(&[0u8; 32]);
+    let mut out = [0u8; 2 * BLOCK_SIZE];
+
+    keystream.fill(&mut out);
+
+    assert_eq!(out[..BLOCK_SIZE], BLOCK_0);
+    assert_eq!(out[BLOCK_SIZE..], BLOCK_1);
+}
+
+#[test]
+fn test_keystream_split_reads_match_single_read() {
+    let key = [0x42u8; 32];
+
+    let mut expected = [0u8; 300];
+    ChaCha20Keystream::new(&key).fill(&mut expected);
+
+    let mut keystream = ChaCha20Keystream::new(&key);
+    let mut out = [0u8; 300];
+    for chunk in out.chunks_mut(7) {
+        keystream.fill(chunk);
+    }
+
+    assert_eq!(out, expected);
+}
+
+// =============================================================================
+// rekey()
+// =============================================================================
+
+#[test]
+fn test_keystream_rekey_restarts_stream() {
+    let mut keystream = ChaCha20Keystream::new(&[0x11u8; 32]);
+    let mut discard = [0u8; 10];
+    keystream.fill(&mut discard);
+
+    keystream.rekey(&[0u8; 32]);
+
+    let mut out = [0u8; BLOCK_SIZE];
+    keystream.fill(&mut out);
+
+    assert_eq!(out, BLOCK_0);
+}
+
+// =============================================================================
+// Zeroization
+// =============================================================================
+
+#[test]
+fn test_keystream_zeroize() {
+    let mut keystream = ChaCha20Keystream::new(&[0x42u8; 32]);
+    let mut out = [0u8; 10];
+    keystream.fill(&mut out);
+
+    keystream.fast_zeroize();
+
+    assert!(keystream.is_zeroized());
+}
+
+#[test]
+fn test_keystream_assert_zeroize_on_drop() {
+    let keystream = ChaCha20Keystream::new(&[0x42u8; 32]);
+
+    keystream.assert_zeroize_on_drop();
+}
@@ -2,9 +2,12 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // See LICENSE in the repository root for full license text.
 
+mod buffered;
+mod chacha20;
 mod fill;
 mod generate_random_key;
 mod session;
 mod support;
 mod system;
+mod traits;
 mod u64_seed;
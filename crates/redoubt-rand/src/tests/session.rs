@@ -7,7 +7,17 @@ use core::mem::size_of;
 use crate::error::EntropyError;
 use crate::session::{Counter, NonceSessionGenerator};
 use crate::support::test_utils::{MockEntropySource, MockEntropySourceBehaviour};
-use crate::traits::NonceGenerator;
+use crate::traits::{EntropySource, NonceGenerator};
+
+/// Deterministic entropy source that always yields the same bytes.
+struct FixedEntropySource;
+
+impl EntropySource for FixedEntropySource {
+    fn fill_bytes(&self, dest: &mut [u8]) -> Result<(), EntropyError> {
+        dest.fill(0xAB);
+        Ok(())
+    }
+}
 
 #[test]
 fn test_nonce_session_generator_counter_increments() {
@@ -137,3 +147,110 @@ fn test_nonce_session_generator_propagates_entropy_error() {
     assert!(result.is_err());
     assert!(matches!(result, Err(EntropyError::EntropyNotAvailable)));
 }
+
+// =============================================================================
+// with_uniqueness_tracking()
+// =============================================================================
+
+#[test]
+fn test_generate_nonce_into_matches_owned_size() {
+    let entropy = MockEntropySource::new(MockEntropySourceBehaviour::None);
+    let mut session = NonceSessionGenerator::<_, 24>::new(entropy);
+    session.set_counter_for_test(7);
+
+    let owned = session
+        .generate_nonce()
+        .expect("Failed to generate_nonce()");
+    let mut into = [0u8; 24];
+    session
+        .generate_nonce_into(&mut into)
+        .expect("Failed to generate_nonce_into(..)");
+
+    assert_eq!(owned.len(), into.len());
+    // Both share the counter sequence
+    assert_eq!(owned[..size_of::<Counter>()], (7 as Counter).to_le_bytes());
+    assert_eq!(into[..size_of::<Counter>()], (8 as Counter).to_le_bytes());
+}
+
+#[test]
+fn test_uniqueness_tracking_detects_repeat() {
+    let mut session =
+        NonceSessionGenerator::<_, 16>::with_uniqueness_tracking(FixedEntropySource, 8);
+    session.set_counter_for_test(7);
+
+    session
+        .generate_nonce()
+        .expect("Failed to generate_nonce() (#0)");
+
+    // Replaying the counter with a fixed suffix reproduces the same nonce
+    session.set_counter_for_test(7);
+    let result = session.generate_nonce();
+
+    assert!(matches!(result, Err(EntropyError::NonceCollision)));
+}
+
+#[test]
+fn test_uniqueness_tracking_accepts_distinct_nonces() {
+    let mut session =
+        NonceSessionGenerator::<_, 16>::with_uniqueness_tracking(FixedEntropySource, 4);
+    session.set_counter_for_test(0);
+
+    // Counter keeps nonces distinct even though the suffix never changes
+    for i in 0..16 {
+        session
+            .generate_nonce()
+            .unwrap_or_else(|_| panic!("Failed to generate_nonce() (#{i})"));
+    }
+}
+
+#[test]
+fn test_uniqueness_tracking_forgets_evicted_nonces() {
+    let mut session =
+        NonceSessionGenerator::<_, 16>::with_uniqueness_tracking(FixedEntropySource, 2);
+    session.set_counter_for_test(0);
+
+    for i in 0..3 {
+        session
+            .generate_nonce()
+            .unwrap_or_else(|_| panic!("Failed to generate_nonce() (#{i})"));
+    }
+
+    // Counter 0 was evicted (capacity 2), counter 2 is still remembered
+    session.set_counter_for_test(0);
+    assert!(session.generate_nonce().is_ok());
+
+    session.set_counter_for_test(2);
+    assert!(matches!(
+        session.generate_nonce(),
+        Err(EntropyError::NonceCollision)
+    ));
+}
+
+#[test]
+fn test_uniqueness_tracking_zero_capacity_disables_tracking() {
+    let mut session =
+        NonceSessionGenerator::<_, 16>::with_uniqueness_tracking(FixedEntropySource, 0);
+
+    session.set_counter_for_test(7);
+    assert!(session.generate_nonce().is_ok());
+
+    session.set_counter_for_test(7);
+    assert!(session.generate_nonce().is_ok());
+}
+
+#[test]
+fn test_without_tracking_repeat_is_not_detected() {
+    let mut session = NonceSessionGenerator::<_, 16>::new(FixedEntropySource);
+
+    session.set_counter_for_test(7);
+    let first = session
+        .generate_nonce()
+        .expect("Failed to generate_nonce() (#0)");
+
+    session.set_counter_for_test(7);
+    let second = session
+        .generate_nonce()
+        .expect("Failed to generate_nonce() (#1)");
+
+    assert_eq!(first, second);
+}
@@ -4,3 +4,4 @@
 
 mod mock_entropy_source;
 mod mock_nonce_session_generator;
+mod seeded_entropy_source;
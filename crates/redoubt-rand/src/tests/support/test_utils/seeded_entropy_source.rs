@@ -0,0 +1,72 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use crate::session::NonceSessionGenerator;
+use crate::support::test_utils::SeededEntropySource;
+use crate::traits::{EntropySource, NonceGenerator};
+
+#[test]
+fn test_seeded_entropy_source_same_seed_same_output() {
+    let a = SeededEntropySource::new([0x01u8; 32]);
+    let b = SeededEntropySource::new([0x01u8; 32]);
+
+    let mut out_a = [0u8; 200];
+    let mut out_b = [0u8; 200];
+
+    a.fill_bytes(&mut out_a).expect("Failed to fill_bytes(..)");
+    // Different read boundaries must not change the stream
+    for chunk in out_b.chunks_mut(13) {
+        b.fill_bytes(chunk).expect("Failed to fill_bytes(..)");
+    }
+
+    assert_eq!(out_a, out_b);
+}
+
+#[test]
+fn test_seeded_entropy_source_different_seeds_diverge() {
+    let a = SeededEntropySource::new([0x01u8; 32]);
+    let b = SeededEntropySource::new([0x02u8; 32]);
+
+    let mut out_a = [0u8; 64];
+    let mut out_b = [0u8; 64];
+
+    a.fill_bytes(&mut out_a).expect("Failed to fill_bytes(..)");
+    b.fill_bytes(&mut out_b).expect("Failed to fill_bytes(..)");
+
+    assert_ne!(out_a, out_b);
+}
+
+#[test]
+fn test_seeded_entropy_source_successive_reads_differ() {
+    let source = SeededEntropySource::new([0x01u8; 32]);
+
+    let mut first = [0u8; 32];
+    let mut second = [0u8; 32];
+
+    source
+        .fill_bytes(&mut first)
+        .expect("Failed to fill_bytes(..)");
+    source
+        .fill_bytes(&mut second)
+        .expect("Failed to fill_bytes(..)");
+
+    assert_ne!(first, second);
+}
+
+#[test]
+fn test_seeded_entropy_source_reproducible_nonce_session() {
+    let mut a = NonceSessionGenerator::<_, 24>::new(SeededEntropySource::new([0x03u8; 32]));
+    let mut b = NonceSessionGenerator::<_, 24>::new(SeededEntropySource::new([0x03u8; 32]));
+
+    for i in 0..4 {
+        let nonce_a = a
+            .generate_nonce()
+            .unwrap_or_else(|_| panic!("Failed to generate_nonce() (#{i})"));
+        let nonce_b = b
+            .generate_nonce()
+            .unwrap_or_else(|_| panic!("Failed to generate_nonce() (#{i})"));
+
+        assert_eq!(nonce_a, nonce_b);
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use core::cell::Cell;
+
+use crate::error::EntropyError;
+use crate::session::{Counter, NonceSessionGenerator};
+use crate::traits::{EntropySource, NonceGenerator};
+
+/// Entropy source that writes at most `max_chunk` bytes per call.
+struct ShortReadEntropySource {
+    max_chunk: usize,
+    calls: Cell<usize>,
+}
+
+impl ShortReadEntropySource {
+    fn new(max_chunk: usize) -> Self {
+        Self {
+            max_chunk,
+            calls: Cell::new(0),
+        }
+    }
+}
+
+impl EntropySource for ShortReadEntropySource {
+    fn fill_bytes(&self, _dest: &mut [u8]) -> Result<(), EntropyError> {
+        unreachable!("callers must go through fill_bytes_exact")
+    }
+
+    fn fill_bytes_partial(&self, dest: &mut [u8]) -> Result<usize, EntropyError> {
+        self.calls.set(self.calls.get() + 1);
+
+        let written = dest.len().min(self.max_chunk);
+        dest[..written].fill(0xAB);
+
+        Ok(written)
+    }
+}
+
+/// Entropy source that never makes progress.
+struct StalledEntropySource;
+
+impl EntropySource for StalledEntropySource {
+    fn fill_bytes(&self, _dest: &mut [u8]) -> Result<(), EntropyError> {
+        Ok(())
+    }
+
+    fn fill_bytes_partial(&self, _dest: &mut [u8]) -> Result<usize, EntropyError> {
+        Ok(0)
+    }
+}
+
+/// Entropy source relying on the default `fill_bytes_partial`.
+struct FullEntropySource;
+
+impl EntropySource for FullEntropySource {
+    fn fill_bytes(&self, dest: &mut [u8]) -> Result<(), EntropyError> {
+        dest.fill(0xCD);
+        Ok(())
+    }
+}
+
+// =============================================================================
+// fill_bytes_exact()
+// =============================================================================
+
+#[test]
+fn test_fill_bytes_exact_retries_short_reads() {
+    let entropy = ShortReadEntropySource::new(3);
+    let mut buf = [0u8; 10];
+
+    entropy
+        .fill_bytes_exact(&mut buf)
+        .expect("Failed to fill_bytes_exact(..)");
+
+    assert_eq!(buf, [0xAB; 10]);
+    assert_eq!(entropy.calls.get(), 4);
+}
+
+#[test]
+fn test_fill_bytes_exact_default_partial_fills_in_one_call() {
+    let mut buf = [0u8; 16];
+
+    FullEntropySource
+        .fill_bytes_exact(&mut buf)
+        .expect("Failed to fill_bytes_exact(..)");
+
+    assert_eq!(buf, [0xCD; 16]);
+}
+
+#[test]
+fn test_fill_bytes_exact_no_progress_fails() {
+    let mut buf = [0u8; 8];
+
+    let result = StalledEntropySource.fill_bytes_exact(&mut buf);
+
+    assert!(matches!(result, Err(EntropyError::EntropyNotAvailable)));
+}
+
+#[test]
+fn test_fill_bytes_exact_empty_buffer_ok() {
+    let mut buf = [];
+
+    assert!(StalledEntropySource.fill_bytes_exact(&mut buf).is_ok());
+}
+
+#[test]
+fn test_nonce_session_generator_fills_nonce_across_short_reads() {
+    let mut session = NonceSessionGenerator::<_, 24>::new(ShortReadEntropySource::new(5));
+
+    let nonce = session
+        .generate_nonce()
+        .expect("Failed to generate_nonce()");
+
+    // Counter initialization and the random suffix both completed
+    assert_eq!(nonce[..size_of::<Counter>()], [0xAB; size_of::<Counter>()]);
+    assert!(nonce[size_of::<Counter>()..].iter().all(|b| *b == 0xAB));
+}
@@ -16,6 +16,46 @@ pub trait EntropySource {
     /// Returns [`EntropyError::EntropyNotAvailable`] if the system entropy source
     /// is unavailable or fails to generate random data.
     fn fill_bytes(&self, dest: &mut [u8]) -> Result<(), EntropyError>;
+
+    /// Fills a prefix of `dest`, returning how many bytes were written.
+    ///
+    /// Sources backed by a primitive that may return short reads override
+    /// this. The default fills the whole buffer via [`fill_bytes`](Self::fill_bytes).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`fill_bytes`](Self::fill_bytes).
+    fn fill_bytes_partial(&self, dest: &mut [u8]) -> Result<usize, EntropyError> {
+        self.fill_bytes(dest)?;
+        Ok(dest.len())
+    }
+
+    /// Fills all of `dest`, retrying after short reads.
+    ///
+    /// Calls [`fill_bytes_partial`](Self::fill_bytes_partial) on the unfilled
+    /// remainder until the whole buffer is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error reported by the source, or
+    /// [`EntropyError::EntropyNotAvailable`] if a call makes no progress
+    /// (returns `0`, or claims more bytes than requested).
+    fn fill_bytes_exact(&self, dest: &mut [u8]) -> Result<(), EntropyError> {
+        let mut filled = 0;
+
+        while filled < dest.len() {
+            let remaining = &mut dest[filled..];
+            let written = self.fill_bytes_partial(remaining)?;
+
+            if written == 0 || written > remaining.len() {
+                return Err(EntropyError::EntropyNotAvailable);
+            }
+
+            filled += written;
+        }
+
+        Ok(())
+    }
 }
 
 /// Trait for XChaCha20 nonce generators (192-bit nonces).
@@ -35,5 +75,20 @@ pub trait NonceGenerator<const N: usize> {
     ///
     /// Returns [`EntropyError::EntropyNotAvailable`] if the underlying entropy
     /// source fails to provide random data.
-    fn generate_nonce(&mut self) -> Result<[u8; N], EntropyError>;
+    fn generate_nonce(&mut self) -> Result<[u8; N], EntropyError> {
+        let mut nonce = [0u8; N];
+        self.generate_nonce_into(&mut nonce)?;
+        Ok(nonce)
+    }
+
+    /// Writes a unique nonce into a caller-provided array.
+    ///
+    /// Allocation-free counterpart of [`generate_nonce`](Self::generate_nonce)
+    /// for hot AEAD loops. On error the contents of `out` are unspecified and
+    /// must not be used as a nonce.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`generate_nonce`](Self::generate_nonce).
+    fn generate_nonce_into(&mut self, out: &mut [u8; N]) -> Result<(), EntropyError>;
 }
@@ -0,0 +1,57 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! Tests that `generate_nonce_into` does not touch the heap.
+//!
+//! Lives in its own test binary because it installs a counting global allocator.
+
+#[cfg(test)]
+mod nonce_into_alloc {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use redoubt_rand::{NonceGenerator, NonceSessionGenerator, SystemEntropySource};
+
+    static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+    /// Counts every allocation made by the process.
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+            // SAFETY: Caller upholds the `GlobalAlloc::alloc` contract
+            unsafe { System.alloc(layout) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            // SAFETY: Caller upholds the `GlobalAlloc::dealloc` contract
+            unsafe { System.dealloc(ptr, layout) }
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn test_generate_nonce_into_does_not_allocate() {
+        let mut session = NonceSessionGenerator::<_, 24>::new(SystemEntropySource::default());
+        let mut nonce = [0u8; 24];
+
+        // Warm up: lazy counter initialization
+        session
+            .generate_nonce_into(&mut nonce)
+            .expect("Failed to generate_nonce_into(..)");
+
+        let before = ALLOCATIONS.load(Ordering::SeqCst);
+        for _ in 0..64 {
+            session
+                .generate_nonce_into(&mut nonce)
+                .expect("Failed to generate_nonce_into(..)");
+        }
+        let after = ALLOCATIONS.load(Ordering::SeqCst);
+
+        assert_eq!(before, after);
+    }
+}
@@ -0,0 +1,20 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! Error types for redoubt-secret.
+
+use thiserror::Error;
+
+/// Error type for `RedoubtSecret` constructors.
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum RedoubtSecretError {
+    /// The source slice length does not match the secret's fixed length.
+    #[error("Length mismatch: expected {expected} bytes, found {found}")]
+    LengthMismatch {
+        /// Length the secret requires.
+        expected: usize,
+        /// Length of the provided slice.
+        found: usize,
+    },
+}
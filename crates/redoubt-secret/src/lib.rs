@@ -4,6 +4,12 @@
 
 //! Wrapper type that prevents accidental exposure of sensitive data.
 //!
+//! ## Features
+//!
+//! - `serde`: implements `Serialize`/`Deserialize` for [`RedoubtSecret`] as its
+//!   codec-encoded bytes. **The serialized output is the raw plaintext secret**;
+//!   only write it to a stream you encrypt or a buffer you zeroize.
+//!
 //! ## License
 //!
 //! GPL-3.0-only
@@ -15,12 +21,20 @@
 extern crate alloc;
 
 use alloc::boxed::Box;
+use core::marker::PhantomData;
 
 #[cfg(test)]
 mod tests;
 
+mod error;
+#[cfg(feature = "serde")]
+mod serde_support;
+
+pub use error::RedoubtSecretError;
+
 use core::fmt;
 
+use redoubt_alloc::ThreadMarker;
 use redoubt_codec::{BytesRequired, Decode, Encode, RedoubtCodec};
 use redoubt_zero::{FastZeroizable, RedoubtZero, ZeroizationProbe, ZeroizeOnDropSentinel};
 
@@ -33,9 +47,13 @@ use redoubt_zero::{FastZeroizable, RedoubtZero, ZeroizationProbe, ZeroizeOnDropS
 /// # Design Principles
 ///
 /// - **No `Deref`/`DerefMut`**: Prevents accidental copies of `Copy` types via `*secret`
-/// - **No `Clone`**: Prevents unintended copies of sensitive data
+/// - **No `Clone`**: Prevents unintended copies of sensitive data; intended copies go
+///   through [`clone_secret()`](RedoubtSecret::clone_secret)
 /// - **Redacted `Debug`**: Prints `[REDACTED RedoubtSecret]` instead of inner value
+/// - **No `PartialEq`**: A derived `==` short-circuits on the first differing byte and
+///   leaks how much of two secrets match; use [`ct_eq()`](RedoubtSecret::ct_eq) instead
 /// - **Drop verification**: Contains [`ZeroizeOnDropSentinel`] to verify zeroization happened
+/// - **Optional thread-locality**: `!Send + !Sync` with the `single-thread` feature
 ///
 /// # Usage
 ///
@@ -60,6 +78,29 @@ use redoubt_zero::{FastZeroizable, RedoubtZero, ZeroizationProbe, ZeroizeOnDropS
 /// assert_eq!(secret2.as_ref(), &0xDEADBEEF);
 /// ```
 ///
+/// # Encoding consumes the secret
+///
+/// With the (default) `zeroize` feature of `redoubt-codec`, encoding is a
+/// drain: `encode_into` copies the boxed inner value straight from the heap
+/// into the [`RedoubtCodecBuffer`](redoubt_codec::RedoubtCodecBuffer), with no
+/// intermediate plaintext copy, and then zeroizes it. Decoding likewise
+/// zeroizes the consumed input bytes. No secret survives encoding: keep the
+/// encoded buffer (or decode it back) if the value is still needed.
+///
+/// ```rust
+/// use redoubt_codec::{BytesRequired, Encode, RedoubtCodecBuffer};
+/// use redoubt_secret::RedoubtSecret;
+/// use redoubt_zero::ZeroizationProbe;
+///
+/// let mut secret = RedoubtSecret::from(&mut 0xDEADBEEFu64);
+/// let mut buf = RedoubtCodecBuffer::with_capacity(secret.encode_bytes_required().unwrap());
+///
+/// secret.encode_into(&mut buf).unwrap();
+///
+/// // The secret was drained into the buffer
+/// assert!(secret.is_zeroized());
+/// ```
+///
 /// # ⚠️ Warning: Dereferencing with Copy types
 ///
 /// **NEVER** dereference `as_ref()` or `as_mut()` when `T` implements `Copy`.
@@ -78,12 +119,15 @@ use redoubt_zero::{FastZeroizable, RedoubtZero, ZeroizationProbe, ZeroizeOnDropS
 /// // ✅ SAFE: Only uses a reference
 /// assert_eq!(secret.as_ref(), &0xDEADBEEF);
 /// ```
-#[derive(PartialEq, Eq, RedoubtZero, RedoubtCodec)]
+#[derive(RedoubtZero, RedoubtCodec)]
 pub struct RedoubtSecret<T>
 where
     T: FastZeroizable + ZeroizationProbe + Encode + Decode + BytesRequired,
 {
     inner: Box<T>,
+    #[fast_zeroize(skip)]
+    #[codec(default)]
+    __thread_marker: ThreadMarker,
     #[codec(default)]
     __sentinel: ZeroizeOnDropSentinel,
 }
@@ -95,6 +139,7 @@ where
     fn default() -> Self {
         Self {
             inner: Box::new(T::default()),
+            __thread_marker: PhantomData,
             __sentinel: ZeroizeOnDropSentinel::default(),
         }
     }
@@ -143,6 +188,7 @@ where
 
         Self {
             inner: Box::new(value),
+            __thread_marker: PhantomData,
             __sentinel: ZeroizeOnDropSentinel::default(),
         }
     }
@@ -182,6 +228,231 @@ where
         // Replace Box content
         *self.inner = new_value;
     }
+
+    /// Deep-copies the secret into a new, independently heap-allocated `RedoubtSecret`.
+    ///
+    /// `RedoubtSecret` deliberately does not implement `Clone`. This method is the
+    /// explicit escape hatch for the cases that need a second copy, such as forking
+    /// a derived key. After the call there are **two** live copies of the secret and
+    /// the caller is responsible for both; each one is zeroized when dropped.
+    ///
+    /// The clone is written into a fresh heap allocation via `clone_from`, the same
+    /// way [`from()`](RedoubtSecret::from) keeps the value off the stack.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use redoubt_secret::RedoubtSecret;
+    ///
+    /// let root = RedoubtSecret::from(&mut [0xAB; 32]);
+    /// let mut fork = root.clone_secret();
+    ///
+    /// fork.as_mut()[0] = 0xCD;
+    ///
+    /// assert_eq!(root.as_ref()[0], 0xAB);
+    /// assert_eq!(fork.as_ref()[0], 0xCD);
+    /// ```
+    #[inline(never)]
+    pub fn clone_secret(&self) -> Self
+    where
+        T: Clone + Default,
+    {
+        let mut inner = Box::new(T::default());
+        inner.clone_from(&self.inner);
+
+        Self {
+            inner,
+            __thread_marker: PhantomData,
+            __sentinel: ZeroizeOnDropSentinel::default(),
+        }
+    }
+
+    /// Exchanges the contents of two secrets.
+    ///
+    /// Only the heap pointers are swapped, so neither plaintext is moved or
+    /// copied. Each secret keeps its own [`ZeroizeOnDropSentinel`]. Useful for
+    /// double-buffering derived keys.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use redoubt_secret::RedoubtSecret;
+    ///
+    /// let mut current = RedoubtSecret::from(&mut [0xAA; 32]);
+    /// let mut next = RedoubtSecret::from(&mut [0xBB; 32]);
+    ///
+    /// current.swap(&mut next);
+    ///
+    /// assert_eq!(current.as_ref(), &[0xBB; 32]);
+    /// assert_eq!(next.as_ref(), &[0xAA; 32]);
+    /// ```
+    #[inline]
+    pub fn swap(&mut self, other: &mut Self) {
+        core::mem::swap(&mut self.inner, &mut other.inner);
+    }
+
+    /// Transforms the inner value in place.
+    ///
+    /// The closure works directly on the heap-allocated value, so no copy of
+    /// the secret is made. Prefer this over dereferencing
+    /// [`as_mut()`](RedoubtSecret::as_mut), where a stray `*` on a `Copy` type
+    /// silently duplicates the secret onto the stack.
+    ///
+    /// If `T` owns sub-allocations, growing them inside the closure reallocates
+    /// without wiping the old block; use containers such as `RedoubtVec` that
+    /// zeroize on growth.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use redoubt_secret::RedoubtSecret;
+    ///
+    /// let mut counter = RedoubtSecret::from(&mut 41u64);
+    /// counter.map_in_place(|value| *value += 1);
+    ///
+    /// assert_eq!(counter.as_ref(), &42);
+    /// ```
+    #[inline]
+    pub fn map_in_place<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut T),
+    {
+        f(&mut self.inner);
+    }
+
+    /// Fallible variant of [`map_in_place()`](RedoubtSecret::map_in_place).
+    ///
+    /// Returns whatever the closure returns. On error the inner value keeps any
+    /// changes the closure made before failing.
+    #[inline]
+    pub fn try_map_in_place<F, R, E>(&mut self, f: F) -> Result<R, E>
+    where
+        F: FnOnce(&mut T) -> Result<R, E>,
+    {
+        f(&mut self.inner)
+    }
+}
+
+impl<T, const N: usize> RedoubtSecret<[T; N]>
+where
+    T: Default,
+    [T; N]: FastZeroizable + ZeroizationProbe + Encode + Decode + BytesRequired,
+{
+    /// Creates a new `RedoubtSecret` by moving an array of any length, zeroizing the source.
+    ///
+    /// [`from()`](RedoubtSecret::from) requires `[T; N]: Default`, which `core` only
+    /// provides for `N <= 32`. This constructor only needs `T: Default`, so arrays such
+    /// as `[u8; 48]` or `[u8; 64]` can be wrapped without manual impls.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use redoubt_secret::RedoubtSecret;
+    ///
+    /// let mut seed = [0xAB; 64];
+    /// let secret = RedoubtSecret::from_mut_array(&mut seed);
+    ///
+    /// // seed is guaranteed to be zeroized
+    /// assert_eq!(seed, [0u8; 64]);
+    /// assert_eq!(secret.as_ref(), &[0xAB; 64]);
+    /// ```
+    #[inline(never)]
+    pub fn from_mut_array(src: &mut [T; N]) -> Self {
+        let mut secret = Self {
+            inner: Box::new(core::array::from_fn(|_| T::default())),
+            __thread_marker: PhantomData,
+            __sentinel: ZeroizeOnDropSentinel::default(),
+        };
+        secret.replace_from_mut_array(src);
+        secret
+    }
+
+    /// Replaces the inner array with `src`, zeroizing both the old value and the source.
+    ///
+    /// Uses `ptr::swap_nonoverlapping` to exchange contents with the source
+    /// without creating intermediate copies that could spill to stack.
+    pub fn replace_from_mut_array(&mut self, src: &mut [T; N]) {
+        // Zeroize old value
+        self.inner.fast_zeroize();
+
+        unsafe {
+            // SAFETY: Both arrays have exactly N elements and are properly aligned
+            core::ptr::swap_nonoverlapping(src.as_mut_ptr(), self.inner.as_mut_ptr(), N);
+        }
+
+        // Zeroize source (which now contains the old, already zeroized, inner values)
+        src.fast_zeroize();
+    }
+}
+
+impl<const N: usize> RedoubtSecret<[u8; N]>
+where
+    [u8; N]: FastZeroizable + ZeroizationProbe + Encode + Decode + BytesRequired,
+{
+    /// Creates a new `RedoubtSecret` from a runtime-length slice, zeroizing the source.
+    ///
+    /// Fails with [`RedoubtSecretError::LengthMismatch`] unless `data.len() == N`.
+    /// The length is checked before anything is copied, so no partial secret is
+    /// ever built. The source slice is zeroized on both the success and the error path.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use redoubt_secret::{RedoubtSecret, RedoubtSecretError};
+    ///
+    /// let mut decoded = vec![0xAB; 32];
+    /// let secret = RedoubtSecret::<[u8; 32]>::try_from_slice(&mut decoded).unwrap();
+    ///
+    /// assert!(decoded.iter().all(|b| *b == 0));
+    /// assert_eq!(secret.as_ref(), &[0xAB; 32]);
+    ///
+    /// let mut short = vec![0xAB; 16];
+    /// let result = RedoubtSecret::<[u8; 32]>::try_from_slice(&mut short);
+    ///
+    /// assert_eq!(
+    ///     result.unwrap_err(),
+    ///     RedoubtSecretError::LengthMismatch { expected: 32, found: 16 }
+    /// );
+    /// ```
+    pub fn try_from_slice(data: &mut [u8]) -> Result<Self, RedoubtSecretError> {
+        let found = data.len();
+
+        match <&mut [u8; N]>::try_from(&mut *data) {
+            Ok(array) => Ok(Self::from_mut_array(array)),
+            Err(_) => {
+                data.fast_zeroize();
+                Err(RedoubtSecretError::LengthMismatch { expected: N, found })
+            }
+        }
+    }
+}
+
+impl<T> RedoubtSecret<T>
+where
+    T: FastZeroizable + ZeroizationProbe + Encode + Decode + BytesRequired + AsRef<[u8]>,
+{
+    /// Compares two secrets in constant time.
+    ///
+    /// The running time depends only on the byte length, never on the contents,
+    /// so a timing observer cannot learn how many leading bytes match. Secrets of
+    /// different lengths compare unequal immediately.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use redoubt_secret::RedoubtSecret;
+    ///
+    /// let a = RedoubtSecret::from(&mut [0xAB; 32]);
+    /// let b = RedoubtSecret::from(&mut [0xAB; 32]);
+    /// let c = RedoubtSecret::from(&mut [0xCD; 32]);
+    ///
+    /// assert!(a.ct_eq(&b));
+    /// assert!(!a.ct_eq(&c));
+    /// ```
+    #[inline]
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        redoubt_util::constant_time_eq((*self.inner).as_ref(), (*other.inner).as_ref())
+    }
 }
 
 impl<T> AsRef<T> for RedoubtSecret<T>
@@ -0,0 +1,154 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! `serde` support for [`RedoubtSecret`] (behind the `serde` feature).
+//!
+//! # WARNING: the serialized output is the raw plaintext
+//!
+//! A secret serializes to its codec encoding, emitted as a byte array. Those
+//! bytes ARE the secret: nothing is encrypted, and whatever the serializer
+//! writes them to (a file, a socket, an intermediate `Vec<u8>`) now holds an
+//! unprotected copy outside this crate's control. Only serialize into a
+//! stream the caller encrypts, or into a buffer the caller zeroizes.
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{self, Serialize, Serializer};
+
+use redoubt_alloc::RedoubtVec;
+use redoubt_codec::{BytesRequired, Decode, Encode, RedoubtCodecBuffer};
+use redoubt_zero::{FastZeroizable, ZeroizationProbe};
+
+use crate::RedoubtSecret;
+
+/// Upper bound on the capacity preallocated from a sequence's `size_hint`.
+///
+/// The hint comes from the input, so it is only trusted up to this many
+/// bytes; longer sequences grow the buffer as elements arrive.
+const MAX_PREALLOC_BYTES: usize = 4096;
+
+impl<T> Serialize for RedoubtSecret<T>
+where
+    T: FastZeroizable + ZeroizationProbe + Encode + Decode + BytesRequired + Clone + Default,
+{
+    /// Encodes the secret and emits the bytes with `serialize_bytes`.
+    ///
+    /// Encoding zeroizes its source, so a [`clone_secret()`](RedoubtSecret::clone_secret)
+    /// copy is encoded instead of `self`. The copy and the encode buffer are
+    /// zeroized before returning.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut copy = self.clone_secret();
+
+        let bytes_required = copy.encode_bytes_required().map_err(ser::Error::custom)?;
+        let mut buf = RedoubtCodecBuffer::with_capacity(bytes_required);
+
+        copy.encode_into(&mut buf).map_err(ser::Error::custom)?;
+
+        // buf is zeroized on drop
+        serializer.serialize_bytes(buf.as_slice())
+    }
+}
+
+impl<'de, T> Deserialize<'de> for RedoubtSecret<T>
+where
+    T: FastZeroizable + ZeroizationProbe + Encode + Decode + BytesRequired + Default,
+{
+    /// Decodes a secret from a byte array produced by [`Serialize`].
+    ///
+    /// The bytes are copied into an owned buffer that decoding zeroizes as it
+    /// consumes them. Owned input (`visit_byte_buf`) is zeroized as well.
+    /// Borrowed input belongs to the deserializer and is left untouched.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(SecretVisitor(PhantomData))
+    }
+}
+
+struct SecretVisitor<T>(PhantomData<T>);
+
+impl<T> SecretVisitor<T>
+where
+    T: FastZeroizable + ZeroizationProbe + Encode + Decode + BytesRequired + Default,
+{
+    fn decode<E>(bytes: &mut [u8]) -> Result<RedoubtSecret<T>, E>
+    where
+        E: de::Error,
+    {
+        let mut secret = RedoubtSecret::<T>::default();
+        let mut cursor = &mut *bytes;
+
+        let result = secret.decode_from(&mut cursor);
+        let trailing = cursor.len();
+
+        bytes.fast_zeroize();
+
+        result.map_err(de::Error::custom)?;
+
+        if trailing != 0 {
+            return Err(de::Error::custom(format_args!(
+                "{trailing} trailing bytes after encoded RedoubtSecret"
+            )));
+        }
+
+        Ok(secret)
+    }
+}
+
+impl<'de, T> Visitor<'de> for SecretVisitor<T>
+where
+    T: FastZeroizable + ZeroizationProbe + Encode + Decode + BytesRequired + Default,
+{
+    type Value = RedoubtSecret<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an encoded RedoubtSecret byte array")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        // Exact capacity: the copy never reallocates
+        let mut bytes = Vec::with_capacity(v.len());
+        bytes.extend_from_slice(v);
+
+        let result = Self::decode(&mut bytes);
+        redoubt_util::fast_zeroize_vec(&mut bytes);
+
+        result
+    }
+
+    fn visit_byte_buf<E>(self, mut v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let result = Self::decode(&mut v);
+        redoubt_util::fast_zeroize_vec(&mut v);
+
+        result
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        // RedoubtVec zeroizes the old allocation whenever it grows
+        let capacity = seq.size_hint().unwrap_or(0).min(MAX_PREALLOC_BYTES);
+        let mut bytes = RedoubtVec::<u8>::with_capacity(capacity);
+
+        while let Some(mut byte) = seq.next_element::<u8>()? {
+            bytes.drain_value(&mut byte);
+        }
+
+        Self::decode(bytes.as_mut_slice())
+    }
+}
@@ -2,9 +2,10 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // See LICENSE in the repository root for full license text.
 
-use redoubt_zero::ZeroizationProbe;
+use redoubt_codec::{BytesRequired, Decode, Encode, RedoubtCodecBuffer};
+use redoubt_zero::{FastZeroizable, ZeroizationProbe};
 
-use crate::RedoubtSecret;
+use crate::{RedoubtSecret, RedoubtSecretError};
 
 #[test]
 fn test_secret_assert_zeroization_probe_trait() {
@@ -80,3 +81,215 @@ fn test_secret_replace() {
     assert!(new_data.iter().all(|&b| b == 0));
     assert_eq!(secret.as_ref(), &vec![10u8, 20, 30]);
 }
+
+#[cfg(not(feature = "single-thread"))]
+#[test]
+fn test_secret_is_send_sync_without_single_thread() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<RedoubtSecret<u64>>();
+}
+
+#[test]
+fn test_secret_from_mut_array_any_length() {
+    fn check<const N: usize>() {
+        let mut src = [0u8; N];
+        for (i, byte) in src.iter_mut().enumerate() {
+            *byte = (i as u8).wrapping_add(1);
+        }
+        let expected = src;
+
+        let secret = RedoubtSecret::from_mut_array(&mut src);
+
+        assert!(src.is_zeroized());
+        assert_eq!(secret.as_ref(), &expected);
+    }
+
+    check::<1>();
+    check::<32>();
+    check::<33>();
+    check::<48>();
+    check::<64>();
+    check::<256>();
+}
+
+#[test]
+fn test_secret_replace_from_mut_array() {
+    let mut first = [1u8; 48];
+    let mut secret = RedoubtSecret::from_mut_array(&mut first);
+    assert!(first.is_zeroized());
+
+    let mut second = [2u8; 48];
+    secret.replace_from_mut_array(&mut second);
+
+    assert!(second.is_zeroized());
+    assert_eq!(secret.as_ref(), &[2u8; 48]);
+}
+
+#[test]
+fn test_secret_encode_drains_inner_value() {
+    let mut secret = RedoubtSecret::from(&mut [0xABu8; 32]);
+    let bytes_required = secret
+        .encode_bytes_required()
+        .expect("Failed to encode_bytes_required()");
+    let mut buf = RedoubtCodecBuffer::with_capacity(bytes_required);
+
+    secret
+        .encode_into(&mut buf)
+        .expect("Failed to encode_into(..)");
+
+    assert!(secret.is_zeroized());
+    assert!(buf.as_slice().ends_with(&[0xAB; 32]));
+}
+
+#[test]
+fn test_secret_encode_decode_roundtrip_zeroizes_both_sides() {
+    let mut secret = RedoubtSecret::from(&mut vec![1u8, 2, 3, 4]);
+    let bytes_required = secret
+        .encode_bytes_required()
+        .expect("Failed to encode_bytes_required()");
+    let mut buf = RedoubtCodecBuffer::with_capacity(bytes_required);
+
+    secret
+        .encode_into(&mut buf)
+        .expect("Failed to encode_into(..)");
+    assert!(secret.is_zeroized());
+
+    let mut decoded = RedoubtSecret::<Vec<u8>>::default();
+    let mut encoded = buf.export_as_vec();
+
+    decoded
+        .decode_from(&mut encoded.as_mut_slice())
+        .expect("Failed to decode_from(..)");
+
+    assert_eq!(decoded.as_ref(), &vec![1, 2, 3, 4]);
+    assert!(encoded.is_zeroized());
+}
+
+#[test]
+fn test_secret_try_from_slice_exact() {
+    let mut data = vec![0xAB; 32];
+    let secret =
+        RedoubtSecret::<[u8; 32]>::try_from_slice(&mut data).expect("Failed to try_from_slice(..)");
+
+    assert!(data.iter().all(|&b| b == 0));
+    assert_eq!(secret.as_ref(), &[0xAB; 32]);
+}
+
+#[test]
+fn test_secret_try_from_slice_short() {
+    let mut data = vec![0xAB; 31];
+    let result = RedoubtSecret::<[u8; 32]>::try_from_slice(&mut data);
+
+    assert_eq!(
+        result.unwrap_err(),
+        RedoubtSecretError::LengthMismatch {
+            expected: 32,
+            found: 31
+        }
+    );
+    assert!(data.iter().all(|&b| b == 0));
+}
+
+#[test]
+fn test_secret_try_from_slice_long() {
+    let mut data = vec![0xAB; 33];
+    let result = RedoubtSecret::<[u8; 32]>::try_from_slice(&mut data);
+
+    assert_eq!(
+        result.unwrap_err(),
+        RedoubtSecretError::LengthMismatch {
+            expected: 32,
+            found: 33
+        }
+    );
+    assert!(data.iter().all(|&b| b == 0));
+}
+
+#[test]
+fn test_secret_clone_secret_is_independent() {
+    let mut original = RedoubtSecret::from(&mut vec![1u8, 2, 3, 4]);
+    let mut clone = original.clone_secret();
+
+    assert_eq!(clone.as_ref(), original.as_ref());
+    assert_ne!(clone.as_ref().as_ptr(), original.as_ref().as_ptr());
+
+    clone.as_mut()[0] = 42;
+    assert_eq!(original.as_ref(), &vec![1u8, 2, 3, 4]);
+
+    original.as_mut().push(5);
+    assert_eq!(clone.as_ref(), &vec![42u8, 2, 3, 4]);
+
+    original.fast_zeroize();
+    assert!(original.is_zeroized());
+    assert!(!clone.is_zeroized());
+}
+
+#[test]
+fn test_secret_swap() {
+    let mut a = RedoubtSecret::from(&mut vec![1u8, 2, 3]);
+    let mut b = RedoubtSecret::from(&mut vec![4u8, 5, 6, 7]);
+
+    let a_ptr = a.as_ref().as_ptr();
+    let b_ptr = b.as_ref().as_ptr();
+
+    a.swap(&mut b);
+
+    assert_eq!(a.as_ref(), &vec![4u8, 5, 6, 7]);
+    assert_eq!(b.as_ref(), &vec![1u8, 2, 3]);
+
+    // No plaintext was moved: each secret now owns the other's allocation
+    assert_eq!(a.as_ref().as_ptr(), b_ptr);
+    assert_eq!(b.as_ref().as_ptr(), a_ptr);
+}
+
+#[test]
+fn test_secret_map_in_place() {
+    let mut counter = RedoubtSecret::from(&mut 41u64);
+
+    counter.map_in_place(|value| *value += 1);
+
+    assert_eq!(counter.as_ref(), &42);
+}
+
+#[test]
+fn test_secret_try_map_in_place() {
+    let mut counter = RedoubtSecret::from(&mut (u64::MAX - 1));
+
+    let result: Result<u64, &str> = counter.try_map_in_place(|value| {
+        *value = value.checked_add(1).ok_or("overflow")?;
+        Ok(*value)
+    });
+    assert_eq!(result, Ok(u64::MAX));
+
+    let result: Result<u64, &str> = counter.try_map_in_place(|value| {
+        *value = value.checked_add(1).ok_or("overflow")?;
+        Ok(*value)
+    });
+    assert_eq!(result, Err("overflow"));
+    assert_eq!(counter.as_ref(), &u64::MAX);
+}
+
+#[test]
+fn test_secret_ct_eq() {
+    let a = RedoubtSecret::from(&mut [0xAB; 32]);
+    let b = RedoubtSecret::from(&mut [0xAB; 32]);
+
+    let mut last_differs = [0xAB; 32];
+    last_differs[31] = 0xAC;
+    let c = RedoubtSecret::from(&mut last_differs);
+    let d = RedoubtSecret::from(&mut [0x00; 32]);
+
+    assert!(a.ct_eq(&b));
+    assert!(b.ct_eq(&a));
+    assert!(!a.ct_eq(&c));
+    assert!(!a.ct_eq(&d));
+}
+
+#[test]
+fn test_secret_ct_eq_different_lengths() {
+    let a = RedoubtSecret::from(&mut vec![1u8, 2, 3]);
+    let b = RedoubtSecret::from(&mut vec![1u8, 2, 3, 4]);
+
+    assert!(!a.ct_eq(&b));
+}
@@ -3,3 +3,5 @@
 // See LICENSE in the repository root for full license text.
 
 mod lib;
+#[cfg(feature = "serde")]
+mod serde_support;
@@ -0,0 +1,182 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use redoubt_codec::{BytesRequired, Encode, RedoubtCodecBuffer};
+
+use crate::RedoubtSecret;
+
+fn bincode_config() -> bincode::config::Configuration {
+    bincode::config::standard()
+}
+
+#[test]
+fn test_serde_roundtrip_u64() {
+    let secret = RedoubtSecret::from(&mut 0xDEADBEEFCAFEBABEu64);
+
+    let bytes = bincode::serde::encode_to_vec(&secret, bincode_config())
+        .expect("Failed to encode_to_vec(..)");
+    let (decoded, read): (RedoubtSecret<u64>, usize) =
+        bincode::serde::decode_from_slice(&bytes, bincode_config())
+            .expect("Failed to decode_from_slice(..)");
+
+    assert_eq!(read, bytes.len());
+    assert_eq!(decoded.as_ref(), &0xDEADBEEFCAFEBABE);
+}
+
+#[test]
+fn test_serde_roundtrip_vec() {
+    let secret = RedoubtSecret::from(&mut vec![1u8, 2, 3, 4, 5]);
+
+    let bytes = bincode::serde::encode_to_vec(&secret, bincode_config())
+        .expect("Failed to encode_to_vec(..)");
+    let (decoded, _): (RedoubtSecret<Vec<u8>>, usize) =
+        bincode::serde::decode_from_slice(&bytes, bincode_config())
+            .expect("Failed to decode_from_slice(..)");
+
+    assert_eq!(decoded.as_ref(), &vec![1u8, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_serde_serialize_preserves_source() {
+    let secret = RedoubtSecret::from(&mut [0xAB; 32]);
+
+    bincode::serde::encode_to_vec(&secret, bincode_config()).expect("Failed to encode_to_vec(..)");
+
+    assert_eq!(secret.as_ref(), &[0xAB; 32]);
+}
+
+#[test]
+fn test_serde_serialize_emits_codec_encoding() {
+    let secret = RedoubtSecret::from(&mut 0x0102030405060708u64);
+
+    let mut copy = secret.clone_secret();
+    let mut buf = RedoubtCodecBuffer::with_capacity(
+        copy.encode_bytes_required()
+            .expect("Failed to encode_bytes_required()"),
+    );
+    copy.encode_into(&mut buf)
+        .expect("Failed to encode_into(..)");
+
+    let bytes = bincode::serde::encode_to_vec(&secret, bincode_config())
+        .expect("Failed to encode_to_vec(..)");
+    let (payload, _): (Vec<u8>, usize) =
+        bincode::serde::decode_from_slice(&bytes, bincode_config())
+            .expect("Failed to decode_from_slice(..)");
+
+    assert_eq!(payload, buf.as_slice());
+}
+
+#[test]
+fn test_serde_deserialize_rejects_truncated_input() {
+    let secret = RedoubtSecret::from(&mut vec![1u8, 2, 3, 4, 5]);
+
+    let bytes = bincode::serde::encode_to_vec(&secret, bincode_config())
+        .expect("Failed to encode_to_vec(..)");
+    let (mut payload, _): (Vec<u8>, usize) =
+        bincode::serde::decode_from_slice(&bytes, bincode_config())
+            .expect("Failed to decode_from_slice(..)");
+    payload.pop();
+
+    let truncated = bincode::serde::encode_to_vec(serde_bytes(&payload), bincode_config())
+        .expect("Failed to encode_to_vec(..)");
+    let result = bincode::serde::decode_from_slice::<RedoubtSecret<Vec<u8>>, _>(
+        &truncated,
+        bincode_config(),
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_serde_deserialize_rejects_trailing_bytes() {
+    let secret = RedoubtSecret::from(&mut 7u32);
+
+    let bytes = bincode::serde::encode_to_vec(&secret, bincode_config())
+        .expect("Failed to encode_to_vec(..)");
+    let (mut payload, _): (Vec<u8>, usize) =
+        bincode::serde::decode_from_slice(&bytes, bincode_config())
+            .expect("Failed to decode_from_slice(..)");
+    payload.push(0xFF);
+
+    let padded = bincode::serde::encode_to_vec(serde_bytes(&payload), bincode_config())
+        .expect("Failed to encode_to_vec(..)");
+    let result =
+        bincode::serde::decode_from_slice::<RedoubtSecret<u32>, _>(&padded, bincode_config());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_serde_deserialize_seq_ignores_oversized_size_hint() {
+    let secret = RedoubtSecret::from(&mut 0xDEAD_BEEFu32);
+
+    let bytes = bincode::serde::encode_to_vec(&secret, bincode_config())
+        .expect("Failed to encode_to_vec(..)");
+    let (payload, _): (Vec<u8>, usize) =
+        bincode::serde::decode_from_slice(&bytes, bincode_config())
+            .expect("Failed to decode_from_slice(..)");
+
+    let deserializer = LyingSeqDeserializer {
+        bytes: payload.into_iter(),
+    };
+    let decoded = <RedoubtSecret<u32> as serde::Deserialize>::deserialize(deserializer)
+        .expect("Failed to deserialize(..)");
+
+    assert_eq!(decoded.as_ref(), &0xDEAD_BEEF);
+}
+
+/// Feeds bytes through `visit_seq` while claiming a `usize::MAX` length.
+struct LyingSeqDeserializer {
+    bytes: std::vec::IntoIter<u8>,
+}
+
+impl<'de> serde::Deserializer<'de> for LyingSeqDeserializer {
+    type Error = serde::de::value::Error;
+
+    fn deserialize_any<V: serde::de::Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de> serde::de::SeqAccess<'de> for LyingSeqDeserializer {
+    type Error = serde::de::value::Error;
+
+    fn next_element_seed<S: serde::de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, Self::Error> {
+        use serde::de::IntoDeserializer;
+
+        self.bytes
+            .next()
+            .map(|byte| seed.deserialize(byte.into_deserializer()))
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(usize::MAX)
+    }
+}
+
+/// Serializes a slice with `serialize_bytes`, matching the secret's wire form.
+fn serde_bytes(bytes: &[u8]) -> impl serde::Serialize + '_ {
+    struct Bytes<'a>(&'a [u8]);
+
+    impl serde::Serialize for Bytes<'_> {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(self.0)
+        }
+    }
+
+    Bytes(bytes)
+}
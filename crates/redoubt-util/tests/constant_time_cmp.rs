@@ -0,0 +1,45 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+#[cfg(test)]
+mod constant_time_cmp_tests {
+    use core::cmp::Ordering;
+
+    use redoubt_util::constant_time_cmp;
+
+    #[test]
+    fn test_equal_slices() {
+        assert_eq!(
+            constant_time_cmp(&[1, 2, 3, 4, 5], &[1, 2, 3, 4, 5]),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_empty_slices() {
+        let a: [u8; 0] = [];
+        assert_eq!(constant_time_cmp(&a, &a), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_first_difference_decides() {
+        assert_eq!(constant_time_cmp(&[1, 0xFF], &[2, 0x00]), Ordering::Less);
+        assert_eq!(constant_time_cmp(&[2, 0x00], &[1, 0xFF]), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_prefix_is_less() {
+        assert_eq!(constant_time_cmp(&[1, 2], &[1, 2, 0]), Ordering::Less);
+        assert_eq!(constant_time_cmp(&[1, 2, 0], &[1, 2]), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_matches_slice_cmp_exhaustive_byte_pairs() {
+        for x in 0..=255u8 {
+            for y in 0..=255u8 {
+                assert_eq!(constant_time_cmp(&[x], &[y]), x.cmp(&y), "{x} vs {y}");
+            }
+        }
+    }
+}
@@ -31,4 +31,21 @@ mod constant_time_eq_tests {
     fn test_single_byte_difference() {
         assert!(!constant_time_eq(&[0, 0, 0, 0, 0], &[0, 0, 1, 0, 0]));
     }
+
+    #[test]
+    fn test_word_boundary_lengths() {
+        for len in [7, 8, 9, 4096, 4097] {
+            let a: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let b = a.clone();
+            assert!(constant_time_eq(&a, &b), "len {len}: equal");
+
+            // Flip a single bit at the start, in the middle and at the end
+            // (the last byte lands in the remainder for non-multiple lengths).
+            for idx in [0, len / 2, len - 1] {
+                let mut c = a.clone();
+                c[idx] ^= 0x80;
+                assert!(!constant_time_eq(&a, &c), "len {len}: diff at {idx}");
+            }
+        }
+    }
 }
@@ -0,0 +1,50 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+#[cfg(test)]
+mod is_vec_fully_zeroized_ct_tests {
+    use redoubt_util::{fast_zeroize_vec, is_vec_fully_zeroized, is_vec_fully_zeroized_ct};
+
+    fn assert_same_result(vec: &Vec<u8>, expected: bool) {
+        assert_eq!(is_vec_fully_zeroized(vec), expected);
+        assert_eq!(is_vec_fully_zeroized_ct(vec), expected);
+    }
+
+    #[test]
+    fn test_is_vec_fully_zeroized_ct_empty() {
+        let vec: Vec<u8> = Vec::new();
+        assert_same_result(&vec, true);
+    }
+
+    #[test]
+    fn test_is_vec_fully_zeroized_ct_all_zeros() {
+        let vec = vec![0u8; 64];
+        assert_same_result(&vec, true);
+    }
+
+    #[test]
+    fn test_is_vec_fully_zeroized_ct_leading_nonzero() {
+        let mut vec = vec![0u8; 64];
+        vec[0] = 0xFF;
+        assert_same_result(&vec, false);
+    }
+
+    #[test]
+    fn test_is_vec_fully_zeroized_ct_trailing_nonzero() {
+        let mut vec = vec![0u8; 64];
+        vec[63] = 0x01;
+        assert_same_result(&vec, false);
+    }
+
+    #[test]
+    fn test_is_vec_fully_zeroized_ct_spare_capacity() {
+        let mut vec = vec![0u8, 0, 0, 0, 0xAA];
+        vec.truncate(2); // len = 2, capacity = 5, spare has 0xAA
+
+        assert_same_result(&vec, false);
+
+        fast_zeroize_vec(&mut vec);
+        assert_same_result(&vec, true);
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use redoubt_util::zeroize_primitive_array;
+
+#[test]
+fn test_zeroize_primitive_array_u64() {
+    let mut state = [
+        0x6a09e667f3bcc908u64,
+        0xbb67ae8584caa73b,
+        0x3c6ef372fe94f82b,
+        0xa54ff53a5f1d36f1,
+        0x510e527fade682d1,
+        0x9b05688c2b3e6c1f,
+        0x1f83d9abfb41bd6b,
+        0x5be0cd19137e2179,
+    ];
+    zeroize_primitive_array(&mut state);
+    assert_eq!(state, [0u64; 8]);
+}
+
+#[test]
+fn test_zeroize_primitive_array_u32() {
+    let mut words = [0xDEADBEEFu32; 16];
+    zeroize_primitive_array(&mut words);
+    assert_eq!(words, [0u32; 16]);
+}
+
+#[test]
+fn test_zeroize_primitive_array_empty() {
+    let mut empty: [u64; 0] = [];
+    zeroize_primitive_array(&mut empty);
+    assert_eq!(empty, [0u64; 0]);
+}
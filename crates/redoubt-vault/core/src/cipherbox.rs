@@ -3,22 +3,74 @@
 // See LICENSE in the repository root for full license text.
 
 use alloc::vec;
+use alloc::vec::Vec;
 
 use core::marker::PhantomData;
 
-use redoubt_aead::AeadApi;
+use redoubt_aead::{Aead, AeadApi, AeadError, AeadVariant};
 use redoubt_codec::{BytesRequired, Decode, Encode, RedoubtCodecBuffer};
+use redoubt_hkdf::hkdf;
+use redoubt_rand::fill_with_random_bytes;
 use redoubt_zero::{
     FastZeroizable, RedoubtZero, ZeroizationProbe, ZeroizeMetadata, ZeroizeOnDropSentinel,
     ZeroizingGuard,
 };
 
-use super::consts::AAD;
-use super::error::CipherBoxError;
+use super::consts::{AAD, KEY_INFO, KEY_SALT_LEN, SEALED_AAD, SEALED_KEY_INFO, SEALED_VERSION};
+use super::error::{CipherBoxError, CryptoError};
+use super::limits::DEFAULT_MAX_PLAINTEXT_LEN;
 use super::master_key::leak_master_key;
 use super::traits::{DecryptStruct, Decryptable, EncryptStruct, Encryptable};
 use super::types::{Ciphertext, Ciphertexts, Nonces, Tags};
 
+const SEALED_VERSION_LEN: usize = core::mem::size_of::<u16>();
+
+/// AEAD sealing exported blobs.
+///
+/// Fixed, unlike a box's own backend (chosen from CPU features at runtime),
+/// so a blob exported on one host opens on any other host and build.
+fn sealed_blob_aead() -> Aead {
+    Aead::from(AeadVariant::XChachaPoly1305)
+}
+
+/// Derives the key sealing an exported blob from the user key and the blob salt.
+fn derive_sealed_key(
+    salt: &[u8],
+    key: &[u8],
+    key_size: usize,
+) -> Result<ZeroizingGuard<Vec<u8>>, CipherBoxError> {
+    let mut sealed_key = vec![0u8; key_size];
+
+    if let Err(e) = hkdf(salt, key, SEALED_KEY_INFO, &mut sealed_key) {
+        sealed_key.fast_zeroize();
+        return Err(e.into());
+    }
+
+    Ok(ZeroizingGuard::from_mut(&mut sealed_key))
+}
+
+/// Maps a failed decryption to the error surfaced to callers.
+///
+/// Tag or key commitment mismatches mean the stored data was tampered with
+/// and are reported as [`CipherBoxError::AuthenticationFailed`].
+fn decrypt_error(err: CipherBoxError) -> CipherBoxError {
+    match err {
+        CipherBoxError::AuthenticationFailed
+        | CipherBoxError::Aead(AeadError::AuthenticationFailed | AeadError::CommitmentMismatch) => {
+            CipherBoxError::AuthenticationFailed
+        }
+        _ => CipherBoxError::Poisoned,
+    }
+}
+
+/// Maps a failure to seal the struct again after it was successfully opened.
+fn reencrypt_error(err: CipherBoxError) -> CipherBoxError {
+    match err {
+        CipherBoxError::Poisoned => CipherBoxError::Reencrypt,
+        other => other,
+    }
+}
+
 #[derive(RedoubtZero)]
 #[fast_zeroize(drop)]
 pub struct CipherBox<T, A, const N: usize>
@@ -40,6 +92,11 @@ where
     /// Starts as `false`, becomes `true` when an operation fails.
     poisoned: bool,
     key_size: usize,
+    /// HKDF info for the AEAD key: `KEY_INFO || context`.
+    key_info: Vec<u8>,
+    /// Empty until the first [`rekey`](Self::rekey). The AEAD key is
+    /// `HKDF(key_salt, master_key, key_info)`.
+    key_salt: Vec<u8>,
     ciphertexts: Ciphertexts<N>,
     tmp_ciphertexts: Ciphertexts<N>,
     nonces: Nonces<N>,
@@ -71,6 +128,11 @@ where
         self.key_size = key_size;
     }
 
+    #[cfg(test)]
+    pub(crate) fn __unsafe_change_context(&mut self, context: &[u8]) {
+        self.key_info = [KEY_INFO, context].concat();
+    }
+
     #[cfg(test)]
     pub(crate) fn __unsafe_get_tmp_ciphertext(&mut self) -> &Ciphertext {
         &self.tmp_field_cyphertext
@@ -86,12 +148,40 @@ where
         &self.tmp_ciphertexts
     }
 
+    #[cfg(test)]
+    pub(crate) fn __unsafe_get_nonces(&self) -> &Nonces<N> {
+        &self.nonces
+    }
+
+    #[cfg(test)]
+    pub(crate) fn __unsafe_get_tags(&self) -> &Tags<N> {
+        &self.tags
+    }
+
+    #[cfg(test)]
+    pub(crate) fn __unsafe_get_tags_mut(&mut self) -> &mut Tags<N> {
+        &mut self.tags
+    }
+
     #[cfg(test)]
     pub(crate) fn __unsafe_get_field_ciphertext<const M: usize>(&mut self) -> &Ciphertext {
         &self.ciphertexts[M]
     }
 
+    /// Creates a box with an empty context.
+    ///
+    /// Equivalent to [`with_context`](Self::with_context) with `b""`.
     pub fn new(aead: A) -> Self {
+        Self::with_context(aead, b"")
+    }
+
+    /// Creates a box whose AEAD key is derived from the master key under
+    /// `context`.
+    ///
+    /// Boxes with different contexts use independent keys even though they
+    /// share the process master key, so a ciphertext produced by one cannot be
+    /// opened by the other.
+    pub fn with_context(aead: A, context: &[u8]) -> Self {
         let key_size = aead.api_key_size();
         let nonce_size = aead.api_nonce_size();
         let tag_size = aead.api_tag_size();
@@ -112,6 +202,8 @@ where
         Self {
             aead,
             key_size,
+            key_info: [KEY_INFO, context].concat(),
+            key_salt: Vec::new(),
             tags,
             nonces,
             ciphertexts,
@@ -149,6 +241,8 @@ where
                 self.ciphertexts = ciphertexts;
                 Ok(())
             }
+            // Rejected before anything was touched: the stored fields are intact
+            Err(CipherBoxError::Crypto(CryptoError::TooLarge)) => Err(CryptoError::TooLarge.into()),
             Err(_) => {
                 self.poisoned = true;
                 Err(CipherBoxError::Poisoned)
@@ -174,13 +268,240 @@ where
 
         match result {
             Ok(_) => Ok(value),
-            Err(_) => {
+            Err(e) => {
                 self.poisoned = true;
-                Err(CipherBoxError::Poisoned)
+                Err(decrypt_error(e))
             }
         }
     }
 
+    /// Returns the key the fields are sealed with.
+    ///
+    /// The key is always derived with HKDF from the master key, `key_salt` and
+    /// the box context; the master key is never used directly. Any failure
+    /// poisons the box.
+    #[inline(always)]
+    pub(crate) fn leak_aead_key(&mut self) -> Result<ZeroizingGuard<Vec<u8>>, CipherBoxError> {
+        let mut master_key = leak_master_key(self.key_size).map_err(|_| {
+            self.poisoned = true;
+            CipherBoxError::Poisoned
+        })?;
+
+        let mut aead_key = vec![0u8; self.key_size];
+        let result = hkdf(&self.key_salt, &master_key, &self.key_info, &mut aead_key);
+
+        master_key.fast_zeroize();
+
+        if result.is_err() {
+            aead_key.fast_zeroize();
+            self.poisoned = true;
+            return Err(CipherBoxError::Poisoned);
+        }
+
+        Ok(ZeroizingGuard::from_mut(&mut aead_key))
+    }
+
+    /// Rotates the key the fields are sealed with.
+    ///
+    /// A fresh random salt is drawn and the new AEAD key is derived from it and
+    /// the master key. The whole struct is decrypted with the current key and
+    /// sealed again with the new one (and fresh nonces), within this single
+    /// call: the plaintext only lives in the internal buffers and a
+    /// `ZeroizingGuard`. The old key and salt are zeroized.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`CipherBoxError::Entropy`] if no salt could be generated,
+    /// leaving the box untouched. Any later failure poisons the box.
+    pub fn rekey(&mut self) -> Result<(), CipherBoxError> {
+        self.assert_healthy()?;
+        self.maybe_initialize()?;
+
+        let mut new_salt = vec![0u8; KEY_SALT_LEN];
+        fill_with_random_bytes(&mut new_salt)?;
+
+        let mut value = {
+            let old_key = self.leak_aead_key()?;
+            self.decrypt_struct(&old_key)?
+        };
+
+        self.key_salt.fast_zeroize();
+        self.key_salt = new_salt;
+
+        let new_key = self.leak_aead_key().inspect_err(|_| {
+            value.fast_zeroize();
+        })?;
+
+        self.encrypt_struct(&new_key, &mut value)
+            .map_err(reencrypt_error)
+    }
+
+    /// Serializes the box into a self-contained blob sealed under `key`.
+    ///
+    /// The struct is decrypted, encoded and sealed with XChaCha20-Poly1305
+    /// under a key derived with HKDF from `key` and a fresh random salt. The
+    /// blob AEAD is fixed regardless of the box's own backend, so the blob
+    /// can be imported on any host. Field ciphertexts are bound to the
+    /// process-local master key, which never leaves memory, so they are not
+    /// exported as-is. The box itself is left unchanged.
+    ///
+    /// `key` must be high-entropy key material (e.g. 32 random bytes). HKDF
+    /// does not stretch its input, so a passphrase must go through a password
+    /// KDF (Argon2, scrypt) first.
+    ///
+    /// Layout: `version (u16 LE) || salt || nonce || ciphertext || tag`.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`CipherBoxError::Entropy`] if no salt or nonce could be
+    /// generated. Failing to decrypt the stored fields poisons the box.
+    pub fn export_sealed(&mut self, key: &[u8]) -> Result<Vec<u8>, CipherBoxError> {
+        self.assert_healthy()?;
+        self.maybe_initialize()?;
+
+        let mut sealer = sealed_blob_aead();
+        let tag_size = sealer.api_tag_size();
+
+        let mut salt = vec![0u8; KEY_SALT_LEN];
+        fill_with_random_bytes(&mut salt)?;
+        let nonce = sealer.api_generate_nonce()?;
+        let sealed_key = derive_sealed_key(&salt, key, sealer.api_key_size())?;
+
+        let mut value = {
+            let aead_key = self.leak_aead_key()?;
+            self.decrypt_struct(&aead_key)?
+        };
+
+        let bytes_required = value.encode_bytes_required()?;
+        let mut buf = RedoubtCodecBuffer::with_capacity(bytes_required);
+        value.encode_into(&mut buf)?;
+
+        let header_len = SEALED_VERSION_LEN + salt.len() + nonce.len();
+        let mut blob = Vec::with_capacity(header_len + bytes_required + tag_size);
+        blob.extend_from_slice(&SEALED_VERSION.to_le_bytes());
+        blob.extend_from_slice(&salt);
+        blob.extend_from_slice(&nonce);
+        blob.extend_from_slice(buf.as_slice());
+        blob.resize(header_len + bytes_required + tag_size, 0);
+
+        // Plaintext now lives only in `blob`, which is encrypted in place below
+        buf.fast_zeroize();
+
+        let (body, tag) = blob[header_len..].split_at_mut(bytes_required);
+
+        if let Err(e) = sealer.api_encrypt(&sealed_key, &nonce, SEALED_AAD, body, tag) {
+            blob.fast_zeroize();
+            return Err(e.into());
+        }
+
+        Ok(blob)
+    }
+
+    /// Rebuilds a box from a blob produced by [`export_sealed`](Self::export_sealed).
+    ///
+    /// The decoded struct is sealed under this process' master key with
+    /// `aead`, exactly as if it had been written through
+    /// [`open_mut`](Self::open_mut). `aead` need not be the backend the
+    /// exporting box used. `blob` is zeroized whether the import succeeds or
+    /// not.
+    ///
+    /// # Errors
+    ///
+    /// - [`CipherBoxError::InvalidSealedBlob`] if `blob` is malformed or was
+    ///   written by an unsupported format version.
+    /// - [`CipherBoxError::Aead`] if `key` is wrong or `blob` was tampered with.
+    pub fn import_sealed(aead: A, blob: &mut [u8], key: &[u8]) -> Result<Self, CipherBoxError> {
+        Self::import_sealed_with_context(aead, b"", blob, key)
+    }
+
+    /// Like [`import_sealed`](Self::import_sealed), but the new box derives its
+    /// key under `context`, as if built with [`with_context`](Self::with_context).
+    ///
+    /// The blob itself does not depend on the exporting box's context.
+    pub fn import_sealed_with_context(
+        aead: A,
+        context: &[u8],
+        blob: &mut [u8],
+        key: &[u8],
+    ) -> Result<Self, CipherBoxError> {
+        let mut cb = Self::with_context(aead, context);
+        let result = cb.try_import_sealed(blob, key);
+
+        blob.fast_zeroize();
+
+        result.map(|_| cb)
+    }
+
+    /// Re-seals a blob from [`export_sealed`](Self::export_sealed) under
+    /// `new_key`.
+    ///
+    /// The blob is imported with `old_key` into a temporary box and exported
+    /// again with `new_key`, entirely within this call: the plaintext never
+    /// reaches the caller, and the temporary box and every intermediate copy
+    /// are zeroized. `blob` is replaced (and the old contents zeroized) only
+    /// once the new blob is complete; on any failure it is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`import_sealed`](Self::import_sealed) and
+    /// [`export_sealed`](Self::export_sealed).
+    pub fn rewrap_sealed(
+        aead: A,
+        blob: &mut Vec<u8>,
+        old_key: &[u8],
+        new_key: &[u8],
+    ) -> Result<(), CipherBoxError> {
+        // `import_sealed` zeroizes its input, so it works on a copy
+        let mut work = blob.clone();
+        let mut cb = Self::import_sealed(aead, &mut work, old_key)?;
+        let new_blob = cb.export_sealed(new_key)?;
+
+        blob.fast_zeroize();
+        *blob = new_blob;
+
+        Ok(())
+    }
+
+    fn try_import_sealed(&mut self, blob: &mut [u8], key: &[u8]) -> Result<(), CipherBoxError> {
+        let mut sealer = sealed_blob_aead();
+        let nonce_size = sealer.api_nonce_size();
+        let tag_size = sealer.api_tag_size();
+        let header_len = SEALED_VERSION_LEN + KEY_SALT_LEN + nonce_size;
+
+        if blob.len() < header_len + tag_size {
+            return Err(CipherBoxError::InvalidSealedBlob);
+        }
+
+        let (header, rest) = blob.split_at_mut(header_len);
+
+        if header[..SEALED_VERSION_LEN] != SEALED_VERSION.to_le_bytes() {
+            return Err(CipherBoxError::InvalidSealedBlob);
+        }
+
+        let (salt, nonce) = header[SEALED_VERSION_LEN..].split_at(KEY_SALT_LEN);
+        let sealed_key = derive_sealed_key(salt, key, sealer.api_key_size())?;
+
+        let body_len = rest.len() - tag_size;
+        let (body, tag) = rest.split_at_mut(body_len);
+
+        sealer.api_decrypt(&sealed_key, nonce, SEALED_AAD, body, tag)?;
+
+        // `decode_from` zeroizes the consumed plaintext
+        let mut value = ZeroizingGuard::<T>::from_default();
+        let mut plaintext = body;
+        value.decode_from(&mut plaintext)?;
+
+        if !plaintext.is_empty() {
+            return Err(CipherBoxError::InvalidSealedBlob);
+        }
+
+        let aead_key = self.leak_aead_key()?;
+        self.encrypt_struct(&aead_key, &mut value)?;
+        self.initialized = true;
+
+        Ok(())
+    }
+
     #[cold]
     #[inline(never)]
     pub(crate) fn maybe_initialize(&mut self) -> Result<(), CipherBoxError> {
@@ -188,10 +509,7 @@ where
             return Ok(());
         }
 
-        let master_key = leak_master_key(self.key_size).map_err(|_| {
-            self.poisoned = true;
-            CipherBoxError::Poisoned
-        })?;
+        let master_key = self.leak_aead_key()?;
         let mut value = ZeroizingGuard::<T>::from_default();
 
         self.encrypt_struct(&master_key, &mut value)?;
@@ -252,9 +570,9 @@ where
     {
         let result = self.try_decrypt_field::<F, M>(aead_key, field);
 
-        if result.is_err() {
+        if let Err(e) = result {
             self.poisoned = true;
-            return Err(CipherBoxError::Poisoned);
+            return Err(decrypt_error(e));
         }
 
         Ok(())
@@ -271,6 +589,10 @@ where
     {
         let bytes_required = field.encode_bytes_required()?;
 
+        if bytes_required > DEFAULT_MAX_PLAINTEXT_LEN {
+            return Err(CryptoError::TooLarge.into());
+        }
+
         self.tmp_field_codec_buff
             .realloc_with_capacity(bytes_required);
 
@@ -314,6 +636,7 @@ where
             Ok(()) => Ok(()),
             Err(CipherBoxError::Overflow(err)) => Err(CipherBoxError::Overflow(err)),
             Err(CipherBoxError::Entropy(err)) => Err(CipherBoxError::Entropy(err)),
+            Err(CipherBoxError::Crypto(CryptoError::TooLarge)) => Err(CryptoError::TooLarge.into()),
             _ => {
                 self.poisoned = true;
                 Err(CipherBoxError::Poisoned)
@@ -352,10 +675,7 @@ where
         self.assert_healthy().map_err(E::from)?;
         self.maybe_initialize().map_err(E::from)?;
 
-        let master_key = leak_master_key(self.key_size).map_err(|_| {
-            self.poisoned = true;
-            E::from(CipherBoxError::Poisoned)
-        })?;
+        let master_key = self.leak_aead_key().map_err(E::from)?;
         let mut value = self.decrypt_struct(&master_key).map_err(E::from)?;
 
         let mut result = f(&value).inspect_err(|_| {
@@ -363,7 +683,8 @@ where
             value.fast_zeroize();
         })?;
 
-        self.encrypt_struct(&master_key, &mut value)?;
+        self.encrypt_struct(&master_key, &mut value)
+            .map_err(reencrypt_error)?;
 
         Ok(ZeroizingGuard::from_mut(&mut result))
     }
@@ -403,10 +724,7 @@ where
         self.assert_healthy().map_err(E::from)?;
         self.maybe_initialize().map_err(E::from)?;
 
-        let master_key = leak_master_key(self.key_size).map_err(|_| {
-            self.poisoned = true;
-            E::from(CipherBoxError::Poisoned)
-        })?;
+        let master_key = self.leak_aead_key().map_err(E::from)?;
         let mut value = self.decrypt_struct(&master_key).map_err(E::from)?;
 
         let mut result = f(&mut value).inspect_err(|_| {
@@ -414,7 +732,8 @@ where
             value.fast_zeroize();
         })?;
 
-        self.encrypt_struct(&master_key, &mut value)?;
+        self.encrypt_struct(&master_key, &mut value)
+            .map_err(reencrypt_error)?;
 
         Ok(ZeroizingGuard::from_mut(&mut result))
     }
@@ -432,10 +751,7 @@ where
         self.assert_healthy()?;
         self.maybe_initialize()?;
 
-        let master_key = leak_master_key(self.key_size).map_err(|_| {
-            self.poisoned = true;
-            CipherBoxError::Poisoned
-        })?;
+        let master_key = self.leak_aead_key()?;
         let mut field = ZeroizingGuard::<Field>::from_default();
 
         self.decrypt_field::<Field, M>(&master_key, &mut field)?;
@@ -461,10 +777,7 @@ where
         self.assert_healthy()?;
         self.maybe_initialize()?;
 
-        let master_key = leak_master_key(self.key_size).map_err(|_| {
-            self.poisoned = true;
-            CipherBoxError::Poisoned
-        })?;
+        let master_key = self.leak_aead_key()?;
         let mut field = ZeroizingGuard::<Field>::from_default();
 
         self.decrypt_field::<Field, M>(&master_key, &mut field)?;
@@ -474,7 +787,8 @@ where
             field.fast_zeroize();
         })?;
 
-        self.encrypt_field::<Field, M>(&master_key, &mut field)?;
+        self.encrypt_field::<Field, M>(&master_key, &mut field)
+            .map_err(reencrypt_error)?;
 
         Ok(ZeroizingGuard::from_mut(&mut result))
     }
@@ -562,10 +876,7 @@ where
         self.assert_healthy()?;
         self.maybe_initialize()?;
 
-        let master_key = leak_master_key(self.key_size).map_err(|_| {
-            self.poisoned = true;
-            CipherBoxError::Poisoned
-        })?;
+        let master_key = self.leak_aead_key()?;
         let mut field = ZeroizingGuard::<Field>::from_default();
 
         self.decrypt_field::<Field, M>(&master_key, &mut field)?;
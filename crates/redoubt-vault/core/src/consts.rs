@@ -3,3 +3,18 @@
 // See LICENSE in the repository root for full license text.
 
 pub(crate) const AAD: &[u8] = b"REDOUBT-CIPHERBOX:0.0.1";
+
+/// HKDF info prefix for the per-box AEAD key, followed by the box context.
+pub(crate) const KEY_INFO: &[u8] = b"REDOUBT-CIPHERBOX-KEY:0.0.1";
+
+/// Length of the random salt drawn by [`CipherBox::rekey`](crate::CipherBox::rekey).
+pub(crate) const KEY_SALT_LEN: usize = 32;
+
+/// AAD bound to blobs produced by [`CipherBox::export_sealed`](crate::CipherBox::export_sealed).
+pub(crate) const SEALED_AAD: &[u8] = b"REDOUBT-CIPHERBOX-SEALED:0.0.1";
+
+/// HKDF info for the key sealing an exported blob.
+pub(crate) const SEALED_KEY_INFO: &[u8] = b"REDOUBT-CIPHERBOX-SEALED-KEY:0.0.1";
+
+/// Format version written at the start of every exported blob.
+pub(crate) const SEALED_VERSION: u16 = 1;
@@ -9,6 +9,7 @@ use thiserror::Error;
 use redoubt_aead::AeadError;
 use redoubt_buffer::BufferError;
 use redoubt_codec::{DecodeError, EncodeError, OverflowError};
+use redoubt_hkdf::HkdfError;
 use redoubt_rand::EntropyError;
 
 #[derive(Debug, Error)]
@@ -34,6 +35,14 @@ pub enum CipherBoxError {
     #[error(transparent)]
     Aead(#[from] AeadError),
 
+    #[error(transparent)]
+    Hkdf(#[from] HkdfError),
+
+    /// A blob passed to `CipherBox::import_sealed` is truncated, has an
+    /// unsupported version, or carries trailing bytes.
+    #[error("InvalidSealedBlob")]
+    InvalidSealedBlob,
+
     /// The CipherBox is in an irrecoverable state due to operation failure.
     ///
     /// This error occurs when a cryptographic operation fails partway through,
@@ -46,6 +55,21 @@ pub enum CipherBoxError {
     #[error("poisoned: box is in an irrecoverable state, exposed plaintext was zeroized")]
     Poisoned,
 
+    /// A stored field failed authentication: its ciphertext, nonce or tag
+    /// was modified.
+    ///
+    /// Like `Poisoned`, the box cannot be used afterwards, but this variant
+    /// lets callers tell tampering apart from other failures.
+    #[error("authentication failed: stored ciphertext was tampered with")]
+    AuthenticationFailed,
+
+    /// Sealing the struct again after it was opened failed.
+    ///
+    /// The callback ran successfully, but its result could not be
+    /// re-encrypted. The box is poisoned and the plaintext was zeroized.
+    #[error("reencrypt: failed to seal the box again after opening it")]
+    Reencrypt,
+
     /// The CipherBox was intentionally zeroized.
     ///
     /// This error occurs when `fast_zeroize()` was called on the CipherBox,
@@ -89,4 +113,8 @@ pub enum CryptoError {
 
     #[error("CiphertextWithTagTooShort")]
     CiphertextWithTagTooShort,
+
+    /// A field's plaintext would exceed [`DecryptLimits::max_plaintext_len`](crate::DecryptLimits).
+    #[error("TooLarge")]
+    TooLarge,
 }
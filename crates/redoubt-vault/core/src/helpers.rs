@@ -4,11 +4,12 @@
 
 use alloc::vec;
 
-use redoubt_aead::AeadApi;
+use redoubt_aead::{AeadApi, AeadError};
 use redoubt_codec::RedoubtCodecBuffer;
 use redoubt_zero::{FastZeroizable, ZeroizationProbe};
 
-use crate::error::CipherBoxError;
+use crate::error::{CipherBoxError, CryptoError};
+use crate::limits::{DEFAULT_MAX_PLAINTEXT_LEN, DecryptLimits};
 use crate::traits::{Decryptable, Encryptable};
 use crate::types::{Ciphertexts, Nonces, Tags};
 
@@ -37,6 +38,11 @@ pub fn get_sizes<const N: usize>(
     Ok(sizes)
 }
 
+/// Encodes and encrypts `fields`, one ciphertext per field.
+///
+/// A field whose encoding exceeds [`DEFAULT_MAX_PLAINTEXT_LEN`] yields
+/// [`CryptoError::TooLarge`] before anything is encoded, since [`decrypt_from`]
+/// would refuse to decrypt it. `nonces` and `tags` are left untouched.
 #[inline(always)]
 pub fn encrypt_into<const N: usize>(
     fields: [&mut dyn Encryptable; N],
@@ -46,6 +52,11 @@ pub fn encrypt_into<const N: usize>(
     tags: &mut Tags<N>,
 ) -> Result<Ciphertexts<N>, CipherBoxError> {
     let sizes = get_sizes(&fields)?;
+
+    if sizes.iter().any(|size| *size > DEFAULT_MAX_PLAINTEXT_LEN) {
+        return Err(CryptoError::TooLarge.into());
+    }
+
     let mut buffers: [RedoubtCodecBuffer; N] = sizes.map(RedoubtCodecBuffer::with_capacity);
     let mut ciphertexts: Ciphertexts<N> = core::array::from_fn(|_| vec![]);
 
@@ -141,6 +152,9 @@ fn try_decrypt_from<const N: usize>(
     Ok(())
 }
 
+/// Decrypts `ciphertexts` into `fields` using [`DecryptLimits::default()`].
+///
+/// See [`decrypt_from_with_limits`].
 pub fn decrypt_from<const N: usize>(
     fields: &mut [&mut dyn Decryptable; N],
     aead: &mut dyn AeadApi,
@@ -149,10 +163,54 @@ pub fn decrypt_from<const N: usize>(
     tags: &mut Tags<N>,
     ciphertexts: &mut Ciphertexts<N>,
 ) -> Result<(), CipherBoxError> {
+    decrypt_from_with_limits(
+        fields,
+        aead,
+        aead_key,
+        nonces,
+        tags,
+        ciphertexts,
+        DecryptLimits::default(),
+    )
+}
+
+/// Decrypts `ciphertexts` into `fields`, rejecting oversized fields up front.
+///
+/// Every ciphertext is checked against `limits` before anything is decrypted
+/// or decoded. An oversized field yields [`CryptoError::TooLarge`] and leaves
+/// the ciphertexts untouched, since no plaintext has been exposed yet.
+///
+/// Any later failure zeroizes all ciphertexts and returns
+/// [`CipherBoxError::AuthenticationFailed`] if a tag did not verify, or
+/// [`CipherBoxError::Poisoned`] otherwise.
+pub fn decrypt_from_with_limits<const N: usize>(
+    fields: &mut [&mut dyn Decryptable; N],
+    aead: &mut dyn AeadApi,
+    aead_key: &[u8],
+    nonces: &mut Nonces<N>,
+    tags: &mut Tags<N>,
+    ciphertexts: &mut Ciphertexts<N>,
+    limits: DecryptLimits,
+) -> Result<(), CipherBoxError> {
+    if ciphertexts
+        .iter()
+        .any(|ciphertext| ciphertext.len() > limits.max_plaintext_len)
+    {
+        return Err(CryptoError::TooLarge.into());
+    }
+
     let result = try_decrypt_from(fields, aead, aead_key, nonces, tags, ciphertexts);
 
-    if result.is_err() {
+    if let Err(e) = result {
         ciphertexts.fast_zeroize();
+
+        if let CipherBoxError::Aead(
+            AeadError::AuthenticationFailed | AeadError::CommitmentMismatch,
+        ) = e
+        {
+            return Err(CipherBoxError::AuthenticationFailed);
+        }
+
         return Err(CipherBoxError::Poisoned);
     }
 
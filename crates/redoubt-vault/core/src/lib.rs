@@ -18,13 +18,15 @@ mod cipherbox;
 mod consts;
 mod error;
 mod helpers;
+mod limits;
 mod master_key;
 mod traits;
 mod types;
 
 pub use cipherbox::CipherBox;
-pub use error::CipherBoxError;
-pub use helpers::{decrypt_from, encrypt_into};
+pub use error::{CipherBoxError, CryptoError};
+pub use helpers::{decrypt_from, decrypt_from_with_limits, encrypt_into};
+pub use limits::{DEFAULT_MAX_PLAINTEXT_LEN, DecryptLimits};
 pub use master_key::leak_master_key;
 pub use traits::{CipherBoxDyns, DecryptStruct, Decryptable, EncryptStruct, Encryptable};
 pub use types::{Ciphertext, Ciphertexts, Nonce, Nonces, Tag, Tags};
@@ -0,0 +1,37 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! Resource limits applied while decrypting.
+
+/// Default cap on the plaintext length of a single field (64 MiB).
+///
+/// Also enforced when encrypting, so every stored field can be decrypted
+/// again with the default limits.
+pub const DEFAULT_MAX_PLAINTEXT_LEN: usize = 64 * 1024 * 1024;
+
+/// Limits enforced by [`decrypt_from_with_limits`](crate::decrypt_from_with_limits).
+///
+/// Ciphertexts are decrypted in place, so a field's plaintext is exactly as
+/// long as its ciphertext. The codec checks every collection's element count
+/// against the bytes actually present before preallocating, so allocations
+/// made while decoding a field stay proportional to its plaintext length,
+/// which this cap bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecryptLimits {
+    /// Maximum plaintext length, in bytes, of any single field.
+    pub max_plaintext_len: usize,
+}
+
+impl DecryptLimits {
+    /// Creates limits with the given per-field plaintext cap.
+    pub const fn new(max_plaintext_len: usize) -> Self {
+        Self { max_plaintext_len }
+    }
+}
+
+impl Default for DecryptLimits {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_PLAINTEXT_LEN)
+    }
+}
@@ -2,8 +2,8 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // See LICENSE in the repository root for full license text.
 
-use redoubt_aead::AeadApi;
 use redoubt_aead::support::test_utils::{AeadMock, AeadMockBehaviour};
+use redoubt_aead::{Aead, AeadApi};
 use redoubt_alloc::RedoubtVec;
 use redoubt_codec::RedoubtCodec;
 use redoubt_codec::support::test_utils::{
@@ -17,9 +17,11 @@ use redoubt_zero::{
 };
 
 use crate::cipherbox::CipherBox;
-use crate::error::CipherBoxError;
+use crate::error::{CipherBoxError, CryptoError};
 use crate::helpers::{decrypt_from, encrypt_into};
+use crate::limits::DEFAULT_MAX_PLAINTEXT_LEN;
 use crate::master_key::consts::MASTER_KEY_LEN;
+use crate::master_key::leak_master_key;
 use crate::traits::{CipherBoxDyns, DecryptStruct, Decryptable, EncryptStruct, Encryptable};
 
 use super::consts::NUM_FIELDS;
@@ -221,7 +223,7 @@ fn test_decrypt_struct_propagates_encrypt_error() {
     let result = cb.decrypt_struct(&aead_key);
 
     assert!(result.is_err());
-    assert!(matches!(result, Err(CipherBoxError::Poisoned)));
+    assert!(matches!(result, Err(CipherBoxError::AuthenticationFailed)));
     assert!(cb.assert_healthy().is_err());
 }
 
@@ -273,7 +275,7 @@ fn test_decrypt_field_propagates_decrypt_error() {
     let result = cb.decrypt_field::<RedoubtCodecTestBreaker, 1>(&aead_key, &mut field);
 
     assert!(result.is_err());
-    assert!(matches!(result, Err(CipherBoxError::Poisoned)));
+    assert!(matches!(result, Err(CipherBoxError::AuthenticationFailed)));
     assert!(cb.assert_healthy().is_err());
 
     // SAFETY NOTE: There is no need to assert zeroization, if decryption fails CipherBox will remain Poisoned,
@@ -503,7 +505,7 @@ fn test_open_propagates_decrypt_struct_error() {
     let result = cb.open::<_, _, CipherBoxError>(|_| Ok(()));
 
     assert!(result.is_err());
-    assert!(matches!(result, Err(CipherBoxError::Poisoned)));
+    assert!(matches!(result, Err(CipherBoxError::AuthenticationFailed)));
     assert!(cb.assert_healthy().is_err());
 }
 
@@ -517,7 +519,7 @@ fn test_open_propagates_encrypt_struct_error() {
     let result = cb.open::<_, _, CipherBoxError>(|_| Ok(()));
 
     assert!(result.is_err());
-    assert!(matches!(result, Err(CipherBoxError::Poisoned)));
+    assert!(matches!(result, Err(CipherBoxError::Reencrypt)));
     assert!(cb.assert_healthy().is_err());
 }
 
@@ -563,6 +565,40 @@ fn test_open_when_callback_error_is_propagated_cipherbox_is_not_poisoned() {
     assert_eq!(*current_f0_value.unwrap(), 1);
 }
 
+#[test]
+fn test_open_with_tampered_tag_returns_authentication_failed() {
+    let aead = AeadMock::new(AeadMockBehaviour::None);
+    let mut cb = CipherBox::<RedoubtCodecTestBreakerBox, AeadMock, NUM_FIELDS>::new(aead);
+
+    assert!(cb.maybe_initialize().is_ok());
+
+    cb.__unsafe_get_tags_mut()[3][0] ^= 1;
+
+    let result_1 = cb.open::<_, _, CipherBoxError>(|_| Ok(()));
+    let result_2 = cb.open::<_, _, CipherBoxError>(|_| Ok(()));
+
+    assert!(matches!(
+        result_1,
+        Err(CipherBoxError::AuthenticationFailed)
+    ));
+    assert!(matches!(result_2, Err(CipherBoxError::Poisoned)));
+}
+
+#[test]
+fn test_leak_field_with_tampered_tag_returns_authentication_failed() {
+    let aead = AeadMock::new(AeadMockBehaviour::None);
+    let mut cb = CipherBox::<RedoubtCodecTestBreakerBox, AeadMock, NUM_FIELDS>::new(aead);
+
+    assert!(cb.maybe_initialize().is_ok());
+
+    cb.__unsafe_get_tags_mut()[0][0] ^= 1;
+
+    let result = cb.leak_field::<RedoubtCodecTestBreaker, 0, CipherBoxError>();
+
+    assert!(matches!(result, Err(CipherBoxError::AuthenticationFailed)));
+    assert!(cb.assert_healthy().is_err());
+}
+
 // =============================================================================
 // open_mut()
 // =============================================================================
@@ -632,7 +668,7 @@ fn test_open_mut_propagates_decrypt_struct_error() {
     let result = cb.open_mut::<_, _, CipherBoxError>(|_| Ok(()));
 
     assert!(result.is_err());
-    assert!(matches!(result, Err(CipherBoxError::Poisoned)));
+    assert!(matches!(result, Err(CipherBoxError::AuthenticationFailed)));
     assert!(cb.assert_healthy().is_err());
 }
 
@@ -646,7 +682,7 @@ fn test_open_mut_propagates_encrypt_struct_error() {
     let result = cb.open_mut::<_, _, CipherBoxError>(|_| Ok(()));
 
     assert!(result.is_err());
-    assert!(matches!(result, Err(CipherBoxError::Poisoned)));
+    assert!(matches!(result, Err(CipherBoxError::Reencrypt)));
     assert!(cb.assert_healthy().is_err());
 }
 
@@ -713,6 +749,510 @@ fn test_open_mut_zeroizes_tmp_ciphertexts_on_callback_failure() {
     assert!(tmp_ciphertexts.is_zeroized());
 }
 
+// =============================================================================
+// oversized fields
+// =============================================================================
+
+#[test]
+fn test_open_mut_rejects_oversized_struct_without_poisoning() {
+    let mut cb = TestBreakerCipherBox::new(AeadMock::new(AeadMockBehaviour::None));
+
+    let result = cb.open_mut::<_, _, CipherBoxError>(|tb| {
+        tb.f0.usize.data = 42;
+        tb.f1.behaviour =
+            RedoubtCodecTestBreakerBehaviour::BytesRequiredReturn(DEFAULT_MAX_PLAINTEXT_LEN + 1);
+        Ok(())
+    });
+
+    assert!(matches!(
+        result,
+        Err(CipherBoxError::Crypto(CryptoError::TooLarge))
+    ));
+    assert!(cb.assert_healthy().is_ok());
+
+    // The stored fields are the ones from before the rejected write
+    let f0 = cb
+        .open::<_, _, CipherBoxError>(|tb| Ok(tb.f0.usize.data))
+        .expect("Failed to open(..)");
+    assert_eq!(*f0, 1);
+}
+
+#[test]
+fn test_open_field_mut_rejects_oversized_field_without_poisoning() {
+    let mut cb = TestBreakerCipherBox::new(AeadMock::new(AeadMockBehaviour::None));
+
+    let result = cb.open_field_mut::<RedoubtCodecTestBreaker, 2, _, _, CipherBoxError>(|f2| {
+        f2.usize.data = 42;
+        f2.behaviour =
+            RedoubtCodecTestBreakerBehaviour::BytesRequiredReturn(DEFAULT_MAX_PLAINTEXT_LEN + 1);
+        Ok(())
+    });
+
+    assert!(matches!(
+        result,
+        Err(CipherBoxError::Crypto(CryptoError::TooLarge))
+    ));
+    assert!(cb.assert_healthy().is_ok());
+
+    let f2 = cb
+        .leak_field::<RedoubtCodecTestBreaker, 2, CipherBoxError>()
+        .expect("Failed to leak_field(..)");
+    assert_eq!(f2.usize.data, 1 << 2);
+}
+
+// =============================================================================
+// with_context()
+// =============================================================================
+
+#[test]
+fn test_aead_key_is_derived_per_context() {
+    let mut cb_a = CipherBox::<RedoubtCodecTestBreakerBox, Aead, NUM_FIELDS>::with_context(
+        Aead::new(),
+        b"context-a",
+    );
+    let mut cb_b = CipherBox::<RedoubtCodecTestBreakerBox, Aead, NUM_FIELDS>::with_context(
+        Aead::new(),
+        b"context-b",
+    );
+
+    let key_a = cb_a.leak_aead_key().expect("Failed to leak_aead_key()");
+    let key_b = cb_b.leak_aead_key().expect("Failed to leak_aead_key()");
+    let master_key = leak_master_key(key_a.len()).expect("Failed to leak_master_key(..)");
+
+    assert_ne!(*key_a, *key_b);
+    assert_ne!(*key_a, *master_key);
+    assert_ne!(*key_b, *master_key);
+}
+
+#[test]
+fn test_open_with_different_context_fails() {
+    let mut cb = CipherBox::<RedoubtCodecTestBreakerBox, Aead, NUM_FIELDS>::with_context(
+        Aead::new(),
+        b"context-a",
+    );
+
+    cb.open_mut::<_, _, CipherBoxError>(|tb| {
+        tb.f0.usize.data = 42;
+        Ok(())
+    })
+    .expect("Failed to open_mut(..)");
+
+    cb.__unsafe_change_context(b"context-b");
+
+    let result = cb.open::<_, _, CipherBoxError>(|tb| Ok(tb.f0.usize.data));
+
+    assert!(matches!(result, Err(CipherBoxError::AuthenticationFailed)));
+    assert!(cb.assert_healthy().is_err());
+}
+
+// =============================================================================
+// rekey()
+// =============================================================================
+
+#[test]
+fn test_rekey_keeps_fields_readable() {
+    let aead = AeadMock::new(AeadMockBehaviour::None);
+    let mut cb = CipherBox::<RedoubtCodecTestBreakerBox, AeadMock, NUM_FIELDS>::new(aead);
+
+    cb.open_mut::<_, _, CipherBoxError>(|tb| {
+        tb.f0.usize.data = 42;
+        Ok(())
+    })
+    .expect("Failed to open_mut(..)");
+
+    let nonces_before = cb.__unsafe_get_nonces().clone();
+    let tags_before = cb.__unsafe_get_tags().clone();
+
+    cb.rekey().expect("Failed to rekey()");
+
+    // Every field was sealed again with a fresh nonce under a new key
+    for i in 0..NUM_FIELDS {
+        assert_ne!(cb.__unsafe_get_nonces()[i], nonces_before[i]);
+        assert_ne!(cb.__unsafe_get_tags()[i], tags_before[i]);
+    }
+
+    let f0 = cb
+        .open::<_, _, CipherBoxError>(|tb| Ok(tb.f0.usize.data))
+        .expect("Failed to open(..)");
+    assert_eq!(*f0, 42);
+
+    let f5 = cb
+        .leak_field::<RedoubtCodecTestBreaker, 5, CipherBoxError>()
+        .expect("Failed to leak_field(..)");
+    assert_eq!(f5.usize.data, 1 << 5);
+
+    assert!(cb.assert_healthy().is_ok());
+}
+
+#[test]
+fn test_rekey_twice_keeps_fields_readable() {
+    let aead = AeadMock::new(AeadMockBehaviour::None);
+    let mut cb = CipherBox::<RedoubtCodecTestBreakerBox, AeadMock, NUM_FIELDS>::new(aead);
+
+    cb.rekey().expect("Failed to rekey()");
+    cb.rekey().expect("Failed to rekey()");
+
+    let f3 = cb
+        .open::<_, _, CipherBoxError>(|tb| Ok(tb.f3.usize.data))
+        .expect("Failed to open(..)");
+    assert_eq!(*f3, 1 << 3);
+}
+
+#[test]
+fn test_rekey_master_key_no_longer_decrypts() {
+    let aead = AeadMock::new(AeadMockBehaviour::None);
+    let mut cb = CipherBox::<RedoubtCodecTestBreakerBox, AeadMock, NUM_FIELDS>::new(aead);
+
+    cb.rekey().expect("Failed to rekey()");
+
+    let master_key = leak_master_key(AeadMock::KEY_SIZE).expect("Failed to leak_master_key(..)");
+    let result = cb.decrypt_struct(&master_key);
+
+    assert!(matches!(result, Err(CipherBoxError::AuthenticationFailed)));
+}
+
+#[test]
+fn test_rekey_propagates_poison_error() {
+    let aead = AeadMock::new(AeadMockBehaviour::FailAtNthEncrypt(1));
+    let mut cb = CipherBox::<RedoubtCodecTestBreakerBox, AeadMock, NUM_FIELDS>::new(aead);
+
+    let result_1 = cb.rekey();
+    let result_2 = cb.rekey();
+
+    assert!(matches!(result_1, Err(CipherBoxError::Poisoned)));
+    assert!(matches!(result_2, Err(CipherBoxError::Poisoned)));
+}
+
+#[test]
+fn test_rekey_propagates_decrypt_struct_error() {
+    let aead = AeadMock::new(AeadMockBehaviour::FailAtNthDecrypt(1));
+    let mut cb = CipherBox::<RedoubtCodecTestBreakerBox, AeadMock, NUM_FIELDS>::new(aead);
+
+    assert!(cb.maybe_initialize().is_ok());
+
+    let result = cb.rekey();
+
+    assert!(matches!(result, Err(CipherBoxError::AuthenticationFailed)));
+    assert!(cb.assert_healthy().is_err());
+}
+
+#[test]
+fn test_rekey_propagates_encrypt_struct_error() {
+    let aead = AeadMock::new(AeadMockBehaviour::FailAtNthEncrypt(NUM_FIELDS + 1));
+    let mut cb = CipherBox::<RedoubtCodecTestBreakerBox, AeadMock, NUM_FIELDS>::new(aead);
+
+    assert!(cb.maybe_initialize().is_ok());
+
+    let result = cb.rekey();
+
+    assert!(matches!(result, Err(CipherBoxError::Reencrypt)));
+    assert!(cb.assert_healthy().is_err());
+}
+
+#[test]
+fn test_rekey_propagates_leak_master_key_error() {
+    let aead = AeadMock::new(AeadMockBehaviour::None);
+    let mut cb = CipherBox::<RedoubtCodecTestBreakerBox, AeadMock, NUM_FIELDS>::new(aead);
+
+    assert!(cb.maybe_initialize().is_ok());
+
+    cb.__unsafe_change_api_key_size(MASTER_KEY_LEN + 1);
+
+    let result = cb.rekey();
+
+    assert!(matches!(result, Err(CipherBoxError::Poisoned)));
+    assert!(cb.assert_healthy().is_err());
+}
+
+// =============================================================================
+// export_sealed() / import_sealed()
+// =============================================================================
+
+const EXPORT_KEY: [u8; 32] = [0x5A; 32];
+
+type TestBreakerCipherBox = CipherBox<RedoubtCodecTestBreakerBox, AeadMock, NUM_FIELDS>;
+
+fn export_with_f0(f0: usize) -> Vec<u8> {
+    let mut cb = TestBreakerCipherBox::new(AeadMock::new(AeadMockBehaviour::None));
+
+    cb.open_mut::<_, _, CipherBoxError>(|tb| {
+        tb.f0.usize.data = f0;
+        Ok(())
+    })
+    .expect("Failed to open_mut(..)");
+
+    cb.export_sealed(&EXPORT_KEY)
+        .expect("Failed to export_sealed(..)")
+}
+
+#[test]
+fn test_export_import_sealed_roundtrip() {
+    let mut blob = export_with_f0(1234);
+
+    let mut imported = TestBreakerCipherBox::import_sealed(
+        AeadMock::new(AeadMockBehaviour::None),
+        &mut blob,
+        &EXPORT_KEY,
+    )
+    .expect("Failed to import_sealed(..)");
+
+    assert!(is_vec_fully_zeroized(&blob));
+
+    let f0 = imported
+        .open::<_, _, CipherBoxError>(|tb| Ok(tb.f0.usize.data))
+        .expect("Failed to open(..)");
+    assert_eq!(*f0, 1234);
+
+    let f4 = imported
+        .leak_field::<RedoubtCodecTestBreaker, 4, CipherBoxError>()
+        .expect("Failed to leak_field(..)");
+    assert_eq!(f4.usize.data, 1 << 4);
+}
+
+#[test]
+fn test_export_sealed_keeps_box_usable() {
+    let mut cb = TestBreakerCipherBox::new(AeadMock::new(AeadMockBehaviour::None));
+
+    let blob = cb
+        .export_sealed(&EXPORT_KEY)
+        .expect("Failed to export_sealed(..)");
+    assert_eq!(blob[..2], 1u16.to_le_bytes());
+
+    let f2 = cb
+        .open::<_, _, CipherBoxError>(|tb| Ok(tb.f2.usize.data))
+        .expect("Failed to open(..)");
+    assert_eq!(*f2, 1 << 2);
+    assert!(cb.assert_healthy().is_ok());
+}
+
+#[test]
+fn test_export_sealed_does_not_contain_master_key() {
+    let blob = export_with_f0(7);
+    let master_key = leak_master_key(MASTER_KEY_LEN).expect("Failed to leak_master_key(..)");
+
+    assert!(
+        !blob
+            .windows(master_key.len())
+            .any(|window| window == master_key.as_slice())
+    );
+}
+
+#[test]
+fn test_export_sealed_after_rekey() {
+    let mut cb = TestBreakerCipherBox::new(AeadMock::new(AeadMockBehaviour::None));
+
+    cb.rekey().expect("Failed to rekey()");
+
+    let mut blob = cb
+        .export_sealed(&EXPORT_KEY)
+        .expect("Failed to export_sealed(..)");
+    let mut imported = TestBreakerCipherBox::import_sealed(
+        AeadMock::new(AeadMockBehaviour::None),
+        &mut blob,
+        &EXPORT_KEY,
+    )
+    .expect("Failed to import_sealed(..)");
+
+    let f1 = imported
+        .open::<_, _, CipherBoxError>(|tb| Ok(tb.f1.usize.data))
+        .expect("Failed to open(..)");
+    assert_eq!(*f1, 1 << 1);
+}
+
+#[test]
+fn test_export_sealed_propagates_poison_error() {
+    let mut cb = TestBreakerCipherBox::new(AeadMock::new(AeadMockBehaviour::FailAtNthEncrypt(1)));
+
+    assert!(cb.maybe_initialize().is_err());
+
+    let result = cb.export_sealed(&EXPORT_KEY);
+
+    assert!(matches!(result, Err(CipherBoxError::Poisoned)));
+}
+
+#[test]
+fn test_export_sealed_propagates_decrypt_struct_error() {
+    let mut cb = TestBreakerCipherBox::new(AeadMock::new(AeadMockBehaviour::FailAtNthDecrypt(1)));
+
+    let result = cb.export_sealed(&EXPORT_KEY);
+
+    assert!(matches!(result, Err(CipherBoxError::AuthenticationFailed)));
+    assert!(cb.assert_healthy().is_err());
+}
+
+#[test]
+fn test_import_sealed_with_different_backend() {
+    // The blob is sealed with a fixed AEAD, not the exporting box's backend
+    let mut blob = export_with_f0(4321);
+
+    let mut imported = CipherBox::<RedoubtCodecTestBreakerBox, Aead, NUM_FIELDS>::import_sealed(
+        Aead::new(),
+        &mut blob,
+        &EXPORT_KEY,
+    )
+    .expect("Failed to import_sealed(..)");
+
+    let f0 = imported
+        .open::<_, _, CipherBoxError>(|tb| Ok(tb.f0.usize.data))
+        .expect("Failed to open(..)");
+    assert_eq!(*f0, 4321);
+}
+
+#[test]
+fn test_import_sealed_with_context_roundtrip() {
+    let mut cb =
+        TestBreakerCipherBox::with_context(AeadMock::new(AeadMockBehaviour::None), b"context-a");
+
+    cb.open_mut::<_, _, CipherBoxError>(|tb| {
+        tb.f0.usize.data = 99;
+        Ok(())
+    })
+    .expect("Failed to open_mut(..)");
+
+    let mut blob = cb
+        .export_sealed(&EXPORT_KEY)
+        .expect("Failed to export_sealed(..)");
+    let mut imported = TestBreakerCipherBox::import_sealed_with_context(
+        AeadMock::new(AeadMockBehaviour::None),
+        b"context-a",
+        &mut blob,
+        &EXPORT_KEY,
+    )
+    .expect("Failed to import_sealed_with_context(..)");
+
+    let f0 = imported
+        .open::<_, _, CipherBoxError>(|tb| Ok(tb.f0.usize.data))
+        .expect("Failed to open(..)");
+    assert_eq!(*f0, 99);
+
+    // The imported box derives the same key as a box built with the context
+    let imported_key = imported.leak_aead_key().expect("Failed to leak_aead_key()");
+    let original_key = cb.leak_aead_key().expect("Failed to leak_aead_key()");
+    assert_eq!(*imported_key, *original_key);
+}
+
+#[test]
+fn test_rewrap_sealed_roundtrip() {
+    const NEW_KEY: [u8; 32] = [0xC3; 32];
+
+    let mut blob = export_with_f0(555);
+    let original = blob.clone();
+
+    TestBreakerCipherBox::rewrap_sealed(
+        AeadMock::new(AeadMockBehaviour::None),
+        &mut blob,
+        &EXPORT_KEY,
+        &NEW_KEY,
+    )
+    .expect("Failed to rewrap_sealed(..)");
+
+    assert_ne!(blob, original);
+
+    // The old key no longer opens the blob
+    let mut stale = blob.clone();
+    let result = TestBreakerCipherBox::import_sealed(
+        AeadMock::new(AeadMockBehaviour::None),
+        &mut stale,
+        &EXPORT_KEY,
+    );
+    assert!(matches!(result, Err(CipherBoxError::Aead(_))));
+
+    let mut imported = TestBreakerCipherBox::import_sealed(
+        AeadMock::new(AeadMockBehaviour::None),
+        &mut blob,
+        &NEW_KEY,
+    )
+    .expect("Failed to import_sealed(..)");
+
+    let f0 = imported
+        .open::<_, _, CipherBoxError>(|tb| Ok(tb.f0.usize.data))
+        .expect("Failed to open(..)");
+    assert_eq!(*f0, 555);
+}
+
+#[test]
+fn test_rewrap_sealed_wrong_old_key_leaves_blob_unchanged() {
+    let mut blob = export_with_f0(555);
+    let original = blob.clone();
+
+    let result = TestBreakerCipherBox::rewrap_sealed(
+        AeadMock::new(AeadMockBehaviour::None),
+        &mut blob,
+        &[0xA5; 32],
+        &[0xC3; 32],
+    );
+
+    assert!(matches!(result, Err(CipherBoxError::Aead(_))));
+    assert_eq!(blob, original);
+
+    // Still opens with the right key
+    TestBreakerCipherBox::import_sealed(
+        AeadMock::new(AeadMockBehaviour::None),
+        &mut blob,
+        &EXPORT_KEY,
+    )
+    .expect("Failed to import_sealed(..)");
+}
+
+#[test]
+fn test_import_sealed_wrong_key_fails() {
+    let mut blob = export_with_f0(1);
+
+    let result = TestBreakerCipherBox::import_sealed(
+        AeadMock::new(AeadMockBehaviour::None),
+        &mut blob,
+        &[0xA5; 32],
+    );
+
+    assert!(matches!(result, Err(CipherBoxError::Aead(_))));
+    assert!(is_vec_fully_zeroized(&blob));
+}
+
+#[test]
+fn test_import_sealed_tampered_ciphertext_fails() {
+    let mut blob = export_with_f0(1);
+    let last = blob.len() - 1;
+    blob[last] ^= 0x01;
+
+    let result = TestBreakerCipherBox::import_sealed(
+        AeadMock::new(AeadMockBehaviour::None),
+        &mut blob,
+        &EXPORT_KEY,
+    );
+
+    assert!(matches!(result, Err(CipherBoxError::Aead(_))));
+}
+
+#[test]
+fn test_import_sealed_unsupported_version_fails() {
+    let mut blob = export_with_f0(1);
+    blob[..2].copy_from_slice(&2u16.to_le_bytes());
+
+    let result = TestBreakerCipherBox::import_sealed(
+        AeadMock::new(AeadMockBehaviour::None),
+        &mut blob,
+        &EXPORT_KEY,
+    );
+
+    assert!(matches!(result, Err(CipherBoxError::InvalidSealedBlob)));
+    assert!(is_vec_fully_zeroized(&blob));
+}
+
+#[test]
+fn test_import_sealed_truncated_blob_fails() {
+    let mut blob = export_with_f0(1);
+    let mut truncated = blob[..10].to_vec();
+    blob.fast_zeroize();
+
+    let result = TestBreakerCipherBox::import_sealed(
+        AeadMock::new(AeadMockBehaviour::None),
+        &mut truncated,
+        &EXPORT_KEY,
+    );
+
+    assert!(matches!(result, Err(CipherBoxError::InvalidSealedBlob)));
+}
+
 // =============================================================================
 // open_field()
 // =============================================================================
@@ -786,7 +1326,10 @@ fn test_open_field_propagates_decrypt_error() {
 
     assert!(result_1.is_err());
     assert!(result_2.is_err());
-    assert!(matches!(result_1, Err(CipherBoxError::Poisoned)));
+    assert!(matches!(
+        result_1,
+        Err(CipherBoxError::AuthenticationFailed)
+    ));
     assert!(matches!(result_2, Err(CipherBoxError::Poisoned)));
 }
 
@@ -916,7 +1459,10 @@ fn test_open_field_mut_propagates_decrypt_error() {
 
     assert!(result_1.is_err());
     assert!(result_2.is_err());
-    assert!(matches!(result_1, Err(CipherBoxError::Poisoned)));
+    assert!(matches!(
+        result_1,
+        Err(CipherBoxError::AuthenticationFailed)
+    ));
     assert!(matches!(result_2, Err(CipherBoxError::Poisoned)));
 }
 
@@ -931,7 +1477,7 @@ fn test_open_field_mut_propagates_encrypt_error() {
 
     assert!(cb.assert_healthy().is_err());
     assert!(result.is_err());
-    assert!(matches!(result, Err(CipherBoxError::Poisoned)));
+    assert!(matches!(result, Err(CipherBoxError::Reencrypt)));
 }
 
 #[test]
@@ -1054,7 +1600,10 @@ fn test_leak_field_propagates_decrypt_error() {
 
     assert!(result_1.is_err());
     assert!(result_2.is_err());
-    assert!(matches!(result_1, Err(CipherBoxError::Poisoned)));
+    assert!(matches!(
+        result_1,
+        Err(CipherBoxError::AuthenticationFailed)
+    ));
     assert!(matches!(result_2, Err(CipherBoxError::Poisoned)));
 }
 
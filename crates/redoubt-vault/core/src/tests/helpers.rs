@@ -4,19 +4,22 @@
 
 use redoubt_aead::AeadApi;
 use redoubt_aead::support::test_utils::{AeadMock, AeadMockBehaviour};
-use redoubt_codec::RedoubtCodecBuffer;
 use redoubt_codec::support::test_utils::{
     RedoubtCodecTestBreaker, RedoubtCodecTestBreakerBehaviour,
 };
+use redoubt_codec::{BytesRequired, Encode, RedoubtCodecBuffer};
 use redoubt_test_utils::{apply_permutation, index_permutations};
 use redoubt_zero::ZeroizationProbe;
 
-use crate::error::CipherBoxError;
+use crate::consts::AAD;
+use crate::error::{CipherBoxError, CryptoError};
 use crate::helpers::{
-    decrypt_from, encrypt_into, encrypt_into_buffers, get_sizes, to_decryptable_mut_dyn,
-    to_encryptable_mut_dyn,
+    decrypt_from, decrypt_from_with_limits, encrypt_into, encrypt_into_buffers, get_sizes,
+    to_decryptable_mut_dyn, to_encryptable_mut_dyn,
 };
 
+use crate::limits::{DEFAULT_MAX_PLAINTEXT_LEN, DecryptLimits};
+
 use super::consts::NUM_FIELDS;
 
 fn create_nonces(aead: &dyn AeadApi) -> [Vec<u8>; NUM_FIELDS] {
@@ -121,6 +124,43 @@ fn test_encrypt_into_propagates_errors() {
     assert!(matches!(result, Err(CipherBoxError::Poisoned)));
 }
 
+#[test]
+fn test_encrypt_into_rejects_field_over_default_limit() {
+    let mut test_breakers: [RedoubtCodecTestBreaker; NUM_FIELDS] = core::array::from_fn(|i| {
+        if i == 0 {
+            RedoubtCodecTestBreaker::new(
+                RedoubtCodecTestBreakerBehaviour::BytesRequiredReturn(
+                    DEFAULT_MAX_PLAINTEXT_LEN + 1,
+                ),
+                10,
+            )
+        } else {
+            RedoubtCodecTestBreaker::new(RedoubtCodecTestBreakerBehaviour::None, i << 2)
+        }
+    });
+
+    // Nothing is encrypted: AEAD would fail on the first call if reached.
+    let mut aead = AeadMock::new(AeadMockBehaviour::FailAtNthEncrypt(1));
+    let aead_key = [0u8; 32];
+    let mut nonces = create_nonces(&aead);
+    let mut tags = create_tags(&aead);
+    let original_nonces = nonces.clone();
+    let original_tags = tags.clone();
+
+    let fields = test_breakers
+        .each_mut()
+        .map(|tb| to_encryptable_mut_dyn(tb));
+
+    let result = encrypt_into(fields, &mut aead, &aead_key, &mut nonces, &mut tags);
+
+    assert!(matches!(
+        result,
+        Err(CipherBoxError::Crypto(CryptoError::TooLarge))
+    ));
+    assert_eq!(nonces, original_nonces);
+    assert_eq!(tags, original_tags);
+}
+
 // =============================================================================
 // encrypt_into_buffers tests
 // =============================================================================
@@ -460,7 +500,7 @@ fn test_decrypt_from_zeroizes_on_decrypt_failure() {
             &mut ciphertexts_clone,
         );
         assert!(result.is_err());
-        assert!(matches!(result, Err(CipherBoxError::Poisoned)));
+        assert!(matches!(result, Err(CipherBoxError::AuthenticationFailed)));
 
         // Postcondition: after failure, all ciphertexts must be zeroized.
         assert!(
@@ -559,3 +599,142 @@ fn test_decrypt_from_zeroizes_on_decode_failure() {
         );
     });
 }
+
+// =============================================================================
+// decrypt_from_with_limits()
+// =============================================================================
+
+#[test]
+fn test_decrypt_from_with_limits_rejects_oversized_field() {
+    let mut test_breakers =
+        [RedoubtCodecTestBreaker::new(RedoubtCodecTestBreakerBehaviour::None, 100); NUM_FIELDS];
+    let mut aead = AeadMock::new(AeadMockBehaviour::None);
+
+    let aead_key = [0u8; 32];
+    let mut nonces = create_nonces(&aead);
+    let mut tags = create_tags(&aead);
+    let mut ciphertexts = {
+        let fields = test_breakers
+            .each_mut()
+            .map(|tb| to_encryptable_mut_dyn(tb));
+
+        encrypt_into(fields, &mut aead, &aead_key, &mut nonces, &mut tags)
+            .expect("Failed to encrypt_into()")
+    };
+    let original = ciphertexts.clone();
+    let max_len = ciphertexts.iter().map(|c| c.len()).max().unwrap_or(0);
+
+    // Nothing is decrypted: AEAD would fail on the first call if reached.
+    let mut aead_mock = AeadMock::new(AeadMockBehaviour::FailAtNthDecrypt(1));
+    let mut fields = test_breakers
+        .each_mut()
+        .map(|tb| to_decryptable_mut_dyn(tb));
+
+    let result = decrypt_from_with_limits(
+        &mut fields,
+        &mut aead_mock,
+        &aead_key,
+        &mut nonces,
+        &mut tags,
+        &mut ciphertexts,
+        DecryptLimits::new(max_len - 1),
+    );
+
+    assert!(matches!(
+        result,
+        Err(CipherBoxError::Crypto(CryptoError::TooLarge))
+    ));
+    // Rejected before decryption: ciphertexts are left intact.
+    assert_eq!(ciphertexts, original);
+}
+
+#[test]
+fn test_decrypt_from_with_limits_accepts_field_at_limit() {
+    let mut test_breakers =
+        [RedoubtCodecTestBreaker::new(RedoubtCodecTestBreakerBehaviour::None, 100); NUM_FIELDS];
+    let mut aead = AeadMock::new(AeadMockBehaviour::None);
+
+    let aead_key = [0u8; 32];
+    let mut nonces = create_nonces(&aead);
+    let mut tags = create_tags(&aead);
+    let mut ciphertexts = {
+        let fields = test_breakers
+            .each_mut()
+            .map(|tb| to_encryptable_mut_dyn(tb));
+
+        encrypt_into(fields, &mut aead, &aead_key, &mut nonces, &mut tags)
+            .expect("Failed to encrypt_into()")
+    };
+    let max_len = ciphertexts.iter().map(|c| c.len()).max().unwrap_or(0);
+
+    let mut aead_mock = AeadMock::new(AeadMockBehaviour::None);
+    let mut fields = test_breakers
+        .each_mut()
+        .map(|tb| to_decryptable_mut_dyn(tb));
+
+    let result = decrypt_from_with_limits(
+        &mut fields,
+        &mut aead_mock,
+        &aead_key,
+        &mut nonces,
+        &mut tags,
+        &mut ciphertexts,
+        DecryptLimits::new(max_len),
+    );
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_decrypt_from_with_limits_rejects_inflated_collection_size() {
+    let mut aead = AeadMock::new(AeadMockBehaviour::None);
+    let aead_key = [0u8; 32];
+
+    // Encode a 16 byte Vec<u8>, then forge its header to claim 2^40 elements
+    let mut field = vec![0xAAu8; 16];
+    let bytes_required = field
+        .encode_bytes_required()
+        .expect("Failed to encode_bytes_required()");
+    let mut buf = RedoubtCodecBuffer::with_capacity(bytes_required);
+    field
+        .encode_into(&mut buf)
+        .expect("Failed to encode_into(..)");
+
+    let mut plaintext = buf.export_as_vec();
+    plaintext[..size_of::<usize>()].copy_from_slice(&(1usize << 40).to_ne_bytes());
+
+    // Authentic ciphertext: only the codec header is malicious
+    let mut nonces = [aead
+        .api_generate_nonce()
+        .expect("Failed to api_generate_nonce()")];
+    let mut tags = [vec![0u8; aead.api_tag_size()]];
+    aead.api_encrypt(&aead_key, &nonces[0], AAD, &mut plaintext, &mut tags[0])
+        .expect("Failed to api_encrypt(..)");
+    let mut ciphertexts = [plaintext];
+
+    // Well within the plaintext limit, yet must not preallocate 2^40 elements
+    let mut recovered: Vec<u8> = Vec::new();
+    let mut fields = [to_decryptable_mut_dyn(&mut recovered)];
+
+    let result = decrypt_from_with_limits(
+        &mut fields,
+        &mut aead,
+        &aead_key,
+        &mut nonces,
+        &mut tags,
+        &mut ciphertexts,
+        DecryptLimits::default(),
+    );
+
+    assert!(matches!(result, Err(CipherBoxError::Poisoned)));
+    assert_eq!(recovered.capacity(), 0);
+    assert!(ciphertexts.iter().all(|c| c.is_zeroized()));
+}
+
+#[test]
+fn test_decrypt_limits_default() {
+    assert_eq!(
+        DecryptLimits::default().max_plaintext_len,
+        crate::limits::DEFAULT_MAX_PLAINTEXT_LEN
+    );
+}
@@ -125,6 +125,7 @@ fn subprocess_create_initialized_buffer_succeeds_when_guard_syscalls_blocked() {
     let status = GuardStatus {
         prctl_succeeded: false,
         rlimit_succeeded: false,
+        mitigation_succeeded: false,
     };
 
     let mut buffer = create_initialized_buffer_with(status);
@@ -26,7 +26,8 @@ use proc_macro_crate::{FoundCrate, crate_name};
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{format_ident, quote};
 use syn::{
-    Attribute, Data, DeriveInput, Field, Fields, Ident, LitStr, Meta, Type, parse_macro_input,
+    Attribute, Data, DeriveInput, Field, Fields, Ident, LitByteStr, LitStr, Meta, Type,
+    parse_macro_input,
 };
 
 enum StorageStrategy {
@@ -34,6 +35,17 @@ enum StorageStrategy {
     Portable,
 }
 
+// (wrapper_name, custom_error_type, is_global, storage_strategy, testing_feature, aead, groups)
+type CipherBoxAttr = (
+    Ident,
+    Option<Type>,
+    bool,
+    Option<StorageStrategy>,
+    Option<String>,
+    Option<Type>,
+    Vec<Vec<Ident>>,
+);
+
 /// Derives a CipherBox wrapper struct with per-field access methods.
 ///
 /// **IMPORTANT**: This attribute macro MUST appear BEFORE `#[derive(RedoubtZero)]` to work correctly.
@@ -75,9 +87,10 @@ enum StorageStrategy {
 /// # Generated Code
 ///
 /// This generates:
-/// - `WalletSecretsCipherBox` wrapper struct
+/// - `WalletSecretsCipherBox` wrapper struct, whose AEAD key is derived with
+///   `WalletSecrets` as context
 /// - `EncryptStruct<N>` and `DecryptStruct<N>` trait impls
-/// - Per-field `leak_*`, `open_*`, `open_*_mut` methods
+/// - Per-field `leak_*`, `open_*`, `open_*_mut`, `map_*` methods
 /// - Global `open` and `open_mut` methods
 ///
 /// # Testing Utilities
@@ -100,9 +113,34 @@ enum StorageStrategy {
 /// [dev-dependencies]
 /// my-crate = { path = "...", features = ["test-utils"] }
 /// ```
+///
+/// # AEAD Backend
+///
+/// The wrapper uses `redoubt_aead::Aead` unless another backend is given with
+/// `aead = Type`. The type must implement `AeadApi` and `Default`:
+///
+/// ```ignore
+/// #[cipherbox(SecretsBox, aead = CommittingAead<Aead>)]
+/// #[derive(RedoubtZero, RedoubtCodec)]
+/// struct Secrets { ... }
+/// ```
+///
+/// # Field Groups
+///
+/// `group(a, b, ...)` generates `open_a_and_b(|a, b| ...)`, which decrypts only
+/// the listed fields and hands them to the closure together. The group may be
+/// repeated to declare several combinations:
+///
+/// ```ignore
+/// #[cipherbox(WalletBox, group(seed, pin_hash))]
+/// #[derive(RedoubtZero, RedoubtCodec)]
+/// struct Wallet { seed: [u8; 64], pin_hash: [u8; 32], ... }
+///
+/// wallet.open_seed_and_pin_hash(|seed, pin_hash| { ... })?;
+/// ```
 #[proc_macro_attribute]
 pub fn cipherbox(attr: TokenStream, item: TokenStream) -> TokenStream {
-    let (wrapper_name, custom_error, is_global, storage_strategy, testing_feature) =
+    let (wrapper_name, custom_error, is_global, storage_strategy, testing_feature, aead, groups) =
         parse_cipherbox_attr(attr);
     let input = parse_macro_input!(item as DeriveInput);
     expand(
@@ -111,6 +149,8 @@ pub fn cipherbox(attr: TokenStream, item: TokenStream) -> TokenStream {
         is_global,
         storage_strategy,
         testing_feature,
+        aead,
+        groups,
         input,
     )
     .unwrap_or_else(|e| e)
@@ -123,29 +163,17 @@ pub fn cipherbox(attr: TokenStream, item: TokenStream) -> TokenStream {
 //   - "WrapperName, error = ErrorType"
 //   - "WrapperName, global = true"
 //   - "WrapperName, testing_feature = \"feature-name\""
-// Returns (wrapper_name, custom_error_type, is_global, storage_strategy, testing_feature)
-fn parse_cipherbox_attr(
-    attr: TokenStream,
-) -> (
-    Ident,
-    Option<Type>,
-    bool,
-    Option<StorageStrategy>,
-    Option<String>,
-) {
+//   - "WrapperName, aead = AeadType"
+//   - "WrapperName, group(field_a, field_b)"
+// Returns (wrapper_name, custom_error_type, is_global, storage_strategy, testing_feature, aead, groups)
+fn parse_cipherbox_attr(attr: TokenStream) -> CipherBoxAttr {
     parse_cipherbox_attr_inner(attr.to_string())
 }
 
 // Internal parsing function that takes a string for testability
-pub(crate) fn parse_cipherbox_attr_inner(
-    attr_str: String,
-) -> (
-    Ident,
-    Option<Type>,
-    bool,
-    Option<StorageStrategy>,
-    Option<String>,
-) {
+pub(crate) fn parse_cipherbox_attr_inner(attr_str: String) -> CipherBoxAttr {
+    // `group(..)` contains commas of its own, so pull groups out before splitting
+    let (attr_str, groups) = extract_groups(&attr_str);
     let parts: Vec<&str> = attr_str.split(',').map(|s| s.trim()).collect();
 
     let wrapper_name =
@@ -155,6 +183,7 @@ pub(crate) fn parse_cipherbox_attr_inner(
     let mut is_global = false;
     let mut storage_strategy: Option<StorageStrategy> = None;
     let mut testing_feature: Option<String> = None;
+    let mut aead: Option<Type> = None;
 
     // Parse remaining parts
     for part in &parts[1..] {
@@ -188,6 +217,15 @@ pub(crate) fn parse_cipherbox_attr_inner(
         {
             let feature_str = value.trim().trim_matches('"');
             testing_feature = Some(feature_str.to_string());
+        } else if let Some(value) = part
+            .strip_prefix("aead")
+            .and_then(|s| s.trim().strip_prefix('='))
+        {
+            let aead_type_str = value.trim();
+            aead =
+                Some(syn::parse_str::<Type>(aead_type_str).expect("cipherbox: invalid aead type"));
+        } else if part.is_empty() {
+            // Left behind by an extracted `group(..)`
         } else {
             panic!("cipherbox: unknown attribute parameter: {}", part);
         }
@@ -199,8 +237,50 @@ pub(crate) fn parse_cipherbox_attr_inner(
         is_global,
         storage_strategy,
         testing_feature,
+        aead,
+        groups,
     )
 }
+
+// Removes every `group(a, b, ...)` from the attribute string and returns the
+// remaining string along with the parsed field names of each group.
+fn extract_groups(attr_str: &str) -> (String, Vec<Vec<Ident>>) {
+    let mut rest = String::new();
+    let mut groups = Vec::new();
+    let mut remaining = attr_str;
+
+    while let Some(start) = remaining.find("group") {
+        let after = remaining[start + "group".len()..].trim_start();
+        let is_word_start = remaining[..start]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !c.is_alphanumeric() && c != '_');
+
+        let Some(inner) = after.strip_prefix('(').filter(|_| is_word_start) else {
+            rest.push_str(&remaining[..start + "group".len()]);
+            remaining = &remaining[start + "group".len()..];
+            continue;
+        };
+
+        let end = inner
+            .find(')')
+            .expect("cipherbox: unterminated group(..) parameter");
+        let fields = inner[..end]
+            .split(',')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .map(|s| syn::parse_str::<Ident>(s).expect("cipherbox: invalid group field name"))
+            .collect();
+
+        groups.push(fields);
+        rest.push_str(&remaining[..start]);
+        remaining = &inner[end + 1..];
+    }
+
+    rest.push_str(remaining);
+
+    (rest, groups)
+}
 /// Find the root crate path from a list of candidates.
 /// Candidates can be crate names like "redoubt-vault" or paths like "redoubt::vault".
 pub(crate) fn find_root_with_candidates(candidates: &[&'static str]) -> TokenStream2 {
@@ -300,12 +380,15 @@ fn inject_zeroize_on_drop_sentinel(mut input: DeriveInput) -> DeriveInput {
     input
 }
 
+#[allow(clippy::too_many_arguments)]
 fn expand(
     wrapper_name: Ident,
     custom_error: Option<Type>,
     is_global: bool,
     storage_strategy: Option<StorageStrategy>,
     testing_feature: Option<String>,
+    aead: Option<Type>,
+    groups: Vec<Vec<Ident>>,
     input: DeriveInput,
 ) -> Result<TokenStream2, TokenStream2> {
     // Inject __sentinel field if it doesn't exist
@@ -320,6 +403,18 @@ fn expand(
         find_root_with_candidates(&["redoubt-zero-core", "redoubt-zero", "redoubt::zero"]);
     let redoubt_aead_root = find_root_with_candidates(&["redoubt-aead", "redoubt::aead"]);
 
+    // AEAD backend: `Aead` unless overridden with `aead = Type`
+    let (aead_type, aead_ctor) = match &aead {
+        Some(ty) => (
+            quote! { #ty },
+            quote! { <#ty as ::core::default::Default>::default() },
+        ),
+        None => (
+            quote! { #redoubt_aead_root::Aead },
+            quote! { #redoubt_aead_root::Aead::new() },
+        ),
+    };
+
     // Generate the test cfg attribute based on testing_feature
     let test_cfg = if let Some(ref feature) = testing_feature {
         quote! { #[cfg(any(test, feature = #feature))] }
@@ -359,6 +454,9 @@ fn expand(
     let num_fields = encryptable_fields.len();
     let num_fields_lit = syn::LitInt::new(&num_fields.to_string(), Span::call_site());
 
+    // The struct name is the key derivation context, so each box gets its own key
+    let context_lit = LitByteStr::new(struct_name.to_string().as_bytes(), Span::call_site());
+
     // Generate field references
     let mut_refs: Vec<TokenStream2> = encryptable_fields
         .iter()
@@ -404,6 +502,7 @@ fn expand(
     let mut leak_methods = Vec::new();
     let mut open_methods = Vec::new();
     let mut open_mut_methods = Vec::new();
+    let mut map_methods = Vec::new();
 
     // Vectors for global methods (populated in loop below if is_global)
     // IMPORTANT: These vectors contain code that MUST be injected inside `pub mod #global_module_name`
@@ -412,6 +511,7 @@ fn expand(
     let mut global_leak_methods = Vec::new();
     let mut global_open_methods = Vec::new();
     let mut global_open_mut_methods = Vec::new();
+    let mut global_map_methods = Vec::new();
 
     // Determine storage strategy for global storage
     let use_portable_storage = if let Some(strategy) = storage_strategy {
@@ -420,6 +520,15 @@ fn expand(
         !cfg!(feature = "std")
     };
 
+    // The std storage keeps the box in a static `Mutex`, which needs `Send`
+    if is_global && !use_portable_storage && cfg!(feature = "single-thread") {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "cipherbox: std global storage requires Send, which `single-thread` removes; use storage = \"portable\"",
+        )
+        .to_compile_error());
+    }
+
     for (idx, (_, field)) in encryptable_fields.iter().enumerate() {
         let field_name = field.ident.as_ref().unwrap();
         let field_type = &field.ty;
@@ -428,6 +537,7 @@ fn expand(
         let leak_name = format_ident!("leak_{}", field_name);
         let open_name = format_ident!("open_{}", field_name);
         let open_mut_name = format_ident!("open_{}_mut", field_name);
+        let map_name = format_ident!("map_{}", field_name);
 
         leak_methods.push(quote! {
             #[inline(always)]
@@ -461,6 +571,18 @@ fn expand(
             }
         });
 
+        map_methods.push(quote! {
+            #[inline(always)]
+            pub fn #map_name<F, R>(&mut self, f: F) -> Result<R, #error_type>
+            where
+                F: FnOnce(&#field_type) -> R,
+            {
+                #failure_check
+                let field = self.inner.leak_field::<#field_type, #idx_lit, #error_type>()?;
+                Ok(f(&field))
+            }
+        });
+
         // Generate global methods if needed
         // Note: These methods reference the internal module which is generated later.
         // The internal module name follows the pattern: __{wrapper_name}_internal (lowercase)
@@ -511,6 +633,19 @@ fn expand(
                         instance.#open_mut_name(f)
                     }
                 });
+
+                // Portable: Global map method
+                global_map_methods.push(quote! {
+                    pub fn #map_name<F, R>(f: F) -> Result<R, #error_type>
+                    where
+                        F: FnOnce(&#field_type) -> R,
+                    {
+                        #internal_module_name::lock();
+                        let _guard = #internal_module_name::PanicGuard;
+                        let instance = #internal_module_name::get_or_init();
+                        instance.#map_name(f)
+                    }
+                });
             } else {
                 // std: Global leak method
                 global_leak_methods.push(quote! {
@@ -546,6 +681,139 @@ fn expand(
                         guard.#open_mut_name(f)
                     }
                 });
+
+                // std: Global map method
+                global_map_methods.push(quote! {
+                    pub fn #map_name<F, R>(f: F) -> Result<R, #error_type>
+                    where
+                        F: FnOnce(&#field_type) -> R,
+                    {
+                        let mutex = #internal_module_name::get_or_init();
+                        let mut guard = mutex.lock().unwrap_or_else(|p| p.into_inner());
+                        guard.#map_name(f)
+                    }
+                });
+            }
+        }
+    }
+
+    // Generate grouped open methods for each `group(..)`
+    let mut group_open_methods = Vec::new();
+
+    for group in &groups {
+        if group.len() < 2 {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "cipherbox: group(..) must list at least two fields.",
+            )
+            .to_compile_error());
+        }
+
+        let mut group_fields = Vec::new();
+
+        for (pos, name) in group.iter().enumerate() {
+            if group[..pos].contains(name) {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    format!("cipherbox: field `{}` is listed twice in group(..).", name),
+                )
+                .to_compile_error());
+            }
+
+            let Some((idx, field)) = encryptable_fields
+                .iter()
+                .enumerate()
+                .find(|(_, (_, f))| f.ident.as_ref() == Some(name))
+                .map(|(idx, (_, f))| (idx, *f))
+            else {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    format!(
+                        "cipherbox: group(..) field `{}` is not an encrypted field of `{}`.",
+                        name, struct_name
+                    ),
+                )
+                .to_compile_error());
+            };
+
+            group_fields.push((name, idx, &field.ty));
+        }
+
+        let group_name = format_ident!(
+            "open_{}",
+            group
+                .iter()
+                .map(|name| name.to_string())
+                .collect::<Vec<_>>()
+                .join("_and_")
+        );
+        let field_types: Vec<&Type> = group_fields.iter().map(|(_, _, ty)| *ty).collect();
+        let locals: Vec<Ident> = group_fields
+            .iter()
+            .map(|(name, _, _)| format_ident!("__{}", name))
+            .collect();
+        let leaks: Vec<TokenStream2> = group_fields
+            .iter()
+            .zip(&locals)
+            .map(|((_, idx, ty), local)| {
+                let idx_lit = syn::LitInt::new(&idx.to_string(), Span::call_site());
+                quote! {
+                    let #local = self.inner.leak_field::<#ty, #idx_lit, #error_type>()?;
+                }
+            })
+            .collect();
+
+        // Each field is decrypted on its own; ciphertexts are left untouched, so no re-encryption.
+        group_open_methods.push(quote! {
+            #[inline(always)]
+            pub fn #group_name<F, R>(&mut self, mut f: F) -> Result<#redoubt_zero_root::ZeroizingGuard<R>, #error_type>
+            where
+                F: FnMut(#( &#field_types ),*) -> Result<R, #error_type>,
+                R: Default + #redoubt_zero_root::FastZeroizable + #redoubt_zero_root::ZeroizationProbe,
+            {
+                #failure_check
+                #( #leaks )*
+                let mut result = f(#( &*#locals ),*)?;
+                Ok(#redoubt_zero_root::ZeroizingGuard::from_mut(&mut result))
+            }
+        });
+
+        if is_global {
+            let internal_module_name = format_ident!(
+                "__{}_internal",
+                wrapper_name
+                    .to_string()
+                    .to_shouty_snake_case()
+                    .to_lowercase()
+            );
+
+            if use_portable_storage {
+                // Portable: Global grouped open method
+                global_open_methods.push(quote! {
+                    pub fn #group_name<F, R>(f: F) -> Result<#redoubt_zero_root::ZeroizingGuard<R>, #error_type>
+                    where
+                        F: FnMut(#( &#field_types ),*) -> Result<R, #error_type>,
+                        R: Default + #redoubt_zero_root::FastZeroizable + #redoubt_zero_root::ZeroizationProbe,
+                    {
+                        #internal_module_name::lock();
+                        let _guard = #internal_module_name::PanicGuard;
+                        let instance = #internal_module_name::get_or_init();
+                        instance.#group_name(f)
+                    }
+                });
+            } else {
+                // std: Global grouped open method
+                global_open_methods.push(quote! {
+                    pub fn #group_name<F, R>(f: F) -> Result<#redoubt_zero_root::ZeroizingGuard<R>, #error_type>
+                    where
+                        F: FnMut(#( &#field_types ),*) -> Result<R, #error_type>,
+                        R: Default + #redoubt_zero_root::FastZeroizable + #redoubt_zero_root::ZeroizationProbe,
+                    {
+                        let mutex = #internal_module_name::get_or_init();
+                        let mut guard = mutex.lock().unwrap_or_else(|p| p.into_inner());
+                        guard.#group_name(f)
+                    }
+                });
             }
         }
     }
@@ -707,6 +975,7 @@ fn expand(
                     #( #global_leak_methods )*
                     #( #global_open_methods )*
                     #( #global_open_mut_methods )*
+                    #( #global_map_methods )*
                 }
 
                 impl #redoubt_zero_root::StaticFastZeroizable for #global_struct_name {
@@ -766,6 +1035,7 @@ fn expand(
                     #( #global_leak_methods )*
                     #( #global_open_methods )*
                     #( #global_open_mut_methods )*
+                    #( #global_map_methods )*
                 }
 
                 impl #redoubt_zero_root::StaticFastZeroizable for #global_struct_name {
@@ -850,7 +1120,7 @@ fn expand(
         // Generate wrapper struct
         #[derive(#redoubt_zero_root::RedoubtZero)]
         pub struct #wrapper_name {
-            inner: #root::CipherBox<#struct_name, #redoubt_aead_root::Aead, #num_fields_lit>,
+            inner: #root::CipherBox<#struct_name, #aead_type, #num_fields_lit>,
             #test_cfg
             failure_counter: usize,
         }
@@ -859,7 +1129,7 @@ fn expand(
             #[inline(always)]
             pub fn new() -> Self {
                 Self {
-                    inner: #root::CipherBox::new(#redoubt_aead_root::Aead::new()),
+                    inner: #root::CipherBox::with_context(#aead_ctor, #context_lit),
                     #test_cfg
                     failure_counter: 0,
                 }
@@ -885,6 +1155,13 @@ fn expand(
                 self.inner.open_mut(f)
             }
 
+            #[inline(always)]
+            pub fn rekey(&mut self) -> Result<(), #error_type> {
+                #failure_check
+                self.inner.rekey()?;
+                Ok(())
+            }
+
             #test_cfg
             pub fn set_failure_mode(&mut self, mode: #failure_mode_enum_name) {
                 match mode {
@@ -902,6 +1179,10 @@ fn expand(
             #( #open_methods )*
 
             #( #open_mut_methods )*
+
+            #( #map_methods )*
+
+            #( #group_open_methods )*
         }
 
         impl Default for #wrapper_name {
@@ -106,6 +106,8 @@ fn snapshot_named_struct_ok() {
         false,
         None,
         None,
+        None,
+        vec![],
         derive_input,
     )
     .expect("expand failed");
@@ -126,6 +128,8 @@ fn snapshot_named_struct_with_single_field() {
         false,
         None,
         None,
+        None,
+        vec![],
         derive_input,
     )
     .expect("expand failed");
@@ -147,6 +151,8 @@ fn snapshot_named_struct_with_generics() {
         false,
         None,
         None,
+        None,
+        vec![],
         derive_input,
     )
     .expect("expand failed");
@@ -170,6 +176,8 @@ fn snapshot_named_struct_with_custom_error() {
         false,
         None,
         None,
+        None,
+        vec![],
         derive_input,
     )
     .expect("expand failed");
@@ -198,6 +206,8 @@ fn snapshot_named_struct_with_codec_default_field() {
         false,
         None,
         None,
+        None,
+        vec![],
         derive_input,
     )
     .expect("expand failed");
@@ -223,6 +233,8 @@ fn snapshot_named_struct_with_zeroize_on_drop_sentinel() {
         false,
         None,
         None,
+        None,
+        vec![],
         derive_input,
     )
     .expect("expand failed");
@@ -250,6 +262,8 @@ fn snapshot_named_struct_with_multiple_filtered_fields() {
         false,
         None,
         None,
+        None,
+        vec![],
         derive_input,
     )
     .expect("expand failed");
@@ -276,6 +290,8 @@ fn snapshot_empty_struct_with_only_sentinel() {
         false,
         None,
         None,
+        None,
+        vec![],
         derive_input,
     )
     .expect("expand failed");
@@ -299,6 +315,8 @@ fn snapshot_struct_with_all_fields_filtered() {
         false,
         None,
         None,
+        None,
+        vec![],
         derive_input,
     )
     .expect("expand failed");
@@ -321,6 +339,8 @@ fn snapshot_unit_struct_ok() {
         false,
         None,
         None,
+        None,
+        vec![],
         derive_input,
     )
     .expect("expand failed");
@@ -344,6 +364,8 @@ fn snapshot_tuple_struct_fails() {
         false,
         None,
         None,
+        None,
+        vec![],
         derive_input,
     );
     assert!(result.is_err());
@@ -367,6 +389,8 @@ fn snapshot_enum_fails() {
         false,
         None,
         None,
+        None,
+        vec![],
         derive_input,
     );
     assert!(result.is_err());
@@ -390,11 +414,39 @@ fn snapshot_union_fails() {
         false,
         None,
         None,
+        None,
+        vec![],
         derive_input,
     );
     assert!(result.is_err());
 }
 
+#[cfg(feature = "single-thread")]
+#[test]
+fn test_global_std_storage_fails_with_single_thread() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero, RedoubtCodec)]
+        struct Data {
+            pub alpha: [u8; 32],
+        }
+    };
+
+    let result = expand(
+        syn::parse_quote!(DataBox),
+        None,
+        true,
+        Some(crate::StorageStrategy::Std),
+        None,
+        None,
+        vec![],
+        derive_input,
+    );
+    assert!(result.is_err());
+
+    let err_str = format!("{}", result.unwrap_err());
+    assert!(err_str.contains("single-thread"));
+}
+
 #[test]
 #[should_panic(expected = "cipherbox: unknown attribute parameter")]
 fn test_unknown_attribute_panics() {
@@ -403,15 +455,169 @@ fn test_unknown_attribute_panics() {
 
 #[test]
 fn test_parse_testing_feature() {
-    let (name, error, global, storage, testing_feature) = crate::parse_cipherbox_attr_inner(
-        "SecretsBox, testing_feature = \"test-utils\"".to_string(),
-    );
+    let (name, error, global, storage, testing_feature, aead, groups) =
+        crate::parse_cipherbox_attr_inner(
+            "SecretsBox, testing_feature = \"test-utils\"".to_string(),
+        );
 
     assert_eq!(name.to_string(), "SecretsBox");
     assert!(error.is_none());
     assert!(!global);
     assert!(storage.is_none());
     assert_eq!(testing_feature, Some("test-utils".to_string()));
+    assert!(aead.is_none());
+    assert!(groups.is_empty());
+}
+
+#[test]
+fn test_parse_aead() {
+    let (name, _, _, _, _, aead, _) =
+        crate::parse_cipherbox_attr_inner("SecretsBox, aead = CommittingAead<Aead>".to_string());
+
+    assert_eq!(name.to_string(), "SecretsBox");
+
+    let aead = aead.expect("aead should be parsed");
+    assert_eq!(quote::quote!(#aead).to_string(), "CommittingAead < Aead >");
+}
+
+// === === === === === === === === === ===
+// aead attribute
+// === === === === === === === === === ===
+
+#[test]
+fn snapshot_named_struct_with_aead() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero, RedoubtCodec)]
+        struct Secrets {
+            pub secret_key: [u8; 32],
+        }
+    };
+
+    let token_stream = expand(
+        syn::parse_quote!(SecretsBox),
+        None,
+        false,
+        None,
+        None,
+        Some(syn::parse_quote!(CommittingAead<Aead>)),
+        vec![],
+        derive_input,
+    )
+    .expect("expand failed");
+    insta::assert_snapshot!(pretty(token_stream));
+}
+
+#[test]
+fn test_parse_groups() {
+    let (name, error, _, _, _, _, groups) = crate::parse_cipherbox_attr_inner(
+        "WalletBox, group(seed, pin_hash), error = WalletError, group(seed, salt)".to_string(),
+    );
+
+    assert_eq!(name.to_string(), "WalletBox");
+    assert!(error.is_some());
+
+    let groups: Vec<Vec<String>> = groups
+        .iter()
+        .map(|group| group.iter().map(|field| field.to_string()).collect())
+        .collect();
+    assert_eq!(groups, vec![vec!["seed", "pin_hash"], vec!["seed", "salt"]]);
+}
+
+// === === === === === === === === === ===
+// group attribute
+// === === === === === === === === === ===
+
+#[test]
+fn snapshot_named_struct_with_group() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero, RedoubtCodec)]
+        struct Wallet {
+            pub seed: [u8; 64],
+            pub salt: [u8; 16],
+            pub pin_hash: [u8; 32],
+        }
+    };
+
+    let token_stream = expand(
+        syn::parse_quote!(WalletBox),
+        None,
+        false,
+        None,
+        None,
+        None,
+        vec![vec![syn::parse_quote!(seed), syn::parse_quote!(pin_hash)]],
+        derive_input,
+    )
+    .expect("expand failed");
+    insta::assert_snapshot!(pretty(token_stream));
+}
+
+#[test]
+fn test_group_with_unknown_field_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero, RedoubtCodec)]
+        struct Wallet {
+            pub seed: [u8; 64],
+            #[codec(default)]
+            pub cache: [u8; 16],
+        }
+    };
+
+    let result = expand(
+        syn::parse_quote!(WalletBox),
+        None,
+        false,
+        None,
+        None,
+        None,
+        vec![vec![syn::parse_quote!(seed), syn::parse_quote!(cache)]],
+        derive_input,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_group_with_single_field_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero, RedoubtCodec)]
+        struct Wallet {
+            pub seed: [u8; 64],
+        }
+    };
+
+    let result = expand(
+        syn::parse_quote!(WalletBox),
+        None,
+        false,
+        None,
+        None,
+        None,
+        vec![vec![syn::parse_quote!(seed)]],
+        derive_input,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_group_with_duplicate_field_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero, RedoubtCodec)]
+        struct Wallet {
+            pub seed: [u8; 64],
+        }
+    };
+
+    let result = expand(
+        syn::parse_quote!(WalletBox),
+        None,
+        false,
+        None,
+        None,
+        None,
+        vec![vec![syn::parse_quote!(seed), syn::parse_quote!(seed)]],
+        derive_input,
+    );
+    assert!(result.is_err());
 }
 
 // === === === === === === === === === ===
@@ -433,6 +639,8 @@ fn snapshot_named_struct_with_testing_feature() {
         false,
         None,
         Some("test-utils".to_string()),
+        None,
+        vec![],
         derive_input,
     )
     .expect("expand failed");
@@ -8,7 +8,7 @@ mod tests {
     use redoubt_vault_derive::cipherbox;
     use redoubt_zero::{RedoubtZero, ZeroizationProbe, ZeroizingGuard};
 
-    #[cipherbox(WalletSecretsCipherBox)]
+    #[cipherbox(WalletSecretsCipherBox, group(master_seed, pin_hash))]
     #[derive(Default, RedoubtZero, RedoubtCodec)]
     #[fast_zeroize(drop)]
     struct WalletSecrets {
@@ -18,6 +18,40 @@ mod tests {
         pin_hash: [u8; 32],
     }
 
+    #[cipherbox(CommittedSecretsCipherBox, aead = redoubt_aead::CommittingAead<redoubt_aead::Aead>)]
+    #[derive(Default, RedoubtZero, RedoubtCodec)]
+    #[fast_zeroize(drop)]
+    struct CommittedSecrets {
+        master_seed: [u8; 32],
+        pin_hash: [u8; 32],
+    }
+
+    #[test]
+    fn test_cipherbox_wrapper_with_aead_backend() {
+        let mut cb = CommittedSecretsCipherBox::new();
+
+        cb.open_mut(|cs| {
+            cs.master_seed = [0x42; 32];
+            cs.pin_hash = [0xEF; 32];
+
+            Ok(())
+        })
+        .expect("Failed to open_mut(..)");
+
+        cb.open(|cs| {
+            assert_eq!(cs.master_seed, [0x42; 32]);
+            assert_eq!(cs.pin_hash, [0xEF; 32]);
+
+            Ok(())
+        })
+        .expect("Failed to open(..)");
+
+        let seed = cb
+            .leak_master_seed()
+            .expect("Failed to leak_master_seed(..)");
+        assert_eq!(*seed, [0x42; 32]);
+    }
+
     #[test]
     fn test_cipherbox_wrapper_new() {
         let _cb = WalletSecretsCipherBox::new();
@@ -150,6 +184,70 @@ mod tests {
         .expect("Failed to open(..)");
     }
 
+    #[test]
+    fn test_cipherbox_wrapper_open_group() {
+        let mut cb = WalletSecretsCipherBox::new();
+
+        cb.open_mut(|ws| {
+            ws.master_seed = [0x42; 32];
+            ws.encryption_key = [0xAB; 32];
+            ws.signing_key = [0xCD; 32];
+            ws.pin_hash = [0xEF; 32];
+
+            Ok(())
+        })
+        .expect("Failed to open_mut(..)");
+
+        let matches = cb
+            .open_master_seed_and_pin_hash(|seed, pin_hash| {
+                assert_eq!(*seed, [0x42; 32]);
+                assert_eq!(*pin_hash, [0xEF; 32]);
+
+                Ok(seed[0] == 0x42 && pin_hash[0] == 0xEF)
+            })
+            .expect("Failed to open_master_seed_and_pin_hash(..)");
+        assert!(*matches);
+
+        // Grouped open leaves the other fields untouched
+        cb.open(|ws| {
+            assert_eq!(ws.master_seed, [0x42; 32]);
+            assert_eq!(ws.encryption_key, [0xAB; 32]);
+            assert_eq!(ws.signing_key, [0xCD; 32]);
+            assert_eq!(ws.pin_hash, [0xEF; 32]);
+
+            Ok(())
+        })
+        .expect("Failed to open(..)");
+    }
+
+    #[test]
+    fn test_cipherbox_wrapper_rekey() {
+        let mut cb = WalletSecretsCipherBox::new();
+
+        cb.open_mut(|ws| {
+            ws.master_seed = [0x42; 32];
+            ws.pin_hash = [0xEF; 32];
+
+            Ok(())
+        })
+        .expect("Failed to open_mut(..)");
+
+        cb.rekey().expect("Failed to rekey()");
+
+        let seed = cb
+            .leak_master_seed()
+            .expect("Failed to leak_master_seed(..)");
+        assert_eq!(*seed, [0x42; 32]);
+
+        cb.open(|ws| {
+            assert_eq!(ws.pin_hash, [0xEF; 32]);
+            assert!(ws.signing_key.is_zeroized());
+
+            Ok(())
+        })
+        .expect("Failed to open(..)");
+    }
+
     #[test]
     fn test_cipherbox_wrapper_leak_field() {
         let mut cb = WalletSecretsCipherBox::new();
@@ -184,6 +282,40 @@ mod tests {
         .expect("Failed to open(..)");
     }
 
+    #[test]
+    fn test_cipherbox_wrapper_map_field() {
+        let mut cb = WalletSecretsCipherBox::new();
+
+        cb.open_mut(|ws| {
+            ws.master_seed = [0x42; 32];
+            ws.pin_hash = [0xEF; 32];
+
+            Ok(())
+        })
+        .expect("Failed to open_mut(..)");
+
+        // FnOnce: the closure may consume captured state
+        let prefix = String::from("seed:");
+        let label = cb
+            .map_master_seed(move |seed| format!("{}{:02x}", prefix, seed[0]))
+            .expect("Failed to map_master_seed(..)");
+        assert_eq!(label, "seed:42");
+
+        let sum = cb
+            .map_pin_hash(|hash| hash.iter().map(|b| *b as u32).sum::<u32>())
+            .expect("Failed to map_pin_hash(..)");
+        assert_eq!(sum, 0xEF * 32);
+
+        // Verify original cipherbox is unchanged
+        cb.open(|ws| {
+            assert_eq!(ws.master_seed, [0x42; 32]);
+            assert_eq!(ws.pin_hash, [0xEF; 32]);
+
+            Ok(())
+        })
+        .expect("Failed to open(..)");
+    }
+
     #[test]
     fn test_cipherbox_api() {
         let mut cb = WalletSecretsCipherBox::new();
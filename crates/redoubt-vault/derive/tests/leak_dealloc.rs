@@ -0,0 +1,107 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! Tests that leaked dynamic-length fields leave no plaintext behind once freed.
+//!
+//! Lives in its own test binary because it installs a probing global allocator.
+
+#[cfg(test)]
+mod leak_dealloc {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+
+    use redoubt_alloc::RedoubtVec;
+    use redoubt_codec::RedoubtCodec;
+    use redoubt_util::is_vec_fully_zeroized;
+    use redoubt_vault_derive::cipherbox;
+    use redoubt_zero::{FastZeroizable, RedoubtZero};
+
+    const NOT_FREED: u8 = 0;
+    const FREED_ZEROIZED: u8 = 1;
+    const FREED_DIRTY: u8 = 2;
+
+    /// Address of the block to inspect on `dealloc` (0 = none).
+    static WATCHED: AtomicUsize = AtomicUsize::new(0);
+    static WATCHED_STATE: AtomicU8 = AtomicU8::new(NOT_FREED);
+
+    /// Records whether the watched block is fully zeroed when it is freed.
+    struct ProbeAllocator;
+
+    unsafe impl GlobalAlloc for ProbeAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            // SAFETY: Caller upholds the `GlobalAlloc::alloc` contract
+            unsafe { System.alloc(layout) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            if ptr as usize == WATCHED.load(Ordering::SeqCst) {
+                // SAFETY: `ptr` is a live block of `layout.size()` bytes until freed below
+                let block = unsafe { core::slice::from_raw_parts(ptr, layout.size()) };
+                let state = if block.iter().all(|b| *b == 0) {
+                    FREED_ZEROIZED
+                } else {
+                    FREED_DIRTY
+                };
+                WATCHED_STATE.store(state, Ordering::SeqCst);
+            }
+
+            // SAFETY: Caller upholds the `GlobalAlloc::dealloc` contract
+            unsafe { System.dealloc(ptr, layout) }
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: ProbeAllocator = ProbeAllocator;
+
+    #[cipherbox(SessionCipherBox)]
+    #[derive(Default, RedoubtZero, RedoubtCodec)]
+    #[fast_zeroize(drop)]
+    struct Session {
+        token: RedoubtVec<u8>,
+        pin: [u8; 4],
+    }
+
+    fn session_with_token(token: &mut [u8]) -> SessionCipherBox {
+        let mut cb = SessionCipherBox::new();
+
+        cb.open_mut(|s| {
+            s.token.extend_from_mut_slice(token);
+            s.pin = [1, 2, 3, 4];
+
+            Ok(())
+        })
+        .expect("Failed to open_mut(..)");
+
+        cb
+    }
+
+    #[test]
+    fn test_leak_redoubt_vec_field_guard_zeroizes_whole_allocation() {
+        let mut cb = session_with_token(&mut [0xAB; 5]);
+
+        let mut token = cb.leak_token().expect("Failed to leak_token()");
+        assert_eq!(token.as_slice(), [0xAB; 5]);
+
+        token.fast_zeroize();
+
+        // Covers spare capacity as well as the logical slice
+        assert!(is_vec_fully_zeroized(token.as_vec()));
+    }
+
+    #[test]
+    fn test_leak_redoubt_vec_field_guard_drop_frees_zeroized_allocation() {
+        let mut cb = session_with_token(&mut [0xCD; 13]);
+
+        let token = cb.leak_token().expect("Failed to leak_token()");
+        assert_eq!(token.as_slice(), [0xCD; 13]);
+
+        WATCHED_STATE.store(NOT_FREED, Ordering::SeqCst);
+        WATCHED.store(token.as_vec().as_ptr() as usize, Ordering::SeqCst);
+
+        drop(token);
+
+        WATCHED.store(0, Ordering::SeqCst);
+        assert_eq!(WATCHED_STATE.load(Ordering::SeqCst), FREED_ZEROIZED);
+    }
+}
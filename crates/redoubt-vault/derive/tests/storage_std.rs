@@ -4,7 +4,8 @@
 
 //! Tests for global storage with std strategy
 
-#[cfg(test)]
+// std storage needs a `Send` box, which `single-thread` removes
+#[cfg(all(test, not(feature = "single-thread")))]
 mod storage_std {
     use std::sync::atomic::{AtomicU64, Ordering};
 
@@ -16,6 +16,7 @@ mod test_utils {
     const MAX_ITERATIONS: usize = 100;
 
     // Global CipherBox for testing global failure injection
+    #[cfg(not(feature = "single-thread"))]
     #[cipherbox(TestGlobalBox, global = true)]
     #[derive(Default, RedoubtCodec, RedoubtZero)]
     struct TestGlobalData {
@@ -154,6 +155,7 @@ mod test_utils {
     }
 
     // Global CipherBox tests
+    #[cfg(not(feature = "single-thread"))]
     #[test]
     fn test_failure_injection_global_set_failure_mode() {
         // Set failure mode on global
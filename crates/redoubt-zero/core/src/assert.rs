@@ -4,7 +4,7 @@
 
 //! Test helpers for verifying zeroization behavior.
 
-use super::traits::AssertZeroizeOnDrop;
+use super::traits::{AssertZeroizeOnDrop, ZeroizationProbe};
 
 /// Asserts that a value zeroizes itself when dropped.
 ///
@@ -32,3 +32,33 @@ pub fn assert_zeroize_on_drop<T: AssertZeroizeOnDrop>(value: T) {
     drop(value);
     assert!(sentinel.is_zeroized());
 }
+
+/// Like [`assert_zeroize_on_drop`], but first asserts the value is NOT zeroized.
+///
+/// A value that is already all zeros (e.g. built from default data) passes
+/// [`assert_zeroize_on_drop`] trivially as far as its contents are concerned.
+/// Initialize the value with non-zero data and use this helper to rule out
+/// such false positives.
+///
+/// # Panics
+///
+/// Panics if the value reports itself as zeroized before drop, or if its
+/// [`ZeroizeOnDropSentinel`](crate::ZeroizeOnDropSentinel) was not marked as
+/// zeroized during drop.
+pub fn assert_not_zeroized_without_drop<T>(value: T)
+where
+    T: AssertZeroizeOnDrop + ZeroizationProbe,
+{
+    assert!(
+        !value.is_zeroized(),
+        "value is already zeroized before drop; initialize it with non-zero data"
+    );
+
+    let mut sentinel = value.clone_sentinel();
+
+    sentinel.reset();
+
+    assert!(!sentinel.is_zeroized());
+    drop(value);
+    assert!(sentinel.is_zeroized());
+}
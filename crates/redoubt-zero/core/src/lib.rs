@@ -10,6 +10,7 @@
 //!
 //! - **[`ZeroizeOnDropSentinel`]**: Runtime verification that zeroization happened before drop
 //! - **[`ZeroizingMutGuard`]**: RAII guard for mutable references (auto-zeroizes on drop)
+//! - **[`ZeroizingSliceGuard`]**: [`ZeroizingMutGuard`] over a borrowed `&mut [u8]`
 //! - **Traits**: [`FastZeroizable`], [`ZeroizationProbe`], [`AssertZeroizeOnDrop`], [`MutGuarded`]
 //! - **Derive macro**: `#[derive(RedoubtZero)]` for automatic trait implementations
 //!
@@ -146,4 +147,4 @@ pub use traits::{
 };
 pub use zeroize_on_drop_sentinel::ZeroizeOnDropSentinel;
 pub use zeroizing_guard::ZeroizingGuard;
-pub use zeroizing_mut_guard::ZeroizingMutGuard;
+pub use zeroizing_mut_guard::{ZeroizingMutGuard, ZeroizingSliceGuard};
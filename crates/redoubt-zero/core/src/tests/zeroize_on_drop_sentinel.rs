@@ -145,3 +145,50 @@ fn test_sentinel_reset() {
     assert!(sentinel.is_zeroized());
     assert!(sentinel_clone.is_zeroized());
 }
+
+#[test]
+fn test_sentinel_has_been_dropped() {
+    let sentinel = ZeroizeOnDropSentinel::default();
+    let observer = sentinel.clone();
+
+    assert!(!observer.has_been_dropped());
+
+    drop(sentinel);
+
+    assert!(observer.has_been_dropped());
+    // Dropping does not imply zeroization
+    assert!(!observer.is_zeroized());
+}
+
+#[test]
+fn test_sentinel_has_been_dropped_through_owner() {
+    struct Test {
+        __sentinel: ZeroizeOnDropSentinel,
+    }
+
+    let t = Test {
+        __sentinel: ZeroizeOnDropSentinel::default(),
+    };
+    let observer = t.__sentinel.clone();
+
+    assert!(!observer.has_been_dropped());
+
+    drop(t);
+
+    assert!(observer.has_been_dropped());
+}
+
+#[test]
+fn test_sentinel_has_been_dropped_ignores_observer_drop() {
+    let sentinel = ZeroizeOnDropSentinel::default();
+    let observer = sentinel.clone();
+    let other_observer = sentinel.clone();
+
+    drop(other_observer);
+
+    assert!(!observer.has_been_dropped());
+
+    drop(sentinel);
+
+    assert!(observer.has_been_dropped());
+}
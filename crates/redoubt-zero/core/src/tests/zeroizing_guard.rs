@@ -2,8 +2,28 @@
 // SPDX-License-Identifier: GPL-3.0-only
 // See LICENSE in the repository root for full license text.
 
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use crate::{AssertZeroizeOnDrop, FastZeroizable, ZeroizationProbe, ZeroizingGuard};
 
+/// Bytes shared with an observer, so a test can read them after the guard
+/// holding them is gone.
+#[derive(Default)]
+struct SharedBytes(Rc<RefCell<[u8; 32]>>);
+
+impl FastZeroizable for SharedBytes {
+    fn fast_zeroize(&mut self) {
+        self.0.borrow_mut().fast_zeroize();
+    }
+}
+
+impl ZeroizationProbe for SharedBytes {
+    fn is_zeroized(&self) -> bool {
+        self.0.borrow().is_zeroized()
+    }
+}
+
 #[test]
 fn test_zeroizing_guard_from_default() {
     let guard: ZeroizingGuard<u64> = ZeroizingGuard::from_default();
@@ -111,3 +131,67 @@ fn test_zeroizing_guard_debug_redacts() {
     assert!(debug_str.contains("REDACTED"));
     assert!(!debug_str.contains("12345"));
 }
+
+#[test]
+fn test_zeroizing_guard_as_slice() {
+    let mut arr = [0x42u8; 32];
+    let mut guard = ZeroizingGuard::from_mut(&mut arr);
+
+    assert_eq!(ZeroizingGuard::as_slice(&guard), &[0x42; 32]);
+
+    ZeroizingGuard::as_mut_slice(&mut guard)[0] = 0x01;
+    assert_eq!(ZeroizingGuard::as_slice(&guard)[0], 0x01);
+    assert_eq!(guard[0], 0x01);
+}
+
+#[test]
+fn test_zeroizing_guard_map() {
+    let mut key = [0u8; 32];
+    for (i, b) in key.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+
+    let guard = ZeroizingGuard::from_mut(&mut key);
+    let derived = ZeroizingGuard::map(guard, |full: &mut [u8; 32]| {
+        let mut half = [0u8; 16];
+        for (i, b) in half.iter_mut().enumerate() {
+            *b = full[i] ^ full[i + 16];
+        }
+        half
+    });
+
+    assert_eq!(ZeroizingGuard::as_slice(&derived), &[16u8; 16]);
+    derived.assert_zeroize_on_drop();
+}
+
+#[test]
+fn test_zeroizing_guard_map_drops_original_guard_zeroized() {
+    let guard = ZeroizingGuard::from_mut(&mut [0x42u8; 32]);
+    let sentinel = guard.clone_sentinel();
+
+    let derived = ZeroizingGuard::map(guard, |full: &mut [u8; 32]| full[0] as u64);
+
+    assert!(sentinel.is_zeroized());
+    assert_eq!(*derived, 0x42);
+}
+
+#[test]
+fn test_zeroizing_guard_map_zeroizes_source_value() {
+    let mut source = SharedBytes::default();
+    *source.0.borrow_mut() = [0x42; 32];
+    let observer = SharedBytes(Rc::clone(&source.0));
+
+    let guard = ZeroizingGuard::from_mut(&mut source);
+    let derived = ZeroizingGuard::map(guard, |shared: &mut SharedBytes| shared.0.borrow()[0]);
+
+    assert_eq!(*derived, 0x42);
+    assert_eq!(*observer.0.borrow(), [0u8; 32]);
+}
+
+#[test]
+fn test_zeroizing_guard_helpers_do_not_shadow_inner_methods() {
+    let guard = ZeroizingGuard::from_mut(&mut Some(7u8));
+
+    // `Option::map` through Deref, not `ZeroizingGuard::map`
+    assert_eq!(guard.map(|v| v + 1), Some(8));
+}
@@ -5,7 +5,7 @@
 use core::fmt::Write;
 
 use crate::traits::{AssertZeroizeOnDrop, FastZeroizable, ZeroizationProbe};
-use crate::zeroizing_mut_guard::ZeroizingMutGuard;
+use crate::zeroizing_mut_guard::{ZeroizingMutGuard, ZeroizingSliceGuard};
 
 #[test]
 fn test_zeroizing_mut_guard_assert_zeroization_probe_trait() {
@@ -78,3 +78,81 @@ fn test_zeroizing_mut_guard_on_slice() {
 
     assert!(slice.is_zeroized());
 }
+
+#[test]
+fn test_zeroizing_mut_guard_take() {
+    let mut vec = vec![1u8, 2, 3, 4, 5];
+
+    let mut taken = {
+        let mut guard = ZeroizingMutGuard::from(&mut vec);
+        let taken = ZeroizingMutGuard::take(&mut guard);
+
+        // The guarded slot now holds the default value
+        assert!(guard.is_empty());
+        assert!(guard.is_zeroized());
+
+        taken
+    };
+
+    assert!(vec.is_empty());
+    assert!(vec.is_zeroized());
+
+    // Ownership moved to the caller, untouched by the guard's drop
+    assert_eq!(taken, [1, 2, 3, 4, 5]);
+
+    taken.fast_zeroize();
+    assert!(taken.is_zeroized());
+}
+
+#[test]
+fn test_zeroizing_mut_guard_split_at() {
+    let mut buf = [1u8, 2, 3, 4, 5, 6];
+
+    {
+        let guard = ZeroizingMutGuard::from(buf.as_mut_slice());
+        let (left, mut right) =
+            ZeroizingMutGuard::split_at(guard, 4).expect("Failed to split_at(..)");
+
+        assert_eq!(&*left, &[1, 2, 3, 4]);
+        assert_eq!(&*right, &[5, 6]);
+
+        // Slice methods are still reachable through Deref
+        let (a, b) = right.split_at(1);
+        assert_eq!((a, b), (&[5u8][..], &[6u8][..]));
+
+        right[0] = 0xFF;
+
+        drop(left);
+    }
+
+    assert!(buf.is_zeroized());
+}
+
+#[test]
+fn test_zeroizing_mut_guard_split_at_out_of_range() {
+    let mut buf = [1u8, 2, 3];
+
+    let guard = ZeroizingMutGuard::from(buf.as_mut_slice());
+    assert!(ZeroizingMutGuard::split_at(guard, 4).is_none());
+
+    assert!(buf.is_zeroized());
+}
+
+#[test]
+fn test_zeroizing_slice_guard() {
+    let mut buf = vec![0u8; 8];
+
+    {
+        let mut guard = ZeroizingSliceGuard::from(&mut buf[2..6]);
+
+        guard.copy_from_slice(&[1, 2, 3, 4]);
+        guard[0] ^= 0xF0;
+        guard.reverse();
+
+        assert_eq!(&*guard, &[4, 3, 2, 0xF1]);
+        assert!(!guard.is_zeroized());
+    }
+
+    assert!(buf.is_zeroized());
+    assert_eq!(buf.len(), 8);
+}
@@ -18,10 +18,23 @@ use crate::{FastZeroizable, ZeroizeMetadata};
 ///
 /// # Design
 ///
-/// - Wraps a shared boolean flag (`Arc<AtomicBool>`) representing pristine state
-/// - Initially `true` (pristine/untouched)
-/// - `.zeroize()` sets the flag to `false` (no longer pristine)
-/// - Can be cloned to verify zeroization from tests
+/// - Wraps shared state (`Arc`) holding two flags: pristine and dropped
+/// - Pristine is initially `true` (untouched)
+/// - `.zeroize()` sets pristine to `false` (no longer pristine)
+/// - Dropping the original handle sets dropped to `true`
+/// - Can be cloned to verify zeroization (and drop) from tests; clones are
+///   observers and never set dropped themselves
+///
+/// # Memory Ordering
+///
+/// The pristine flag is read and written with `Relaxed` ordering (the
+/// zeroization write itself is volatile): it is only meant to be checked
+/// after the value is dropped on the same thread, or after a join.
+///
+/// The dropped flag is set with `Release` and read with `Acquire`, so once
+/// [`has_been_dropped()`](Self::has_been_dropped) returns `true`, every write
+/// the dropping thread made before the drop (including zeroization) is
+/// visible to the reader.
 ///
 /// # Panics
 ///
@@ -67,12 +80,34 @@ use crate::{FastZeroizable, ZeroizeMetadata};
 /// sentinel.fast_zeroize();
 /// assert!(sentinel_clone.is_zeroized());
 /// ```
-#[derive(Clone, Debug)]
-pub struct ZeroizeOnDropSentinel(Arc<AtomicBool>);
+#[derive(Debug)]
+pub struct ZeroizeOnDropSentinel {
+    state: Arc<SentinelState>,
+    owner: bool,
+}
+
+#[derive(Debug)]
+struct SentinelState {
+    pristine: AtomicBool,
+    dropped: AtomicBool,
+}
 
 impl PartialEq for ZeroizeOnDropSentinel {
     fn eq(&self, other: &Self) -> bool {
-        self.0.load(Ordering::Relaxed) == other.0.load(Ordering::Relaxed)
+        self.state.pristine.load(Ordering::Relaxed) == other.state.pristine.load(Ordering::Relaxed)
+    }
+}
+
+impl Clone for ZeroizeOnDropSentinel {
+    /// Returns an observer handle sharing this sentinel's state.
+    ///
+    /// The clone sees zeroization and drop of the original, but dropping
+    /// it does not mark the sentinel as dropped.
+    fn clone(&self) -> Self {
+        Self {
+            state: Arc::clone(&self.state),
+            owner: false,
+        }
     }
 }
 
@@ -96,7 +131,7 @@ impl ZeroizeOnDropSentinel {
     /// assert!(!sentinel.is_zeroized());
     /// ```
     pub fn reset(&mut self) {
-        self.0.store(true, Ordering::Relaxed);
+        self.state.pristine.store(true, Ordering::Relaxed);
     }
 
     /// Checks if zeroization happened (i.e., if `.zeroize()` was called).
@@ -115,13 +150,42 @@ impl ZeroizeOnDropSentinel {
     /// assert!(sentinel.is_zeroized());
     /// ```
     pub fn is_zeroized(&self) -> bool {
-        !self.0.load(Ordering::Relaxed)
+        !self.state.pristine.load(Ordering::Relaxed)
+    }
+
+    /// Checks if the original handle of this sentinel has been dropped.
+    ///
+    /// Lets tests and diagnostics observe a drop through a clone without
+    /// going through [`assert_zeroize_on_drop`](crate::assert::assert_zeroize_on_drop),
+    /// which panics on failure. Clones are observers: dropping one does not
+    /// set the flag.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use redoubt_zero_core::ZeroizeOnDropSentinel;
+    ///
+    /// let sentinel = ZeroizeOnDropSentinel::default();
+    /// let observer = sentinel.clone();
+    ///
+    /// assert!(!observer.has_been_dropped());
+    /// drop(sentinel);
+    /// assert!(observer.has_been_dropped());
+    /// ```
+    pub fn has_been_dropped(&self) -> bool {
+        self.state.dropped.load(Ordering::Acquire)
     }
 }
 
 impl Default for ZeroizeOnDropSentinel {
     fn default() -> Self {
-        Self(Arc::new(AtomicBool::new(true)))
+        Self {
+            state: Arc::new(SentinelState {
+                pristine: AtomicBool::new(true),
+                dropped: AtomicBool::new(false),
+            }),
+            owner: true,
+        }
     }
 }
 
@@ -133,7 +197,15 @@ impl FastZeroizable for ZeroizeOnDropSentinel {
     fn fast_zeroize(&mut self) {
         // SAFETY: Using volatile write to prevent compiler from optimizing away the store
         unsafe {
-            ptr::write_volatile(&mut *self.0.as_ptr(), false);
+            ptr::write_volatile(&mut *self.state.pristine.as_ptr(), false);
+        }
+    }
+}
+
+impl Drop for ZeroizeOnDropSentinel {
+    fn drop(&mut self) {
+        if self.owner {
+            self.state.dropped.store(true, Ordering::Release);
         }
     }
 }
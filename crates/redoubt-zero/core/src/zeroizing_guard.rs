@@ -122,6 +122,77 @@ where
         let mut value = T::default();
         Self::from_mut(&mut value)
     }
+
+    /// Returns the guarded value as a byte slice.
+    ///
+    /// This is an associated function (`ZeroizingGuard::as_slice(&guard)`) so it
+    /// does not shadow an `as_slice` the inner type exposes through `Deref`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use redoubt_zero_core::ZeroizingGuard;
+    ///
+    /// let guard = ZeroizingGuard::from_mut(&mut [0xAB; 32]);
+    /// assert_eq!(ZeroizingGuard::as_slice(&guard), &[0xAB; 32]);
+    /// ```
+    #[inline(always)]
+    pub fn as_slice(this: &Self) -> &[u8]
+    where
+        T: AsRef<[u8]>,
+    {
+        (*this.inner).as_ref()
+    }
+
+    /// Returns the guarded value as a mutable byte slice.
+    ///
+    /// Associated function for the same reason as [`as_slice`](Self::as_slice).
+    #[inline(always)]
+    pub fn as_mut_slice(this: &mut Self) -> &mut [u8]
+    where
+        T: AsMut<[u8]>,
+    {
+        (*this.inner).as_mut()
+    }
+
+    /// Transforms the guarded value into a new guarded value.
+    ///
+    /// Like `core::cell::Ref::map`, this is an associated function
+    /// (`ZeroizingGuard::map(guard, f)`) so it does not shadow `Option::map` and
+    /// friends on the inner value.
+    ///
+    /// `f` takes `&mut T` rather than `T` on purpose: handing the closure the
+    /// value by move would copy it out of the guard into a location nothing
+    /// zeroizes, and whatever `f` did not consume would be dropped unwiped.
+    /// Borrowing in place leaves the source under the guard, so `this`
+    /// zeroizes it when it drops at the end of the call. The result is moved
+    /// into a new guard via [`from_mut`](Self::from_mut), which zeroizes the
+    /// intermediate location.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use redoubt_zero_core::ZeroizingGuard;
+    ///
+    /// let key = ZeroizingGuard::from_mut(&mut [0xAB; 32]);
+    /// let half = ZeroizingGuard::map(key, |full: &mut [u8; 32]| {
+    ///     let mut half = [0u8; 16];
+    ///     half.copy_from_slice(&full[..16]);
+    ///     half
+    /// });
+    ///
+    /// assert_eq!(ZeroizingGuard::as_slice(&half), &[0xAB; 16]);
+    /// ```
+    #[inline(always)]
+    pub fn map<U, F>(mut this: Self, f: F) -> ZeroizingGuard<U>
+    where
+        U: FastZeroizable + ZeroizationProbe + Default,
+        F: FnOnce(&mut T) -> U,
+    {
+        let mut mapped = f(&mut this.inner);
+
+        ZeroizingGuard::from_mut(&mut mapped)
+    }
 }
 
 impl<T> Deref for ZeroizingGuard<T>
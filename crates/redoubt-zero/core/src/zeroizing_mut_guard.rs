@@ -5,6 +5,7 @@
 //! RAII guard for mutable references that auto-zeroizes on drop.
 
 use core::fmt;
+use core::mem;
 use core::ops::{Deref, DerefMut};
 use core::sync::atomic::{Ordering, compiler_fence};
 
@@ -74,6 +75,28 @@ where
     __sentinel: ZeroizeOnDropSentinel,
 }
 
+/// Guard for a borrowed byte slice that zeroizes it on drop.
+///
+/// Shorthand for `ZeroizingMutGuard<'a, [u8]>`: wipe a `&mut [u8]` on scope
+/// exit without moving the bytes into an owned guard.
+///
+/// # Example
+///
+/// ```rust
+/// use redoubt_zero_core::{ZeroizationProbe, ZeroizingSliceGuard};
+///
+/// let mut buf = [0u8; 16];
+///
+/// {
+///     let mut guard = ZeroizingSliceGuard::from(&mut buf[..]);
+///     guard.fill(0xAB);
+///     assert_eq!(guard[0], 0xAB);
+/// }
+///
+/// assert!(buf.is_zeroized());
+/// ```
+pub type ZeroizingSliceGuard<'a> = ZeroizingMutGuard<'a, [u8]>;
+
 impl<'a, T> fmt::Debug for ZeroizingMutGuard<'a, T>
 where
     T: FastZeroizable + ZeroizationProbe + ?Sized,
@@ -110,6 +133,84 @@ where
     }
 }
 
+impl<'a, T> ZeroizingMutGuard<'a, T>
+where
+    T: FastZeroizable + ZeroizationProbe + Default,
+{
+    /// Moves the guarded value out, leaving `T::default()` in the borrowed slot.
+    ///
+    /// The caller owns the returned value and is responsible for zeroizing it.
+    /// The guard still zeroizes the slot on drop, but by then the slot only
+    /// holds the default value, so the secret is never wiped twice (for a
+    /// `Vec`, the default has no allocation and drop-time zeroization is a no-op).
+    ///
+    /// This is an associated function (`ZeroizingMutGuard::take(&mut guard)`) so
+    /// it does not shadow a `take` the inner type exposes through `Deref`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use redoubt_zero_core::{FastZeroizable, ZeroizingMutGuard};
+    ///
+    /// let mut slot = vec![1u8, 2, 3];
+    ///
+    /// let mut taken = {
+    ///     let mut guard = ZeroizingMutGuard::from(&mut slot);
+    ///     ZeroizingMutGuard::take(&mut guard)
+    /// };
+    ///
+    /// assert!(slot.is_empty());
+    /// assert_eq!(taken, [1, 2, 3]);
+    ///
+    /// taken.fast_zeroize();
+    /// ```
+    #[inline(always)]
+    pub fn take(this: &mut Self) -> T {
+        mem::take(this.inner)
+    }
+}
+
+impl<'a> ZeroizingMutGuard<'a, [u8]> {
+    /// Splits the guarded slice at `mid` into two guards.
+    ///
+    /// Together the halves cover the original slice, and each zeroizes its own
+    /// portion on drop. Useful for `ciphertext || tag` buffers where both parts
+    /// must stay guarded. Returns `None` (dropping, and so zeroizing, the whole
+    /// slice) if `mid > len`.
+    ///
+    /// This is an associated function (`ZeroizingMutGuard::split_at(guard, mid)`)
+    /// so it does not shadow `<[u8]>::split_at` through `Deref`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use redoubt_zero_core::{ZeroizationProbe, ZeroizingMutGuard};
+    ///
+    /// let mut buf = [1u8, 2, 3, 4, 5];
+    ///
+    /// {
+    ///     let guard = ZeroizingMutGuard::from(&mut buf[..]);
+    ///     let (ciphertext, tag) = ZeroizingMutGuard::split_at(guard, 3).unwrap();
+    ///     assert_eq!(&*ciphertext, &[1, 2, 3]);
+    ///     assert_eq!(&*tag, &[4, 5]);
+    /// }
+    ///
+    /// assert!(buf.is_zeroized());
+    /// ```
+    pub fn split_at(mut this: Self, mid: usize) -> Option<(Self, Self)> {
+        if mid > this.inner.len() {
+            return None;
+        }
+
+        // Leave an empty slice behind: `this` still drops (and marks its
+        // sentinel) but has nothing left to wipe.
+        let inner = mem::take(&mut this.inner);
+        let (left, right) = redoubt_util::try_split_at_mut(inner, mid)?;
+
+        Some((Self::from(left), Self::from(right)))
+    }
+}
+
 impl<'a, T> Deref for ZeroizingMutGuard<'a, T>
 where
     T: FastZeroizable + ZeroizationProbe + ?Sized,
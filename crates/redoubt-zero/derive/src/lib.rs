@@ -26,23 +26,26 @@ mod tests;
 
 use proc_macro::TokenStream;
 use proc_macro_crate::{FoundCrate, crate_name};
-use proc_macro2::{Span, TokenStream as TokenStream2};
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
 use quote::{format_ident, quote};
 use syn::{
-    Attribute, Data, DeriveInput, Fields, Ident, Index, LitStr, Meta, Type, parse_macro_input,
+    Attribute, Data, DataEnum, DeriveInput, Fields, Ident, Index, LitInt, LitStr, Meta, Path, Type,
+    parse_macro_input,
 };
 
-/// Derives `FastZeroizable`, `ZeroizeMetadata`, `ZeroizationProbe`, and optionally `AssertZeroizeOnDrop` for a struct.
+/// Derives `FastZeroizable`, `ZeroizeMetadata`, `ZeroizationProbe`, and optionally `AssertZeroizeOnDrop` for a struct or enum.
 ///
-/// This macro automatically generates trait implementations for structs.
+/// This macro automatically generates trait implementations for structs and enums.
 ///
 /// # Requirements
 ///
-/// - All fields must implement `FastZeroizable` (except fields with `#[fast_zeroize(skip)]`)
+/// - All fields must implement `FastZeroizable` (except fields with `#[fast_zeroize(skip)]`
+///   or `#[fast_zeroize(with = "..")]`)
 ///
 /// # Optional Sentinel Field
 ///
-/// - Named structs can include a field named `__sentinel: ZeroizeOnDropSentinel`
+/// - Named structs can include a field named `__sentinel: ZeroizeOnDropSentinel`, or
+///   pick another name with `#[fast_zeroize(sentinel = "name")]`
 /// - Tuple structs can include a field of type `ZeroizeOnDropSentinel`
 /// - If present, `AssertZeroizeOnDrop` will be implemented for testing drop behavior
 ///
@@ -50,6 +53,10 @@ use syn::{
 ///
 /// - `#[fast_zeroize(drop)]`: Also generates a `Drop` implementation that calls `fast_zeroize()`
 /// - `#[fast_zeroize(skip)]`: Skip a field from zeroization (e.g., immutable references)
+/// - `#[fast_zeroize(with = "path")]`: Zeroize a field that does not implement
+///   `FastZeroizable` by calling `path(&mut field)`. The field counts as zeroized in
+///   `is_zeroized()` unless a paired probe is given with `probe = "path"`, which is
+///   called as `path(&field) -> bool`. Structs only.
 ///
 /// # Generated Implementations
 ///
@@ -61,6 +68,14 @@ use syn::{
 /// If `ZeroizeOnDropSentinel` field is present:
 /// - `AssertZeroizeOnDrop`: Provides test helpers for verifying zeroization on drop
 ///
+/// # Enums
+///
+/// `fast_zeroize` zeroizes the fields of the active variant and `is_zeroized`
+/// checks them; the variant itself is not changed. Sentinel fields follow the
+/// same naming/type rules as structs, but `AssertZeroizeOnDrop` is only
+/// generated when **every** variant carries a sentinel (so unit variants
+/// disable it).
+///
 /// With `#[fast_zeroize(drop)]`:
 /// - `Drop`: Calls `fast_zeroize()` on drop
 ///
@@ -188,16 +203,107 @@ fn has_fast_zeroize_skip(attrs: &[Attribute]) -> bool {
     })
 }
 
+/// Custom zeroization of a field declared with `#[fast_zeroize(with = "..", probe = "..")]`.
+struct ZeroizeWith {
+    with: Path,
+    probe: Option<Path>,
+}
+
+/// Parses `#[fast_zeroize(with = "path")]` and its optional `probe = "path"`.
+///
+/// Only `fast_zeroize` attributes mentioning `with` are parsed, so `skip` and
+/// unknown keys keep being ignored.
+fn fast_zeroize_with(attrs: &[Attribute]) -> Result<Option<ZeroizeWith>, TokenStream2> {
+    let mut with: Option<Path> = None;
+    let mut probe: Option<Path> = None;
+
+    for attr in attrs {
+        let Meta::List(meta_list) = &attr.meta else {
+            continue;
+        };
+
+        if !meta_list.path.is_ident("fast_zeroize")
+            || !meta_list.tokens.to_string().contains("with")
+        {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            let target = if meta.path.is_ident("with") {
+                &mut with
+            } else if meta.path.is_ident("probe") {
+                &mut probe
+            } else {
+                return Err(meta.error("expected `with = \"path\"` or `probe = \"path\"`"));
+            };
+
+            let lit: LitStr = meta.value()?.parse()?;
+            *target = Some(lit.parse()?);
+
+            Ok(())
+        })
+        .map_err(|e| e.to_compile_error())?;
+    }
+
+    match (with, probe) {
+        (Some(with), probe) => Ok(Some(ZeroizeWith { with, probe })),
+        (None, Some(_)) => Err(syn::Error::new(
+            Span::call_site(),
+            "`probe = \"..\"` requires `with = \"..\"` on the same field.",
+        )
+        .to_compile_error()),
+        (None, None) => Ok(None),
+    }
+}
+
 /// Checks if the struct has the `#[fast_zeroize(drop)]` attribute.
+///
+/// Matches the `drop` identifier only, so `sentinel = "drop_guard"` does not count.
 fn has_fast_zeroize_drop(attrs: &[Attribute]) -> bool {
     attrs.iter().any(|attr| match &attr.meta {
         Meta::List(meta_list) => {
-            meta_list.path.is_ident("fast_zeroize") && meta_list.tokens.to_string().contains("drop")
+            meta_list.path.is_ident("fast_zeroize")
+                && meta_list
+                    .tokens
+                    .clone()
+                    .into_iter()
+                    .any(|tt| matches!(tt, TokenTree::Ident(ident) if ident == "drop"))
         }
         _ => false,
     })
 }
 
+/// Parses the struct-level `#[fast_zeroize(sentinel = "name")]` attribute.
+///
+/// Only `fast_zeroize` attributes mentioning `sentinel` are parsed, so `drop`
+/// keeps working alongside it.
+fn fast_zeroize_sentinel(attrs: &[Attribute]) -> Result<Option<LitStr>, TokenStream2> {
+    let mut sentinel: Option<LitStr> = None;
+
+    for attr in attrs {
+        let Meta::List(meta_list) = &attr.meta else {
+            continue;
+        };
+
+        if !meta_list.path.is_ident("fast_zeroize")
+            || !meta_list.tokens.to_string().contains("sentinel")
+        {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("sentinel") {
+                sentinel = Some(meta.value()?.parse()?);
+            }
+
+            Ok(())
+        })
+        .map_err(|e| e.to_compile_error())?;
+    }
+
+    Ok(sentinel)
+}
+
 /// Sentinel field information.
 struct SentinelState {
     index: usize,
@@ -219,23 +325,49 @@ fn expand(input: DeriveInput) -> Result<TokenStream2, TokenStream2> {
             Fields::Unnamed(unnamed) => unnamed.unnamed.iter().enumerate().collect(),
             Fields::Unit => vec![],
         },
-        _ => {
+        Data::Enum(data) => return expand_enum(&input, data, &root),
+        Data::Union(_) => {
             return Err(syn::Error::new_spanned(
                 &input.ident,
-                "RedoubtZero can only be derived for structs (named or tuple).",
+                "RedoubtZero can only be derived for structs (named or tuple) and enums.",
             )
             .to_compile_error());
         }
     };
 
-    // 3) Identify the __sentinel field (optional)
-    let sentinel_ident = format_ident!("__sentinel");
+    // 3) Identify the sentinel field (optional): `__sentinel` unless renamed
+    let custom_sentinel = fast_zeroize_sentinel(&input.attrs)?;
+    let sentinel_ident = match &custom_sentinel {
+        Some(lit) => {
+            let is_named = matches!(&input.data, Data::Struct(data) if matches!(data.fields, Fields::Named(_)));
+            if !is_named {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "`#[fast_zeroize(sentinel = \"..\")]` is only supported on named structs.",
+                )
+                .to_compile_error());
+            }
+            lit.parse::<Ident>().map_err(|e| e.to_compile_error())?
+        }
+        None => format_ident!("__sentinel"),
+    };
     let mut maybe_sentinel_state: Option<SentinelState> = None;
 
     for (i, f) in &all_fields {
         let is_sentinel = if let Some(ident) = &f.ident {
-            // Named field: check if name is __sentinel
+            // Named field: check if name is the sentinel's
             if *ident == sentinel_ident {
+                if custom_sentinel.is_some() && !is_zeroize_on_drop_sentinel_type(&f.ty) {
+                    return Err(syn::Error::new_spanned(
+                        &f.ty,
+                        format!(
+                            "sentinel field `{}` must have type `ZeroizeOnDropSentinel`.",
+                            ident
+                        ),
+                    )
+                    .to_compile_error());
+                }
+
                 maybe_sentinel_state = Some(SentinelState {
                     index: *i,
                     access: quote! { self.#sentinel_ident },
@@ -263,6 +395,17 @@ fn expand(input: DeriveInput) -> Result<TokenStream2, TokenStream2> {
         }
     }
 
+    if let (Some(lit), None) = (&custom_sentinel, &maybe_sentinel_state) {
+        return Err(syn::Error::new_spanned(
+            lit,
+            format!(
+                "sentinel field `{}` not found in `{}`.",
+                sentinel_ident, struct_name
+            ),
+        )
+        .to_compile_error());
+    }
+
     // 4) Validate and filter fields
     // - Check for immutable references without #[fast_zeroize(skip)]
     // - Filter out fields with #[fast_zeroize(skip)]
@@ -293,6 +436,43 @@ fn expand(input: DeriveInput) -> Result<TokenStream2, TokenStream2> {
         }
     }
 
+    // Fields with `#[fast_zeroize(with = "..")]` are zeroized (and optionally probed)
+    // through their custom functions instead of the dyn collections below
+    let mut with_indices = Vec::new();
+    let mut with_calls = Vec::new();
+    let mut with_probes = Vec::new();
+
+    for (i, f) in &all_fields {
+        if Some(*i) == sentinel_idx || has_fast_zeroize_skip(&f.attrs) {
+            continue;
+        }
+
+        let Some(ZeroizeWith { with, probe }) = fast_zeroize_with(&f.attrs)? else {
+            continue;
+        };
+
+        let access = if let Some(ident) = &f.ident {
+            quote! { self.#ident }
+        } else {
+            let idx = Index::from(*i);
+            quote! { self.#idx }
+        };
+        let (mut_access, immut_access) = if is_mut_reference_type(&f.ty) {
+            (quote! { &mut *#access }, quote! { &*#access })
+        } else {
+            (quote! { &mut #access }, quote! { &#access })
+        };
+
+        with_indices.push(*i);
+        with_calls.push(quote! {
+            #with(#mut_access);
+            ::core::sync::atomic::compiler_fence(::core::sync::atomic::Ordering::SeqCst);
+        });
+        if let Some(probe) = probe {
+            with_probes.push(quote! { && #probe(#immut_access) });
+        }
+    }
+
     // 5) Generate two sets of field references:
     //    - immut_refs_without_sentinel: for ZeroizationProbe (excludes sentinel and skipped)
     //    - mut_refs_with_sentinel: for FastZeroizable (includes sentinel, excludes skipped)
@@ -301,7 +481,11 @@ fn expand(input: DeriveInput) -> Result<TokenStream2, TokenStream2> {
     // Special handling: if field is already &mut T, pass self.field directly (not &self.field)
     let (immut_refs_without_sentinel, _): (Vec<TokenStream2>, Vec<TokenStream2>) = all_fields
         .iter()
-        .filter(|(i, f)| Some(*i) != sentinel_idx && !has_fast_zeroize_skip(&f.attrs))
+        .filter(|(i, f)| {
+            Some(*i) != sentinel_idx
+                && !has_fast_zeroize_skip(&f.attrs)
+                && !with_indices.contains(i)
+        })
         .map(|(i, f)| {
             let is_mut_ref = is_mut_reference_type(&f.ty);
 
@@ -328,7 +512,7 @@ fn expand(input: DeriveInput) -> Result<TokenStream2, TokenStream2> {
     // Special handling: if field is already &mut T, pass self.field directly (not &mut self.field)
     let (_, mut_refs_with_sentinel): (Vec<TokenStream2>, Vec<TokenStream2>) = all_fields
         .iter()
-        .filter(|(_, f)| !has_fast_zeroize_skip(&f.attrs))
+        .filter(|(i, f)| !has_fast_zeroize_skip(&f.attrs) && !with_indices.contains(i))
         .map(|(i, f)| {
             let is_mut_ref = is_mut_reference_type(&f.ty);
 
@@ -382,6 +566,7 @@ fn expand(input: DeriveInput) -> Result<TokenStream2, TokenStream2> {
 
         impl #impl_generics #root::FastZeroizable for #struct_name #ty_generics #where_clause {
             fn fast_zeroize(&mut self) {
+                #( #with_calls )*
                 let fields: [&mut dyn #root::FastZeroizable; #len_with_sentinel_lit] = [
                     #( #root::collections::to_fast_zeroizable_dyn_mut(#mut_refs_with_sentinel) ),*
                 ];
@@ -394,7 +579,7 @@ fn expand(input: DeriveInput) -> Result<TokenStream2, TokenStream2> {
                 let fields: [&dyn #root::ZeroizationProbe; #len_without_sentinel_lit] = [
                     #( #root::collections::to_zeroization_probe_dyn_ref(#immut_refs_without_sentinel) ),*
                 ];
-                #root::collections::collection_zeroed(&mut fields.into_iter())
+                #root::collections::collection_zeroed(&mut fields.into_iter()) #( #with_probes )*
             }
         }
 
@@ -426,3 +611,232 @@ fn expand(input: DeriveInput) -> Result<TokenStream2, TokenStream2> {
 
     Ok(full_output)
 }
+
+/// Per-variant pieces of an enum expansion.
+struct VariantParts {
+    /// Pattern binding every non-skipped field, e.g. `Self::A { x, __sentinel, .. }`.
+    pattern: TokenStream2,
+    /// `&mut` expressions for `FastZeroizable` (sentinel included).
+    mut_refs: Vec<TokenStream2>,
+    /// `&` expressions for `ZeroizationProbe` (sentinel excluded).
+    immut_refs: Vec<TokenStream2>,
+    /// Binding of the sentinel field and a pattern binding only it, if the variant has one.
+    sentinel: Option<(Ident, TokenStream2)>,
+}
+
+fn variant_parts(variant: &syn::Variant) -> Result<VariantParts, TokenStream2> {
+    let name = &variant.ident;
+    let sentinel_ident = format_ident!("__sentinel");
+
+    let fields: Vec<(usize, &syn::Field)> = match &variant.fields {
+        Fields::Named(named) => named.named.iter().enumerate().collect(),
+        Fields::Unnamed(unnamed) => unnamed.unnamed.iter().enumerate().collect(),
+        Fields::Unit => vec![],
+    };
+
+    let mut sentinel = None;
+    let mut slots = Vec::new();
+    let mut mut_refs = Vec::new();
+    let mut immut_refs = Vec::new();
+
+    for (i, f) in &fields {
+        let binding = f
+            .ident
+            .clone()
+            .unwrap_or_else(|| format_ident!("__field{}", i));
+        let is_sentinel = sentinel.is_none()
+            && match &f.ident {
+                Some(ident) => *ident == sentinel_ident,
+                None => is_zeroize_on_drop_sentinel_type(&f.ty),
+            };
+
+        if !is_sentinel && is_immut_reference_type(&f.ty) && !has_fast_zeroize_skip(&f.attrs) {
+            return Err(syn::Error::new_spanned(
+                &f.ty,
+                format!(
+                    "field `{}` of variant `{}` has type `&T` (immutable reference) which cannot be zeroized. \
+                     Add `#[fast_zeroize(skip)]` to exclude it from zeroization.",
+                    binding, name
+                ),
+            )
+            .to_compile_error());
+        }
+
+        if !has_fast_zeroize_skip(&f.attrs) && fast_zeroize_with(&f.attrs)?.is_some() {
+            return Err(syn::Error::new_spanned(
+                &f.ty,
+                "`#[fast_zeroize(with = \"..\")]` is only supported on struct fields.",
+            )
+            .to_compile_error());
+        }
+
+        if has_fast_zeroize_skip(&f.attrs) {
+            if f.ident.is_none() {
+                slots.push(quote! { _ });
+            }
+            continue;
+        }
+
+        slots.push(quote! { #binding });
+
+        // Bindings are `&mut T` / `&T`; for `&mut X` fields reborrow the inner reference
+        if is_mut_reference_type(&f.ty) {
+            mut_refs.push(quote! { &mut **#binding });
+        } else {
+            mut_refs.push(quote! { #binding });
+        }
+
+        if is_sentinel {
+            let sentinel_pattern = if f.ident.is_some() {
+                quote! { Self::#name { #binding, .. } }
+            } else {
+                let slots = fields.iter().map(|(j, _)| {
+                    if j == i {
+                        quote! { #binding }
+                    } else {
+                        quote! { _ }
+                    }
+                });
+                quote! { Self::#name( #( #slots ),* ) }
+            };
+            sentinel = Some((binding, sentinel_pattern));
+        } else if is_mut_reference_type(&f.ty) {
+            immut_refs.push(quote! { &**#binding });
+        } else {
+            immut_refs.push(quote! { #binding });
+        }
+    }
+
+    let pattern = match &variant.fields {
+        Fields::Named(_) => quote! { Self::#name { #( #slots, )* .. } },
+        Fields::Unnamed(_) => quote! { Self::#name( #( #slots ),* ) },
+        Fields::Unit => quote! { Self::#name },
+    };
+
+    Ok(VariantParts {
+        pattern,
+        mut_refs,
+        immut_refs,
+        sentinel,
+    })
+}
+
+/// Expands `RedoubtZero` for an enum, dispatching on the active variant.
+fn expand_enum(
+    input: &DeriveInput,
+    data: &DataEnum,
+    root: &TokenStream2,
+) -> Result<TokenStream2, TokenStream2> {
+    let enum_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    if data.variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "RedoubtZero cannot be derived for enums without variants.",
+        )
+        .to_compile_error());
+    }
+
+    if let Some(lit) = fast_zeroize_sentinel(&input.attrs)? {
+        return Err(syn::Error::new_spanned(
+            lit,
+            "`#[fast_zeroize(sentinel = \"..\")]` is only supported on named structs.",
+        )
+        .to_compile_error());
+    }
+
+    let parts = data
+        .variants
+        .iter()
+        .map(variant_parts)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let patterns: Vec<&TokenStream2> = parts.iter().map(|p| &p.pattern).collect();
+    let mut_refs: Vec<&Vec<TokenStream2>> = parts.iter().map(|p| &p.mut_refs).collect();
+    let immut_refs: Vec<&Vec<TokenStream2>> = parts.iter().map(|p| &p.immut_refs).collect();
+    let mut_lens: Vec<LitInt> = parts
+        .iter()
+        .map(|p| LitInt::new(&p.mut_refs.len().to_string(), Span::call_site()))
+        .collect();
+    let immut_lens: Vec<LitInt> = parts
+        .iter()
+        .map(|p| LitInt::new(&p.immut_refs.len().to_string(), Span::call_site()))
+        .collect();
+
+    let drop_impl = if has_fast_zeroize_drop(&input.attrs) {
+        quote! {
+            impl #impl_generics Drop for #enum_name #ty_generics #where_clause {
+                fn drop(&mut self) {
+                    #root::FastZeroizable::fast_zeroize(self);
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // AssertZeroizeOnDrop needs a sentinel whatever the active variant is
+    let sentinels: Option<Vec<&(Ident, TokenStream2)>> =
+        parts.iter().map(|p| p.sentinel.as_ref()).collect();
+    let assert_impl = if let Some(sentinels) = sentinels {
+        let (sentinels, sentinel_patterns): (Vec<&Ident>, Vec<&TokenStream2>) =
+            sentinels.into_iter().map(|(s, p)| (s, p)).unzip();
+        quote! {
+            impl #impl_generics #root::AssertZeroizeOnDrop for #enum_name #ty_generics #where_clause {
+                fn clone_sentinel(&self) -> #root::ZeroizeOnDropSentinel {
+                    match self {
+                        #( #sentinel_patterns => #sentinels.clone(), )*
+                    }
+                }
+
+                fn assert_zeroize_on_drop(self) {
+                    #root::assert::assert_zeroize_on_drop(self);
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        impl #impl_generics #root::ZeroizeMetadata for #enum_name #ty_generics #where_clause {
+            const CAN_BE_BULK_ZEROIZED: bool = false;
+        }
+
+        impl #impl_generics #root::FastZeroizable for #enum_name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn fast_zeroize(&mut self) {
+                match self {
+                    #(
+                        #patterns => {
+                            let fields: [&mut dyn #root::FastZeroizable; #mut_lens] = [
+                                #( #root::collections::to_fast_zeroizable_dyn_mut(#mut_refs) ),*
+                            ];
+                            #root::collections::zeroize_collection(&mut fields.into_iter())
+                        }
+                    )*
+                }
+            }
+        }
+
+        impl #impl_generics #root::ZeroizationProbe for #enum_name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn is_zeroized(&self) -> bool {
+                match self {
+                    #(
+                        #patterns => {
+                            let fields: [&dyn #root::ZeroizationProbe; #immut_lens] = [
+                                #( #root::collections::to_zeroization_probe_dyn_ref(#immut_refs) ),*
+                            ];
+                            #root::collections::collection_zeroed(&mut fields.into_iter())
+                        }
+                    )*
+                }
+            }
+        }
+
+        #drop_impl
+        #assert_impl
+    })
+}
@@ -170,6 +170,150 @@ fn snapshot_named_struct_with_memzer_skip_on_immut_ref() {
     insta::assert_snapshot!(pretty(token_stream));
 }
 
+// === === === === === === === === === ===
+// Named structs - #[fast_zeroize(sentinel = "..")]
+// === === === === === === === === === ===
+
+#[test]
+fn snapshot_named_struct_with_custom_sentinel_name() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero)]
+        #[fast_zeroize(drop, sentinel = "drop_sentinel")]
+        struct Rho {
+            pub alpha: Vec<u8>,
+            drop_sentinel: ZeroizeOnDropSentinel,
+        }
+    };
+
+    let token_stream = expand(derive_input).expect("expand failed");
+    insta::assert_snapshot!(pretty(token_stream));
+}
+
+#[test]
+fn test_custom_sentinel_name_missing_field_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero)]
+        #[fast_zeroize(sentinel = "guard")]
+        struct Rho {
+            pub alpha: Vec<u8>,
+            __sentinel: ZeroizeOnDropSentinel,
+        }
+    };
+
+    assert!(expand(derive_input).is_err());
+}
+
+#[test]
+fn test_custom_sentinel_name_wrong_type_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero)]
+        #[fast_zeroize(sentinel = "guard")]
+        struct Rho {
+            pub alpha: Vec<u8>,
+            guard: u64,
+        }
+    };
+
+    assert!(expand(derive_input).is_err());
+}
+
+#[test]
+fn test_custom_sentinel_name_on_tuple_struct_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero)]
+        #[fast_zeroize(sentinel = "guard")]
+        struct Rho(Vec<u8>, ZeroizeOnDropSentinel);
+    };
+
+    assert!(expand(derive_input).is_err());
+}
+
+#[test]
+fn test_custom_sentinel_name_on_enum_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero)]
+        #[fast_zeroize(sentinel = "guard")]
+        enum Rho {
+            A { guard: ZeroizeOnDropSentinel },
+        }
+    };
+
+    assert!(expand(derive_input).is_err());
+}
+
+// === === === === === === === === === ===
+// Named structs - #[fast_zeroize(with = "..")]
+// === === === === === === === === === ===
+
+#[test]
+fn snapshot_named_struct_with_fast_zeroize_with() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero)]
+        struct Omicron {
+            pub alpha: Vec<u8>,
+            #[fast_zeroize(with = "ffi::wipe_handle")]
+            pub handle: ffi::KeyHandle,
+            __sentinel: ZeroizeOnDropSentinel,
+        }
+    };
+
+    let token_stream = expand(derive_input).expect("expand failed");
+    insta::assert_snapshot!(pretty(token_stream));
+}
+
+#[test]
+fn snapshot_tuple_struct_with_fast_zeroize_with_and_probe() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero)]
+        struct Pi<'a>(
+            Vec<u8>,
+            #[fast_zeroize(with = "wipe_handle", probe = "is_handle_wiped")] &'a mut KeyHandle,
+            ZeroizeOnDropSentinel,
+        );
+    };
+
+    let token_stream = expand(derive_input).expect("expand failed");
+    insta::assert_snapshot!(pretty(token_stream));
+}
+
+#[test]
+fn test_fast_zeroize_with_non_string_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero)]
+        struct Omicron {
+            #[fast_zeroize(with = wipe_handle)]
+            pub handle: KeyHandle,
+        }
+    };
+
+    assert!(expand(derive_input).is_err());
+}
+
+#[test]
+fn test_fast_zeroize_with_unknown_key_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero)]
+        struct Omicron {
+            #[fast_zeroize(with = "wipe_handle", check = "is_handle_wiped")]
+            pub handle: KeyHandle,
+        }
+    };
+
+    assert!(expand(derive_input).is_err());
+}
+
+#[test]
+fn test_fast_zeroize_with_on_enum_field_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero)]
+        enum Omicron {
+            Handle(#[fast_zeroize(with = "wipe_handle")] KeyHandle),
+        }
+    };
+
+    assert!(expand(derive_input).is_err());
+}
+
 // === === === === === === === === === ===
 // Named structs - #[fast_zeroize(drop)]
 // === === === === === === === === === ===
@@ -402,15 +546,68 @@ fn snapshot_unit_struct_no_assert_zeroize_on_drop_impl() {
 }
 
 #[test]
-fn snapshot_enum_fails() {
+fn snapshot_enum_ok() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero)]
+        #[fast_zeroize(drop)]
+        enum Lambda<'a> {
+            Alpha { key: Vec<u8>, #[fast_zeroize(skip)] label: &'a str, __sentinel: ZeroizeOnDropSentinel },
+            Beta([u8; 32], &'a mut [u8], ZeroizeOnDropSentinel),
+        }
+    };
+
+    let token_stream = expand(derive_input).expect("expand failed");
+    insta::assert_snapshot!(pretty(token_stream));
+}
+
+#[test]
+fn snapshot_enum_without_sentinel_in_every_variant_no_assert_zeroize_on_drop_impl() {
     let derive_input = parse_quote! {
         #[derive(RedoubtZero)]
         enum Lambda {
-            Alpha,
+            Alpha { key: Vec<u8>, __sentinel: ZeroizeOnDropSentinel },
             Beta,
         }
     };
 
+    let token_stream = expand(derive_input).expect("expand failed");
+    insta::assert_snapshot!(pretty(token_stream));
+}
+
+#[test]
+fn test_enum_without_variants_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero)]
+        enum Lambda {}
+    };
+
+    let result = expand(derive_input);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_enum_immut_ref_without_skip_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero)]
+        enum Lambda<'a> {
+            Alpha(&'a [u8]),
+        }
+    };
+
+    let result = expand(derive_input);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_union_fails() {
+    let derive_input = parse_quote! {
+        #[derive(RedoubtZero)]
+        union Lambda {
+            alpha: u32,
+            beta: f32,
+        }
+    };
+
     let result = expand(derive_input);
     assert!(result.is_err());
 }
@@ -0,0 +1,98 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use redoubt_zero_core::{
+    AssertZeroizeOnDrop, FastZeroizable, ZeroizationProbe, ZeroizeOnDropSentinel,
+};
+use redoubt_zero_derive::RedoubtZero;
+
+#[derive(RedoubtZero)]
+#[fast_zeroize(drop)]
+enum SessionState {
+    Handshake {
+        ephemeral: [u8; 32],
+        transcript: Vec<u8>,
+        __sentinel: ZeroizeOnDropSentinel,
+    },
+    Established(Vec<u8>, u64, ZeroizeOnDropSentinel),
+}
+
+#[derive(RedoubtZero)]
+enum MaybeKey {
+    Key(Vec<u8>),
+    Missing,
+}
+
+fn handshake() -> SessionState {
+    SessionState::Handshake {
+        ephemeral: [0xAB; 32],
+        transcript: vec![1, 2, 3, 4],
+        __sentinel: ZeroizeOnDropSentinel::default(),
+    }
+}
+
+fn established() -> SessionState {
+    SessionState::Established(vec![5, 6, 7], 42, ZeroizeOnDropSentinel::default())
+}
+
+#[test]
+fn test_enum_fast_zeroize_each_variant() {
+    for mut state in [handshake(), established()] {
+        // Assert (not) zeroization!
+        assert!(!state.is_zeroized());
+
+        state.fast_zeroize();
+
+        // Assert zeroization!
+        assert!(state.is_zeroized());
+    }
+}
+
+#[test]
+fn test_enum_fast_zeroize_keeps_variant() {
+    let mut state = established();
+
+    state.fast_zeroize();
+
+    match &state {
+        SessionState::Established(secret, counter, _) => {
+            assert!(secret.is_zeroized());
+            assert_eq!(*counter, 0);
+        }
+        SessionState::Handshake { .. } => panic!("variant changed"),
+    }
+}
+
+#[test]
+fn test_enum_is_zeroized_checks_active_variant_only() {
+    let mut state = handshake();
+
+    if let SessionState::Handshake { ephemeral, .. } = &mut state {
+        ephemeral.fast_zeroize();
+    }
+    assert!(!state.is_zeroized());
+
+    if let SessionState::Handshake { transcript, .. } = &mut state {
+        transcript.fast_zeroize();
+    }
+    assert!(state.is_zeroized());
+}
+
+#[test]
+fn test_enum_assert_zeroize_on_drop() {
+    handshake().assert_zeroize_on_drop();
+    established().assert_zeroize_on_drop();
+}
+
+#[test]
+fn test_enum_without_sentinel_in_every_variant() {
+    let mut key = MaybeKey::Key(vec![9; 16]);
+    assert!(!key.is_zeroized());
+
+    key.fast_zeroize();
+    assert!(key.is_zeroized());
+
+    // Unit variants have nothing to zeroize
+    assert!(MaybeKey::Missing.is_zeroized());
+}
@@ -0,0 +1,44 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use redoubt_zero_core::{
+    AssertZeroizeOnDrop, FastZeroizable, ZeroizationProbe, ZeroizeOnDropSentinel,
+};
+use redoubt_zero_derive::RedoubtZero;
+
+#[derive(RedoubtZero)]
+#[fast_zeroize(drop, sentinel = "zeroize_sentinel")]
+struct Credentials {
+    password: Vec<u8>,
+    zeroize_sentinel: ZeroizeOnDropSentinel,
+}
+
+fn credentials() -> Credentials {
+    Credentials {
+        password: vec![1, 2, 3, 4],
+        zeroize_sentinel: ZeroizeOnDropSentinel::default(),
+    }
+}
+
+#[test]
+fn test_custom_sentinel_is_excluded_from_probe() {
+    let mut creds = credentials();
+
+    assert!(!creds.is_zeroized());
+
+    creds.fast_zeroize();
+
+    assert!(creds.is_zeroized());
+    assert!(creds.zeroize_sentinel.is_zeroized());
+}
+
+#[test]
+fn test_custom_sentinel_assert_zeroize_on_drop() {
+    let creds = credentials();
+    let sentinel = creds.clone_sentinel();
+
+    assert!(!sentinel.is_zeroized());
+
+    creds.assert_zeroize_on_drop();
+}
@@ -0,0 +1,88 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+use redoubt_zero_core::{
+    AssertZeroizeOnDrop, FastZeroizable, ZeroizationProbe, ZeroizeOnDropSentinel,
+};
+use redoubt_zero_derive::RedoubtZero;
+
+/// Stand-in for a third-party key handle that does not implement `FastZeroizable`.
+struct KeyHandle {
+    bytes: [u8; 16],
+}
+
+fn wipe_key_handle(handle: &mut KeyHandle) {
+    handle.bytes.fast_zeroize();
+}
+
+fn is_key_handle_wiped(handle: &KeyHandle) -> bool {
+    handle.bytes.is_zeroized()
+}
+
+#[derive(RedoubtZero)]
+#[fast_zeroize(drop)]
+struct Session {
+    transcript: Vec<u8>,
+    #[fast_zeroize(with = "wipe_key_handle")]
+    handle: KeyHandle,
+    __sentinel: ZeroizeOnDropSentinel,
+}
+
+#[derive(RedoubtZero)]
+struct ProbedSession {
+    transcript: Vec<u8>,
+    #[fast_zeroize(with = "wipe_key_handle", probe = "is_key_handle_wiped")]
+    handle: KeyHandle,
+}
+
+#[test]
+fn test_fast_zeroize_with_calls_custom_function() {
+    let mut session = Session {
+        transcript: vec![1, 2, 3],
+        handle: KeyHandle { bytes: [0xAB; 16] },
+        __sentinel: ZeroizeOnDropSentinel::default(),
+    };
+
+    session.fast_zeroize();
+
+    assert!(session.handle.bytes.iter().all(|&b| b == 0));
+    assert!(session.is_zeroized());
+}
+
+#[test]
+fn test_fast_zeroize_with_field_is_zeroized_without_probe() {
+    let session = Session {
+        transcript: vec![],
+        handle: KeyHandle { bytes: [0xAB; 16] },
+        __sentinel: ZeroizeOnDropSentinel::default(),
+    };
+
+    // Without a probe the custom field always counts as zeroized
+    assert!(session.is_zeroized());
+}
+
+#[test]
+fn test_fast_zeroize_with_probe() {
+    let mut session = ProbedSession {
+        transcript: vec![],
+        handle: KeyHandle { bytes: [0xAB; 16] },
+    };
+
+    assert!(!session.is_zeroized());
+
+    session.fast_zeroize();
+
+    assert!(session.is_zeroized());
+}
+
+#[test]
+fn test_fast_zeroize_with_assert_zeroize_on_drop() {
+    let session = Session {
+        transcript: vec![1, 2, 3],
+        handle: KeyHandle { bytes: [0xAB; 16] },
+        __sentinel: ZeroizeOnDropSentinel::default(),
+    };
+
+    session.assert_zeroize_on_drop();
+}
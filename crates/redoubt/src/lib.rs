@@ -167,6 +167,27 @@
 //! # Ok::<(), redoubt::vault::CipherBoxError>(())
 //! ```
 //!
+//! ## `map_<field>`
+//!
+//! Derive an owned value from a field. The closure runs once and its return value is
+//! handed back as-is; the decrypted field is zeroized before the method returns:
+//!
+//! ```rust
+//! # use redoubt::alloc::RedoubtArray;
+//! # use redoubt::codec::RedoubtCodec;
+//! # use redoubt::vault::cipherbox;
+//! # use redoubt::zero::RedoubtZero;
+//! # #[cipherbox(Wallet)]
+//! # #[derive(Default, RedoubtCodec, RedoubtZero)]
+//! # struct WalletData { seed: RedoubtArray<u8, 32> }
+//! # let mut wallet = Wallet::new();
+//! let fingerprint = wallet.map_seed(|seed| seed.as_slice()[0])?;
+//! # Ok::<(), redoubt::vault::CipherBoxError>(())
+//! ```
+//!
+//! Unlike `open_<field>`, the result is NOT wrapped in a `ZeroizingGuard`: only return
+//! values that are safe to keep in plaintext.
+//!
 //! ## Returning values
 //!
 //! Closures can return values. The return value is wrapped in a `ZeroizingGuard` that wipes memory on drop:
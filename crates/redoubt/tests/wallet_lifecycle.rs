@@ -0,0 +1,160 @@
+// Copyright (c) 2025-2026 Federico Hoerth <memparanoid@gmail.com>
+// SPDX-License-Identifier: GPL-3.0-only
+// See LICENSE in the repository root for full license text.
+
+//! End-to-end wallet lifecycle: every buffer that carried plaintext must be
+//! zeroized once it leaves the caller's hands.
+
+use redoubt::alloc::{RedoubtArray, RedoubtString, RedoubtVec};
+use redoubt::codec::RedoubtCodec;
+use redoubt::secret::RedoubtSecret;
+use redoubt::util::is_vec_fully_zeroized;
+use redoubt::vault::{CipherBoxError, cipherbox};
+use redoubt::zero::{AssertZeroizeOnDrop, RedoubtZero, ZeroizationProbe};
+
+const MNEMONIC: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+#[cipherbox(WalletBox)]
+#[derive(Default, RedoubtCodec, RedoubtZero)]
+#[fast_zeroize(drop)]
+struct Wallet {
+    seed: RedoubtArray<u8, 32>,
+    mnemonic: RedoubtString,
+    backup: RedoubtVec<u8>,
+    account_index: RedoubtSecret<u64>,
+}
+
+fn populate(wallet: &mut WalletBox) {
+    let mut seed = [0x42u8; 32];
+    let mut mnemonic = String::from(MNEMONIC);
+    let mut backup = vec![0xAAu8; 64];
+    let mut account_index = 7u64;
+
+    wallet
+        .open_mut(|w| {
+            w.seed.replace_from_mut_array(&mut seed);
+            w.mnemonic.replace_from_mut_string(&mut mnemonic);
+            w.backup.extend_from_mut_slice(&mut backup);
+            w.account_index.replace(&mut account_index);
+
+            Ok::<(), CipherBoxError>(())
+        })
+        .expect("Failed to open_mut(..)");
+
+    // Sources handed to the wallet are wiped, including spare capacity.
+    assert!(seed.iter().all(|b| *b == 0));
+    assert!(is_vec_fully_zeroized(&mnemonic.into_bytes()));
+    assert!(is_vec_fully_zeroized(&backup));
+    assert_eq!(account_index, 0);
+}
+
+#[test]
+fn test_wallet_struct_zeroizes_on_drop() {
+    let mut wallet = Wallet::default();
+
+    wallet.seed.replace_from_mut_array(&mut [0x42u8; 32]);
+    wallet.backup.extend_from_mut_slice(&mut [0xAA; 16]);
+    assert!(!wallet.is_zeroized());
+
+    wallet.assert_zeroize_on_drop();
+}
+
+#[test]
+fn test_wallet_lifecycle_open_mut_wipes_sources() {
+    let mut wallet = WalletBox::new();
+
+    populate(&mut wallet);
+
+    wallet
+        .open(|w| {
+            assert_eq!(w.seed.as_slice(), &[0x42u8; 32]);
+            assert_eq!(w.mnemonic.as_str(), MNEMONIC);
+            assert_eq!(w.backup.as_slice(), &[0xAAu8; 64]);
+            assert_eq!(*w.account_index.as_ref(), 7);
+
+            Ok::<(), CipherBoxError>(())
+        })
+        .expect("Failed to open(..)");
+}
+
+#[test]
+fn test_wallet_lifecycle_open_mut_result_zeroizes_on_drop() {
+    let mut wallet = WalletBox::new();
+
+    populate(&mut wallet);
+
+    let exported = wallet
+        .open_mut(|w| {
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(w.backup.as_slice());
+            Ok::<Vec<u8>, CipherBoxError>(bytes)
+        })
+        .expect("Failed to open_mut(..)");
+
+    assert_eq!(exported.as_slice(), &[0xAAu8; 64]);
+
+    exported.assert_zeroize_on_drop();
+}
+
+#[test]
+fn test_wallet_lifecycle_leak_field_zeroizes() {
+    let mut wallet = WalletBox::new();
+
+    populate(&mut wallet);
+
+    // Leaked guard wipes itself on drop.
+    let seed = wallet.leak_seed().expect("Failed to leak_seed()");
+    assert_eq!(seed.as_slice(), &[0x42u8; 32]);
+    seed.assert_zeroize_on_drop();
+
+    // Dropping a leaked guard runs its zeroization, observed through the
+    // guard's own sentinel.
+    let backup = wallet.leak_backup().expect("Failed to leak_backup()");
+    assert_eq!(backup.as_slice(), &[0xAAu8; 64]);
+
+    let sentinel = backup.clone_sentinel();
+    assert!(!sentinel.is_zeroized());
+
+    drop(backup);
+    assert!(sentinel.is_zeroized());
+    assert!(sentinel.has_been_dropped());
+
+    let mnemonic = wallet.leak_mnemonic().expect("Failed to leak_mnemonic()");
+    assert_eq!(mnemonic.as_str(), MNEMONIC);
+    mnemonic.assert_zeroize_on_drop();
+
+    // Leaking never consumes the ciphertext.
+    wallet
+        .open_seed(|seed| {
+            assert_eq!(seed.as_slice(), &[0x42u8; 32]);
+            Ok::<(), CipherBoxError>(())
+        })
+        .expect("Failed to open_seed(..)");
+}
+
+#[test]
+fn test_wallet_lifecycle_field_mut_roundtrip() {
+    let mut wallet = WalletBox::new();
+
+    populate(&mut wallet);
+
+    let next = wallet
+        .open_account_index_mut(|index| {
+            let mut next = *index.as_ref() + 1;
+            index.replace(&mut next);
+            assert_eq!(next, 0);
+
+            Ok::<u64, CipherBoxError>(*index.as_ref())
+        })
+        .expect("Failed to open_account_index_mut(..)");
+
+    assert_eq!(*next, 8);
+    next.assert_zeroize_on_drop();
+
+    let index = wallet
+        .leak_account_index()
+        .expect("Failed to leak_account_index()");
+    assert_eq!(*index.as_ref(), 8);
+    index.assert_zeroize_on_drop();
+}